@@ -0,0 +1,131 @@
+// 应用全局快捷键
+// 根据 ShortcutSettings 解析快捷键，并在设置变更时重新注册
+
+use crate::shortcut_parser::parse_shortcut;
+use aumate_core_domain::settings::ShortcutSettings;
+use std::sync::RwLock;
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut};
+
+/// 当前生效的应用快捷键
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AppShortcuts {
+    pub toggle_palette: Shortcut,
+    pub screenshot: Shortcut,
+    pub element_scan: Shortcut,
+}
+
+impl AppShortcuts {
+    /// 从设置解析快捷键，解析失败时使用默认值
+    pub fn from_settings(config: &ShortcutSettings) -> Self {
+        let defaults = ShortcutSettings::default();
+        Self {
+            toggle_palette: parse_or_default(
+                "toggle_palette",
+                &config.toggle_palette,
+                &defaults.toggle_palette,
+            ),
+            screenshot: parse_or_default("screenshot", &config.screenshot, &defaults.screenshot),
+            element_scan: parse_or_default(
+                "element_scan",
+                &config.element_scan,
+                &defaults.element_scan,
+            ),
+        }
+    }
+
+    /// 按名称列出所有快捷键
+    pub fn entries(&self) -> [(&'static str, Shortcut); 3] {
+        [
+            ("toggle_palette", self.toggle_palette),
+            ("screenshot", self.screenshot),
+            ("element_scan", self.element_scan),
+        ]
+    }
+}
+
+/// 与 `AppShortcuts::entries` 顺序一致的原始快捷键字符串
+fn config_values(config: &ShortcutSettings) -> [&str; 3] {
+    [&config.toggle_palette, &config.screenshot, &config.element_scan]
+}
+
+fn parse_or_default(name: &str, value: &str, fallback: &str) -> Shortcut {
+    match parse_shortcut(value) {
+        Ok(s) => s,
+        Err(e) => {
+            log::error!("Failed to parse {} shortcut '{}': {}", name, value, e);
+            parse_shortcut(fallback).unwrap()
+        }
+    }
+}
+
+/// 注册所有应用快捷键
+pub fn register_all(app: &tauri::AppHandle, shortcuts: &AppShortcuts, config: &ShortcutSettings) {
+    for ((name, shortcut), value) in shortcuts.entries().into_iter().zip(config_values(config)) {
+        if let Err(e) = app.global_shortcut().register(shortcut) {
+            log::warn!("Failed to register {} hotkey '{}': {}", name, value, e);
+        }
+    }
+}
+
+/// 设置变更后重新注册发生变化的快捷键
+///
+/// 先注销所有旧快捷键，再注册新快捷键，这样两个动作互换快捷键时也不会冲突。
+/// 快捷键回调会读取 `current`，因此在调用插件前先更新状态并释放写锁，避免死锁
+pub fn reregister(
+    app: &tauri::AppHandle,
+    current: &RwLock<AppShortcuts>,
+    config: &ShortcutSettings,
+) {
+    let new = AppShortcuts::from_settings(config);
+
+    let old = {
+        let mut current = match current.write() {
+            Ok(guard) => guard,
+            Err(e) => {
+                log::error!("Shortcut state lock poisoned: {}", e);
+                return;
+            }
+        };
+        if *current == new {
+            return;
+        }
+        std::mem::replace(&mut *current, new)
+    };
+
+    let changed: Vec<_> = old
+        .entries()
+        .into_iter()
+        .zip(new.entries())
+        .zip(config_values(config))
+        .filter(|(((_, old), (_, new)), _)| old != new)
+        .collect();
+
+    for (((name, old), _), _) in &changed {
+        if let Err(e) = app.global_shortcut().unregister(*old) {
+            log::warn!("Failed to unregister previous {} hotkey: {}", name, e);
+        }
+    }
+
+    for ((_, (name, shortcut)), value) in &changed {
+        log::info!("Re-registering {} hotkey as '{}'", name, value);
+        if let Err(e) = app.global_shortcut().register(*shortcut) {
+            log::warn!("Failed to register {} hotkey '{}': {}", name, value, e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tauri_plugin_global_shortcut::{Code, Modifiers};
+
+    #[test]
+    fn test_from_settings_falls_back_on_invalid() {
+        let config =
+            ShortcutSettings { screenshot: "Ctrl+Nope".to_string(), ..ShortcutSettings::default() };
+
+        let shortcuts = AppShortcuts::from_settings(&config);
+        assert_eq!(shortcuts.toggle_palette, Shortcut::new(None, Code::F3));
+        assert_eq!(shortcuts.screenshot, Shortcut::new(Some(Modifiers::CONTROL), Code::Digit4));
+    }
+}
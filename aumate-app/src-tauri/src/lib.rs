@@ -6,6 +6,8 @@ use tauri::{
 };
 
 // Import commands and state management
+#[cfg(desktop)]
+mod app_shortcuts;
 mod commands;
 mod setup;
 mod shortcut_parser;
@@ -211,8 +213,10 @@ pub fn run() {
             // Register global shortcuts from settings
             #[cfg(desktop)]
             {
-                use shortcut_parser::parse_shortcut;
-                use tauri_plugin_global_shortcut::{GlobalShortcutExt, ShortcutState};
+                use app_shortcuts::AppShortcuts;
+                use aumate_core_traits::settings::SettingsStoragePort;
+                use std::sync::{Arc, RwLock};
+                use tauri_plugin_global_shortcut::ShortcutState;
 
                 // 从设置中读取快捷键配置
                 let shortcuts_config = {
@@ -233,56 +237,27 @@ pub fn run() {
                 log::info!("  screenshot: {}", shortcuts_config.screenshot);
                 log::info!("  element_scan: {}", shortcuts_config.element_scan);
 
-                // 解析快捷键字符串
-                let toggle_palette_shortcut = match parse_shortcut(&shortcuts_config.toggle_palette)
-                {
-                    Ok(s) => s,
-                    Err(e) => {
-                        log::error!(
-                            "Failed to parse toggle_palette shortcut '{}': {}",
-                            shortcuts_config.toggle_palette,
-                            e
-                        );
-                        parse_shortcut("F3").unwrap() // fallback
-                    }
-                };
-
-                let screenshot_shortcut = match parse_shortcut(&shortcuts_config.screenshot) {
-                    Ok(s) => s,
-                    Err(e) => {
-                        log::error!(
-                            "Failed to parse screenshot shortcut '{}': {}",
-                            shortcuts_config.screenshot,
-                            e
-                        );
-                        parse_shortcut("Ctrl+4").unwrap() // fallback
-                    }
-                };
-
-                let element_scan_shortcut = match parse_shortcut(&shortcuts_config.element_scan) {
-                    Ok(s) => s,
-                    Err(e) => {
-                        log::error!(
-                            "Failed to parse element_scan shortcut '{}': {}",
-                            shortcuts_config.element_scan,
-                            e
-                        );
-                        parse_shortcut("Ctrl+5").unwrap() // fallback
-                    }
-                };
+                // 解析快捷键字符串，设置变更时会被替换
+                let shortcuts =
+                    Arc::new(RwLock::new(AppShortcuts::from_settings(&shortcuts_config)));
+                let handler_shortcuts = shortcuts.clone();
 
                 // 注册全局快捷键处理器
                 app.handle().plugin(
                     tauri_plugin_global_shortcut::Builder::new()
                         .with_handler(move |app_handle, hotkey, event| {
                             if event.state == ShortcutState::Pressed {
-                                if hotkey == &toggle_palette_shortcut {
+                                let Ok(active) = handler_shortcuts.read().map(|s| *s) else {
+                                    return;
+                                };
+
+                                if hotkey == &active.toggle_palette {
                                     if let Some(window) =
                                         app_handle.get_webview_window("commandpalette")
                                     {
                                         toggle_window(&window);
                                     }
-                                } else if hotkey == &screenshot_shortcut {
+                                } else if hotkey == &active.screenshot {
                                     let app_handle_clone = app_handle.clone();
                                     tauri::async_runtime::spawn(async move {
                                         if let Err(e) =
@@ -291,7 +266,7 @@ pub fn run() {
                                             log::error!("Failed to create draw window: {}", e);
                                         }
                                     });
-                                } else if hotkey == &element_scan_shortcut {
+                                } else if hotkey == &active.element_scan {
                                     // Ctrl+5 作为切换键：如果已打开则关闭，否则打开
                                     if let Some(window) =
                                         app_handle.get_webview_window("elementscan")
@@ -329,29 +304,17 @@ pub fn run() {
                 )?;
 
                 // 注册快捷键
-                if let Err(e) = app.global_shortcut().register(toggle_palette_shortcut) {
-                    log::warn!(
-                        "Failed to register toggle_palette hotkey '{}': {}",
-                        shortcuts_config.toggle_palette,
-                        e
-                    );
+                if let Ok(active) = shortcuts.read() {
+                    app_shortcuts::register_all(app.handle(), &active, &shortcuts_config);
                 }
 
-                if let Err(e) = app.global_shortcut().register(screenshot_shortcut) {
-                    log::warn!(
-                        "Failed to register screenshot hotkey '{}': {}",
-                        shortcuts_config.screenshot,
-                        e
-                    );
-                }
-
-                if let Err(e) = app.global_shortcut().register(element_scan_shortcut) {
-                    log::warn!(
-                        "Failed to register element_scan hotkey '{}': {}",
-                        shortcuts_config.element_scan,
-                        e
-                    );
-                }
+                // 设置保存后重新注册发生变化的快捷键，无需重启
+                let app_handle = app.handle().clone();
+                app.state::<state::AppState>().settings_storage.on_settings_changed(Arc::new(
+                    move |settings: &aumate_core_domain::settings::Settings| {
+                        app_shortcuts::reregister(&app_handle, &shortcuts, &settings.shortcuts);
+                    },
+                ));
 
                 log::info!("Global shortcuts registered successfully");
             }
@@ -22,7 +22,7 @@ pub use page::PageManagementPort;
 pub use platform::PlatformInfoPort;
 pub use screenshot::{ImageProcessingPort, ScreenCapturePort};
 pub use scroll::ScrollCapturePort;
-pub use settings::SettingsStoragePort;
+pub use settings::{SettingsChangedCallback, SettingsStoragePort};
 pub use storage::{CachePort, FileSystemPort};
 pub use window::{
     MonitorInfo, UIAutomationPort, VibrancyEffect, WindowLayout, WindowLayoutPort, WindowListPort,
@@ -2,6 +2,10 @@
 use async_trait::async_trait;
 use aumate_core_domain::settings::Settings;
 use aumate_core_shared::InfrastructureError;
+use std::sync::Arc;
+
+/// Callback invoked with the new settings after they have been saved
+pub type SettingsChangedCallback = Arc<dyn Fn(&Settings) + Send + Sync>;
 
 /// Settings storage port
 ///
@@ -13,4 +17,9 @@ pub trait SettingsStoragePort: Send + Sync {
 
     /// Save settings to storage
     async fn save(&self, settings: Settings) -> Result<(), InfrastructureError>;
+
+    /// Subscribe to settings changes
+    ///
+    /// The callback runs after every successful `save`
+    fn on_settings_changed(&self, callback: SettingsChangedCallback);
 }
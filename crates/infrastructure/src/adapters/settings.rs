@@ -2,13 +2,15 @@
 use async_trait::async_trait;
 use aumate_core_domain::settings::Settings;
 use aumate_core_shared::InfrastructureError;
-use aumate_core_traits::settings::SettingsStoragePort;
+use aumate_core_traits::settings::{SettingsChangedCallback, SettingsStoragePort};
 use std::path::PathBuf;
+use std::sync::Mutex;
 use tokio::fs;
 
 /// File system based settings storage adapter
 pub struct FileSystemSettingsAdapter {
     settings_path: PathBuf,
+    listeners: Mutex<Vec<SettingsChangedCallback>>,
 }
 
 impl FileSystemSettingsAdapter {
@@ -16,12 +18,12 @@ impl FileSystemSettingsAdapter {
     pub fn new() -> Self {
         let home = dirs::home_dir().expect("Could not find home directory");
         let settings_path = home.join(".aumate").join("settings.json");
-        Self { settings_path }
+        Self { settings_path, listeners: Mutex::new(Vec::new()) }
     }
 
     /// Create with custom path
     pub fn with_path(path: PathBuf) -> Self {
-        Self { settings_path: path }
+        Self { settings_path: path, listeners: Mutex::new(Vec::new()) }
     }
 
     /// Ensure the settings directory exists
@@ -33,6 +35,22 @@ impl FileSystemSettingsAdapter {
         }
         Ok(())
     }
+
+    /// Notify all subscribers about saved settings
+    fn notify_listeners(&self, settings: &Settings) {
+        // Clone the list so callbacks may subscribe without deadlocking
+        let listeners = match self.listeners.lock() {
+            Ok(listeners) => listeners.clone(),
+            Err(e) => {
+                log::error!("Settings listeners lock poisoned: {}", e);
+                return;
+            }
+        };
+
+        for listener in listeners {
+            listener(settings);
+        }
+    }
 }
 
 impl Default for FileSystemSettingsAdapter {
@@ -76,6 +94,41 @@ impl SettingsStoragePort for FileSystemSettingsAdapter {
             InfrastructureError::IoFailed(format!("Failed to write settings: {}", e))
         })?;
 
+        self.notify_listeners(&settings);
+
         Ok(())
     }
+
+    fn on_settings_changed(&self, callback: SettingsChangedCallback) {
+        match self.listeners.lock() {
+            Ok(mut listeners) => listeners.push(callback),
+            Err(e) => log::error!("Settings listeners lock poisoned: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_save_notifies_listeners() {
+        let path = std::env::temp_dir().join("test_aumate_settings_notify.json");
+        let adapter = FileSystemSettingsAdapter::with_path(path.clone());
+
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let sink = received.clone();
+        adapter.on_settings_changed(Arc::new(move |settings: &Settings| {
+            sink.lock().unwrap().push(settings.shortcuts.screenshot.clone());
+        }));
+
+        let mut settings = Settings::default();
+        settings.shortcuts.screenshot = "Ctrl+Shift+4".to_string();
+        adapter.save(settings).await.unwrap();
+
+        assert_eq!(*received.lock().unwrap(), vec!["Ctrl+Shift+4".to_string()]);
+
+        let _ = std::fs::remove_file(&path);
+    }
 }
@@ -0,0 +1,584 @@
+//! Clipboard operations module
+//!
+//! Provides clipboard text, HTML, RTF and image operations for desktop automation.
+
+mod rtf;
+
+use crate::error::{AumateError, Result};
+use arboard::Clipboard;
+use image::ImageEncoder;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+// Thread-safe clipboard instance
+static CLIPBOARD: Mutex<Option<Clipboard>> = Mutex::new(None);
+
+fn get_or_init_clipboard() -> Result<std::sync::MutexGuard<'static, Option<Clipboard>>> {
+    let mut guard =
+        CLIPBOARD.lock().map_err(|e| AumateError::Clipboard(format!("Lock error: {}", e)))?;
+
+    if guard.is_none() {
+        let clipboard = Clipboard::new().map_err(|e| {
+            AumateError::Clipboard(format!("Failed to initialize clipboard: {}", e))
+        })?;
+        *guard = Some(clipboard);
+    }
+
+    Ok(guard)
+}
+
+/// Get text from clipboard
+pub fn get_text() -> Result<String> {
+    let mut guard = get_or_init_clipboard()?;
+    let clipboard = guard
+        .as_mut()
+        .ok_or_else(|| AumateError::Clipboard("Clipboard not initialized".to_string()))?;
+
+    clipboard
+        .get_text()
+        .map_err(|e| AumateError::Clipboard(format!("Failed to get clipboard text: {}", e)))
+}
+
+/// Set text to clipboard
+pub fn set_text(text: &str) -> Result<()> {
+    let mut guard = get_or_init_clipboard()?;
+    let clipboard = guard
+        .as_mut()
+        .ok_or_else(|| AumateError::Clipboard("Clipboard not initialized".to_string()))?;
+
+    clipboard
+        .set_text(text.to_string())
+        .map_err(|e| AumateError::Clipboard(format!("Failed to set clipboard text: {}", e)))
+}
+
+/// Get HTML from clipboard
+///
+/// Returns `None` if the clipboard holds no HTML content.
+pub fn get_html() -> Result<Option<String>> {
+    let mut guard = get_or_init_clipboard()?;
+    let clipboard = guard
+        .as_mut()
+        .ok_or_else(|| AumateError::Clipboard("Clipboard not initialized".to_string()))?;
+
+    match clipboard.get().html() {
+        Ok(html) => Ok(Some(html)),
+        Err(arboard::Error::ContentNotAvailable) => Ok(None),
+        Err(e) => Err(AumateError::Clipboard(format!("Failed to get clipboard HTML: {}", e))),
+    }
+}
+
+/// Set HTML to clipboard
+///
+/// A plain-text fallback is stored alongside the HTML so pasting into plain
+/// editors works. When `alt_text` is `None`, it is derived from the HTML.
+pub fn set_html(html: &str, alt_text: Option<&str>) -> Result<()> {
+    let mut guard = get_or_init_clipboard()?;
+    let clipboard = guard
+        .as_mut()
+        .ok_or_else(|| AumateError::Clipboard("Clipboard not initialized".to_string()))?;
+
+    let alt_text = alt_text.map(str::to_string).unwrap_or_else(|| html_to_plain_text(html));
+    clipboard
+        .set_html(html.to_string(), Some(alt_text))
+        .map_err(|e| AumateError::Clipboard(format!("Failed to set clipboard HTML: {}", e)))
+}
+
+/// Strip tags and decode common entities to produce a plain-text version of HTML
+fn html_to_plain_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut chars = html.chars();
+    while let Some(c) = chars.next() {
+        if c != '<' {
+            text.push(c);
+            continue;
+        }
+        let tag: String = chars.by_ref().take_while(|&c| c != '>').collect();
+        let name = tag.trim_start_matches('/').split_whitespace().next().unwrap_or("");
+        if matches!(name.to_ascii_lowercase().trim_end_matches('/'), "br" | "p" | "div" | "li")
+            && !text.is_empty()
+            && !text.ends_with('\n')
+        {
+            text.push('\n');
+        }
+    }
+
+    let text = text
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&");
+    text.trim().to_string()
+}
+
+/// Get RTF from clipboard
+///
+/// Returns `None` if the clipboard holds no RTF content.
+pub fn get_rtf() -> Result<Option<String>> {
+    rtf::get_rtf()
+}
+
+/// Set RTF to clipboard
+///
+/// A plain-text fallback is stored alongside the RTF so pasting into plain
+/// editors works. When `alt_text` is `None`, it is derived from the RTF.
+pub fn set_rtf(rtf: &str, alt_text: Option<&str>) -> Result<()> {
+    let alt_text = alt_text.map(str::to_string).unwrap_or_else(|| rtf::rtf_to_plain_text(rtf));
+    rtf::set_rtf(rtf, &alt_text)
+}
+
+/// List the content types currently on the clipboard
+///
+/// Platform formats are normalized to MIME types: `text/plain`, `text/html`,
+/// `text/rtf`, `image/png` and `text/uri-list` (for copied files).
+pub fn get_types() -> Result<Vec<String>> {
+    let mut guard = get_or_init_clipboard()?;
+    let clipboard = guard
+        .as_mut()
+        .ok_or_else(|| AumateError::Clipboard("Clipboard not initialized".to_string()))?;
+
+    let mut types = Vec::new();
+    if clipboard.get_text().is_ok() {
+        types.push("text/plain".to_string());
+    }
+    if clipboard.get().html().is_ok() {
+        types.push("text/html".to_string());
+    }
+    if rtf::get_rtf().is_ok_and(|rtf| rtf.is_some()) {
+        types.push("text/rtf".to_string());
+    }
+    if clipboard.get_image().is_ok() {
+        types.push("image/png".to_string());
+    }
+    if clipboard.get().file_list().is_ok_and(|files| !files.is_empty()) {
+        types.push("text/uri-list".to_string());
+    }
+    Ok(types)
+}
+
+/// Get image from clipboard (returns PNG-encoded buffer)
+pub fn get_image() -> Result<Vec<u8>> {
+    let mut guard = get_or_init_clipboard()?;
+    let clipboard = guard
+        .as_mut()
+        .ok_or_else(|| AumateError::Clipboard("Clipboard not initialized".to_string()))?;
+
+    let image_data = clipboard.get_image().map_err(|e| match e {
+        arboard::Error::ContentNotAvailable => {
+            AumateError::Clipboard("Clipboard does not contain an image".to_string())
+        }
+        e => AumateError::Clipboard(format!("Failed to get clipboard image: {}", e)),
+    })?;
+
+    // Convert arboard ImageData to PNG buffer
+    let rgba_data = image_data.bytes.to_vec();
+    let width = image_data.width as u32;
+    let height = image_data.height as u32;
+
+    // Create image from raw RGBA data
+    let img = image::RgbaImage::from_raw(width, height, rgba_data).ok_or_else(|| {
+        AumateError::Clipboard("Failed to create image from clipboard data".to_string())
+    })?;
+
+    // Encode as PNG
+    let mut png_data = Vec::new();
+    let encoder = image::codecs::png::PngEncoder::new(&mut png_data);
+    encoder
+        .write_image(img.as_raw(), width, height, image::ExtendedColorType::Rgba8)
+        .map_err(|e| AumateError::Clipboard(format!("Failed to encode image as PNG: {}", e)))?;
+
+    Ok(png_data)
+}
+
+/// Set image to clipboard (accepts PNG-encoded buffer)
+pub fn set_image(image_buffer: &[u8]) -> Result<()> {
+    let mut guard = get_or_init_clipboard()?;
+    let clipboard = guard
+        .as_mut()
+        .ok_or_else(|| AumateError::Clipboard("Clipboard not initialized".to_string()))?;
+
+    // Decode PNG buffer
+    let img = image::load_from_memory(image_buffer)
+        .map_err(|e| AumateError::Clipboard(format!("Failed to decode image: {}", e)))?;
+
+    let rgba = img.to_rgba8();
+    let (width, height) = rgba.dimensions();
+
+    // Convert to arboard ImageData
+    let image_data = arboard::ImageData {
+        width: width as usize,
+        height: height as usize,
+        bytes: rgba.into_raw().into(),
+    };
+
+    clipboard
+        .set_image(image_data)
+        .map_err(|e| AumateError::Clipboard(format!("Failed to set clipboard image: {}", e)))
+}
+
+/// Set image to clipboard from raw RGBA data
+pub fn set_image_raw(width: u32, height: u32, rgba_data: Vec<u8>) -> Result<()> {
+    let mut guard = get_or_init_clipboard()?;
+    let clipboard = guard
+        .as_mut()
+        .ok_or_else(|| AumateError::Clipboard("Clipboard not initialized".to_string()))?;
+
+    let image_data = arboard::ImageData {
+        width: width as usize,
+        height: height as usize,
+        bytes: rgba_data.into(),
+    };
+
+    clipboard
+        .set_image(image_data)
+        .map_err(|e| AumateError::Clipboard(format!("Failed to set clipboard image: {}", e)))
+}
+
+/// Clear clipboard
+pub fn clear() -> Result<()> {
+    let mut guard = get_or_init_clipboard()?;
+    let clipboard = guard
+        .as_mut()
+        .ok_or_else(|| AumateError::Clipboard("Clipboard not initialized".to_string()))?;
+
+    clipboard
+        .clear()
+        .map_err(|e| AumateError::Clipboard(format!("Failed to clear clipboard: {}", e)))
+}
+
+/// Kind of content currently on the clipboard
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClipboardContentType {
+    Text,
+    Html,
+    Image,
+    Empty,
+}
+
+impl ClipboardContentType {
+    /// Stable lowercase name ("text", "html", "image" or "empty")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ClipboardContentType::Text => "text",
+            ClipboardContentType::Html => "html",
+            ClipboardContentType::Image => "image",
+            ClipboardContentType::Empty => "empty",
+        }
+    }
+}
+
+/// Clipboard change reported by [`watch`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClipboardChange {
+    pub content_type: ClipboardContentType,
+    /// Plain text of the new content, if any
+    pub text: Option<String>,
+}
+
+/// Handle to a running clipboard watcher; watching stops when it is dropped
+pub struct ClipboardWatcher {
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl ClipboardWatcher {
+    /// Stop watching and wait for the polling thread to exit
+    pub fn stop(self) {
+        drop(self);
+    }
+}
+
+impl Drop for ClipboardWatcher {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Watch the clipboard and call `callback` whenever its content changes
+///
+/// Polls every 250ms and waits for the content to stay unchanged for 100ms
+/// before reporting, so rapid successive copies produce a single event.
+///
+/// On macOS and Windows changes are detected with the system's clipboard change
+/// counter. Elsewhere only text and HTML are compared, so replacing one image
+/// with another is not reported.
+pub fn watch<F>(callback: F) -> Result<ClipboardWatcher>
+where
+    F: FnMut(ClipboardChange) + Send + 'static,
+{
+    watch_with_interval(Duration::from_millis(250), Duration::from_millis(100), callback)
+}
+
+/// Watch the clipboard with a custom polling interval and debounce window
+pub fn watch_with_interval<F>(
+    interval: Duration,
+    debounce: Duration,
+    mut callback: F,
+) -> Result<ClipboardWatcher>
+where
+    F: FnMut(ClipboardChange) + Send + 'static,
+{
+    // The watcher polls through its own clipboard handle so it never holds the
+    // shared one while reading
+    let mut clipboard = Clipboard::new()
+        .map_err(|e| AumateError::Clipboard(format!("Failed to initialize clipboard: {}", e)))?;
+    let initial = fingerprint(&mut clipboard);
+    let stop = Arc::new(AtomicBool::new(false));
+    let stop_flag = stop.clone();
+
+    let thread = thread::Builder::new()
+        .name("aumate-clipboard-watch".to_string())
+        .spawn(move || {
+            let mut debouncer = Debouncer::new(initial, debounce);
+            while !stop_flag.load(Ordering::SeqCst) {
+                thread::sleep(interval);
+                if debouncer.observe(fingerprint(&mut clipboard), Instant::now()) {
+                    callback(read_change(&mut clipboard));
+                }
+            }
+        })
+        .map_err(|e| AumateError::Clipboard(format!("Failed to start clipboard watcher: {}", e)))?;
+
+    Ok(ClipboardWatcher { stop, thread: Some(thread) })
+}
+
+/// Cheap value that changes whenever the clipboard content does
+///
+/// Uses the system change counter when there is one, otherwise hashes the text
+/// and HTML. Images are never read here since decoding them on every poll is
+/// expensive.
+fn fingerprint(clipboard: &mut Clipboard) -> u64 {
+    if let Some(count) = change_count() {
+        return count;
+    }
+
+    let mut hasher = DefaultHasher::new();
+    clipboard.get_text().ok().filter(|t| !t.is_empty()).hash(&mut hasher);
+    clipboard.get().html().ok().filter(|h| !h.is_empty()).hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Read the current clipboard content for a change event
+fn read_change(clipboard: &mut Clipboard) -> ClipboardChange {
+    let text = clipboard.get_text().ok().filter(|t| !t.is_empty());
+    let html = clipboard.get().html().ok().filter(|h| !h.is_empty());
+
+    let content_type = match (&text, &html) {
+        (Some(_), Some(_)) => ClipboardContentType::Html,
+        (Some(_), None) => ClipboardContentType::Text,
+        // The image only decides the type when there is no text
+        (None, _) if clipboard.get_image().is_ok() => ClipboardContentType::Image,
+        (None, Some(_)) => ClipboardContentType::Html,
+        (None, None) => ClipboardContentType::Empty,
+    };
+
+    ClipboardChange { content_type, text }
+}
+
+/// System clipboard change counter (`NSPasteboard.changeCount`)
+#[cfg(target_os = "macos")]
+fn change_count() -> Option<u64> {
+    use objc::runtime::Object;
+    use objc::{class, msg_send};
+
+    unsafe {
+        let pasteboard: *mut Object = msg_send![class!(NSPasteboard), generalPasteboard];
+        if pasteboard.is_null() {
+            return None;
+        }
+        let count: isize = msg_send![pasteboard, changeCount];
+        Some(count as u64)
+    }
+}
+
+/// System clipboard change counter (`GetClipboardSequenceNumber`)
+#[cfg(target_os = "windows")]
+fn change_count() -> Option<u64> {
+    // 0 means this window station has no clipboard access
+    let count = unsafe { winapi::um::winuser::GetClipboardSequenceNumber() };
+    (count != 0).then_some(count as u64)
+}
+
+/// No change counter is available on this platform
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn change_count() -> Option<u64> {
+    None
+}
+
+/// Reports a change once a new fingerprint has been stable for the debounce window
+struct Debouncer {
+    reported: u64,
+    pending: Option<(u64, Instant)>,
+    debounce: Duration,
+}
+
+impl Debouncer {
+    fn new(initial: u64, debounce: Duration) -> Self {
+        Self { reported: initial, pending: None, debounce }
+    }
+
+    /// Record an observation; returns true when the change should be reported
+    fn observe(&mut self, fingerprint: u64, now: Instant) -> bool {
+        match self.pending {
+            Some((pending, since)) if pending == fingerprint => {
+                if now.duration_since(since) < self.debounce {
+                    return false;
+                }
+                self.pending = None;
+                if fingerprint == self.reported {
+                    return false;
+                }
+                self.reported = fingerprint;
+                true
+            }
+            _ if fingerprint == self.reported => {
+                self.pending = None;
+                false
+            }
+            _ => {
+                self.pending = Some((fingerprint, now));
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Helper to check if display server is available
+    fn has_display() -> bool {
+        // Check for X11 or Wayland display
+        std::env::var("DISPLAY").is_ok() || std::env::var("WAYLAND_DISPLAY").is_ok()
+    }
+
+    #[test]
+    fn test_clipboard_text() {
+        if !has_display() {
+            eprintln!("Skipping clipboard test: no display server available");
+            return;
+        }
+
+        let test_text = "Hello, clipboard!";
+        set_text(test_text).unwrap();
+
+        let retrieved = get_text().unwrap();
+        assert_eq!(retrieved, test_text);
+    }
+
+    #[test]
+    fn test_html_to_plain_text() {
+        assert_eq!(html_to_plain_text("<b>Hello</b>, <i>world</i>!"), "Hello, world!");
+        assert_eq!(html_to_plain_text("<p>One</p><p>Two</p>"), "One\nTwo");
+        assert_eq!(html_to_plain_text("a<br/>b &amp; c &lt;d&gt;"), "a\nb & c <d>");
+    }
+
+    #[test]
+    fn test_clipboard_html() {
+        if !has_display() {
+            eprintln!("Skipping clipboard test: no display server available");
+            return;
+        }
+
+        set_html("<b>Hello</b>", None).unwrap();
+        assert_eq!(get_html().unwrap().as_deref(), Some("<b>Hello</b>"));
+        assert_eq!(get_text().unwrap(), "Hello");
+    }
+
+    #[test]
+    fn test_rtf_to_plain_text() {
+        let rtf = r"{\rtf1\ansi{\fonttbl\f0\fswiss Helvetica;}{\colortbl;\red255\green0\blue0;}
+\f0\pard Hello, {\b world}!\par
+Caf\'e9 \{braces\}\tab end}";
+        assert_eq!(rtf::rtf_to_plain_text(rtf), "Hello, world!\nCaf\u{e9} {braces}\tend");
+        assert_eq!(rtf::rtf_to_plain_text(r"{\rtf1{\*\generator Writer;}Plain}"), "Plain");
+    }
+
+    #[test]
+    fn test_clipboard_rtf() {
+        if !has_display() {
+            eprintln!("Skipping clipboard test: no display server available");
+            return;
+        }
+
+        let rtf = r"{\rtf1\ansi {\b Hello}}";
+        set_rtf(rtf, None).unwrap();
+        assert_eq!(get_rtf().unwrap().as_deref(), Some(rtf));
+        assert_eq!(get_text().unwrap(), "Hello");
+
+        set_text("plain").unwrap();
+        assert_eq!(get_rtf().unwrap(), None);
+    }
+
+    #[test]
+    fn test_debouncer_reports_stable_change_once() {
+        let start = Instant::now();
+        let ms = Duration::from_millis;
+        let mut debouncer = Debouncer::new(1, ms(100));
+
+        assert!(!debouncer.observe(1, start));
+        assert!(!debouncer.observe(2, start + ms(10)));
+        assert!(!debouncer.observe(2, start + ms(50)));
+        assert!(debouncer.observe(2, start + ms(120)));
+        assert!(!debouncer.observe(2, start + ms(400)));
+    }
+
+    #[test]
+    fn test_debouncer_coalesces_rapid_changes() {
+        let start = Instant::now();
+        let ms = Duration::from_millis;
+        let mut debouncer = Debouncer::new(1, ms(100));
+
+        assert!(!debouncer.observe(2, start));
+        assert!(!debouncer.observe(3, start + ms(50)));
+        assert!(!debouncer.observe(4, start + ms(100)));
+        assert!(debouncer.observe(4, start + ms(250)));
+    }
+
+    #[test]
+    fn test_debouncer_ignores_revert() {
+        let start = Instant::now();
+        let ms = Duration::from_millis;
+        let mut debouncer = Debouncer::new(1, ms(100));
+
+        assert!(!debouncer.observe(2, start));
+        assert!(!debouncer.observe(1, start + ms(50)));
+        assert!(!debouncer.observe(1, start + ms(300)));
+    }
+
+    #[test]
+    fn test_get_types_text() {
+        if !has_display() {
+            eprintln!("Skipping clipboard test: no display server available");
+            return;
+        }
+
+        set_text("types").unwrap();
+        let types = get_types().unwrap();
+        assert!(types.contains(&"text/plain".to_string()));
+        assert!(!types.contains(&"image/png".to_string()));
+    }
+
+    #[test]
+    fn test_clear_clipboard() {
+        if !has_display() {
+            eprintln!("Skipping clipboard test: no display server available");
+            return;
+        }
+
+        set_text("test").unwrap();
+        clear().unwrap();
+        // Getting clipboard might fail or return empty string after clear
+        let _ = get_text();
+    }
+}
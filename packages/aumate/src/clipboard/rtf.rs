@@ -0,0 +1,597 @@
+//! RTF clipboard access
+//!
+//! arboard has no RTF support, so the platform clipboards are used directly:
+//! the `public.rtf` pasteboard type on macOS, the registered "Rich Text Format"
+//! clipboard format on Windows and the `text/rtf` selection target on X11
+//! (which also serves Wayland sessions through XWayland).
+
+use crate::error::Result;
+
+/// Read RTF from the clipboard, or `None` if none is present
+pub(super) fn get_rtf() -> Result<Option<String>> {
+    platform::get_rtf()
+}
+
+/// Put RTF on the clipboard together with a plain-text fallback
+pub(super) fn set_rtf(rtf: &str, alt_text: &str) -> Result<()> {
+    platform::set_rtf(rtf, alt_text)
+}
+
+/// Extract the visible text from an RTF document
+///
+/// Control words are dropped except for paragraph and line breaks, tabs and
+/// `\'hh` escapes (decoded as Latin-1), and header groups such as the font
+/// and color tables are skipped.
+pub(super) fn rtf_to_plain_text(rtf: &str) -> String {
+    const SKIPPED_GROUPS: &[&str] =
+        &["fonttbl", "colortbl", "stylesheet", "info", "pict", "header", "footer"];
+
+    let mut text = String::with_capacity(rtf.len());
+    // Per open group: whether its content is skipped
+    let mut groups: Vec<bool> = Vec::new();
+    let mut skipping = false;
+    let mut chars = rtf.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' => {
+                groups.push(skipping);
+                // `{\*\dest ...}` marks optional destinations readers may ignore
+                if chars.peek() == Some(&'\\') {
+                    let mut lookahead = chars.clone();
+                    lookahead.next();
+                    if lookahead.peek() == Some(&'*') {
+                        skipping = true;
+                    }
+                }
+            }
+            '}' => skipping = groups.pop().unwrap_or(false),
+            '\\' => {
+                let Some(&next) = chars.peek() else { break };
+                if !next.is_ascii_alphabetic() {
+                    chars.next();
+                    match next {
+                        '\'' => {
+                            let hex: String = chars.by_ref().take(2).collect();
+                            if let (false, Ok(byte)) = (skipping, u8::from_str_radix(&hex, 16)) {
+                                text.push(byte as char);
+                            }
+                        }
+                        '\\' | '{' | '}' if !skipping => text.push(next),
+                        '~' if !skipping => text.push(' '),
+                        _ => {}
+                    }
+                    continue;
+                }
+
+                let mut word = String::new();
+                while let Some(&c) = chars.peek().filter(|c| c.is_ascii_alphabetic()) {
+                    word.push(c);
+                    chars.next();
+                }
+                if chars.peek() == Some(&'-') {
+                    chars.next();
+                }
+                while chars.peek().is_some_and(|c| c.is_ascii_digit()) {
+                    chars.next();
+                }
+                // A single space after a control word is part of it
+                if chars.peek() == Some(&' ') {
+                    chars.next();
+                }
+
+                if SKIPPED_GROUPS.contains(&word.as_str()) {
+                    skipping = true;
+                } else if !skipping {
+                    match word.as_str() {
+                        "par" | "line" => text.push('\n'),
+                        "tab" => text.push('\t'),
+                        _ => {}
+                    }
+                }
+            }
+            '\r' | '\n' => {}
+            _ if !skipping => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.trim().to_string()
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use crate::error::{AumateError, Result};
+    use objc::rc::autoreleasepool;
+    use objc::runtime::{BOOL, NO, Object};
+    use objc::{class, msg_send};
+    use std::ffi::{CStr, c_void};
+
+    const RTF_TYPE: &CStr = c"public.rtf";
+    const TEXT_TYPE: &CStr = c"public.utf8-plain-text";
+    /// NSUTF8StringEncoding
+    const UTF8_ENCODING: usize = 4;
+
+    fn pasteboard_type(name: &CStr) -> *mut Object {
+        unsafe { msg_send![class!(NSString), stringWithUTF8String: name.as_ptr()] }
+    }
+
+    fn general_pasteboard() -> Result<*mut Object> {
+        let pasteboard: *mut Object = unsafe { msg_send![class!(NSPasteboard), generalPasteboard] };
+        if pasteboard.is_null() {
+            return Err(AumateError::Clipboard("Failed to open the pasteboard".to_string()));
+        }
+        Ok(pasteboard)
+    }
+
+    pub fn get_rtf() -> Result<Option<String>> {
+        autoreleasepool(|| {
+            let pasteboard = general_pasteboard()?;
+            let data: *mut Object =
+                unsafe { msg_send![pasteboard, dataForType: pasteboard_type(RTF_TYPE)] };
+            if data.is_null() {
+                return Ok(None);
+            }
+
+            let bytes: *const u8 = unsafe { msg_send![data, bytes] };
+            let length: usize = unsafe { msg_send![data, length] };
+            if bytes.is_null() || length == 0 {
+                return Ok(Some(String::new()));
+            }
+            let rtf = unsafe { std::slice::from_raw_parts(bytes, length) };
+            Ok(Some(String::from_utf8_lossy(rtf).into_owned()))
+        })
+    }
+
+    pub fn set_rtf(rtf: &str, alt_text: &str) -> Result<()> {
+        autoreleasepool(|| {
+            let pasteboard = general_pasteboard()?;
+            unsafe {
+                let _: isize = msg_send![pasteboard, clearContents];
+
+                let data: *mut Object = msg_send![class!(NSData),
+                    dataWithBytes: rtf.as_ptr() as *const c_void
+                    length: rtf.len()];
+                let stored: BOOL =
+                    msg_send![pasteboard, setData: data forType: pasteboard_type(RTF_TYPE)];
+                if stored == NO {
+                    return Err(AumateError::Clipboard("Failed to set clipboard RTF".to_string()));
+                }
+
+                let text: *mut Object = msg_send![class!(NSString), alloc];
+                let text: *mut Object = msg_send![text,
+                    initWithBytes: alt_text.as_ptr() as *const c_void
+                    length: alt_text.len()
+                    encoding: UTF8_ENCODING];
+                let _: *mut Object = msg_send![text, autorelease];
+                let stored: BOOL =
+                    msg_send![pasteboard, setString: text forType: pasteboard_type(TEXT_TYPE)];
+                if stored == NO {
+                    return Err(AumateError::Clipboard(
+                        "Failed to set clipboard plain text".to_string(),
+                    ));
+                }
+            }
+            Ok(())
+        })
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use crate::error::{AumateError, Result};
+    use std::ptr;
+    use std::thread;
+    use std::time::Duration;
+    use winapi::um::winbase::{
+        GMEM_MOVEABLE, GlobalAlloc, GlobalFree, GlobalLock, GlobalSize, GlobalUnlock,
+    };
+    use winapi::um::winuser::{
+        CF_UNICODETEXT, CloseClipboard, EmptyClipboard, GetClipboardData, OpenClipboard,
+        RegisterClipboardFormatW, SetClipboardData,
+    };
+
+    /// The clipboard, open until dropped
+    struct OpenClipboardGuard;
+
+    impl OpenClipboardGuard {
+        /// Open the clipboard, retrying briefly while another program holds it
+        fn open() -> Result<Self> {
+            for _ in 0..10 {
+                if unsafe { OpenClipboard(ptr::null_mut()) } != 0 {
+                    return Ok(Self);
+                }
+                thread::sleep(Duration::from_millis(10));
+            }
+            Err(AumateError::Clipboard("Failed to open the clipboard".to_string()))
+        }
+    }
+
+    impl Drop for OpenClipboardGuard {
+        fn drop(&mut self) {
+            unsafe { CloseClipboard() };
+        }
+    }
+
+    fn rtf_format() -> Result<u32> {
+        let name: Vec<u16> = "Rich Text Format\0".encode_utf16().collect();
+        match unsafe { RegisterClipboardFormatW(name.as_ptr()) } {
+            0 => Err(AumateError::Clipboard("Failed to register the RTF format".to_string())),
+            format => Ok(format),
+        }
+    }
+
+    pub fn get_rtf() -> Result<Option<String>> {
+        let format = rtf_format()?;
+        let _clipboard = OpenClipboardGuard::open()?;
+
+        let handle = unsafe { GetClipboardData(format) };
+        if handle.is_null() {
+            return Ok(None);
+        }
+        let data = unsafe { GlobalLock(handle) } as *const u8;
+        if data.is_null() {
+            return Err(AumateError::Clipboard("Failed to read clipboard RTF".to_string()));
+        }
+        let bytes = unsafe { std::slice::from_raw_parts(data, GlobalSize(handle)) };
+        // The data is NUL-terminated inside a possibly larger allocation
+        let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+        let rtf = String::from_utf8_lossy(&bytes[..end]).into_owned();
+        unsafe { GlobalUnlock(handle) };
+        Ok(Some(rtf))
+    }
+
+    pub fn set_rtf(rtf: &str, alt_text: &str) -> Result<()> {
+        let format = rtf_format()?;
+        let _clipboard = OpenClipboardGuard::open()?;
+        if unsafe { EmptyClipboard() } == 0 {
+            return Err(AumateError::Clipboard("Failed to clear the clipboard".to_string()));
+        }
+
+        let mut rtf_bytes = rtf.as_bytes().to_vec();
+        rtf_bytes.push(0);
+        set_data(format, &rtf_bytes)?;
+
+        let text_bytes: Vec<u8> =
+            alt_text.encode_utf16().chain([0]).flat_map(u16::to_ne_bytes).collect();
+        set_data(CF_UNICODETEXT, &text_bytes)
+    }
+
+    /// Copy `data` into global memory and hand it to the open clipboard
+    fn set_data(format: u32, data: &[u8]) -> Result<()> {
+        let error = || AumateError::Clipboard("Failed to set clipboard data".to_string());
+
+        let handle = unsafe { GlobalAlloc(GMEM_MOVEABLE, data.len()) };
+        if handle.is_null() {
+            return Err(error());
+        }
+        let dest = unsafe { GlobalLock(handle) } as *mut u8;
+        if dest.is_null() {
+            unsafe { GlobalFree(handle) };
+            return Err(error());
+        }
+        unsafe {
+            ptr::copy_nonoverlapping(data.as_ptr(), dest, data.len());
+            GlobalUnlock(handle);
+        }
+
+        // The clipboard owns the memory once this succeeds
+        if unsafe { SetClipboardData(format, handle) }.is_null() {
+            unsafe { GlobalFree(handle) };
+            return Err(error());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use crate::error::{AumateError, Result};
+    use std::ffi::CStr;
+    use std::os::raw::{c_int, c_long, c_uchar, c_ulong};
+    use std::ptr;
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::{Duration, Instant};
+    use x11::xlib;
+
+    /// How long to wait for the selection owner to answer
+    const TIMEOUT: Duration = Duration::from_secs(1);
+
+    /// Connection to the X server, closed when dropped
+    struct Display(*mut xlib::Display);
+
+    // The connection is only used from one thread at a time
+    unsafe impl Send for Display {}
+
+    impl Display {
+        fn open() -> Result<Self> {
+            let display = unsafe { xlib::XOpenDisplay(ptr::null()) };
+            if display.is_null() {
+                return Err(AumateError::Clipboard("Failed to open the X display".to_string()));
+            }
+            Ok(Self(display))
+        }
+
+        fn atom(&self, name: &CStr) -> xlib::Atom {
+            unsafe { xlib::XInternAtom(self.0, name.as_ptr(), xlib::False) }
+        }
+
+        /// Create an unmapped window to exchange selection data through
+        fn create_window(&self) -> xlib::Window {
+            unsafe {
+                let root = xlib::XDefaultRootWindow(self.0);
+                let window = xlib::XCreateSimpleWindow(self.0, root, 0, 0, 1, 1, 0, 0, 0);
+                xlib::XSelectInput(self.0, window, xlib::PropertyChangeMask);
+                window
+            }
+        }
+
+        /// Wait for an event of `event_type` on `window` that satisfies `accept`
+        fn wait_for(
+            &self,
+            window: xlib::Window,
+            event_type: c_int,
+            accept: impl Fn(&xlib::XEvent) -> bool,
+        ) -> Result<xlib::XEvent> {
+            let deadline = Instant::now() + TIMEOUT;
+            let mut event: xlib::XEvent = unsafe { std::mem::zeroed() };
+            while Instant::now() < deadline {
+                if unsafe { xlib::XCheckTypedWindowEvent(self.0, window, event_type, &mut event) }
+                    != 0
+                {
+                    if accept(&event) {
+                        return Ok(event);
+                    }
+                    continue;
+                }
+                thread::sleep(Duration::from_millis(5));
+            }
+            Err(AumateError::Clipboard("Timed out waiting for the clipboard owner".to_string()))
+        }
+
+        /// Read and delete a window property, returning its type and bytes
+        fn take_property(
+            &self,
+            window: xlib::Window,
+            property: xlib::Atom,
+        ) -> Result<(xlib::Atom, Vec<u8>)> {
+            let mut actual_type = 0;
+            let mut format = 0;
+            let mut items: c_ulong = 0;
+            let mut remaining: c_ulong = 0;
+            let mut data: *mut c_uchar = ptr::null_mut();
+
+            let status = unsafe {
+                xlib::XGetWindowProperty(
+                    self.0,
+                    window,
+                    property,
+                    0,
+                    c_long::MAX / 4,
+                    xlib::True,
+                    xlib::AnyPropertyType as xlib::Atom,
+                    &mut actual_type,
+                    &mut format,
+                    &mut items,
+                    &mut remaining,
+                    &mut data,
+                )
+            };
+            if status != xlib::Success as c_int {
+                return Err(AumateError::Clipboard("Failed to read clipboard data".to_string()));
+            }
+            if data.is_null() {
+                return Ok((actual_type, Vec::new()));
+            }
+
+            // Xlib returns 32-bit items as longs
+            let item_size = match format {
+                16 => 2,
+                32 => std::mem::size_of::<c_long>(),
+                _ => 1,
+            };
+            let bytes =
+                unsafe { std::slice::from_raw_parts(data, items as usize * item_size) }.to_vec();
+            unsafe { xlib::XFree(data as *mut _) };
+            Ok((actual_type, bytes))
+        }
+    }
+
+    impl Drop for Display {
+        fn drop(&mut self) {
+            // Also destroys the windows created on this connection
+            unsafe { xlib::XCloseDisplay(self.0) };
+        }
+    }
+
+    pub fn get_rtf() -> Result<Option<String>> {
+        let display = Display::open()?;
+        let clipboard = display.atom(c"CLIPBOARD");
+        if unsafe { xlib::XGetSelectionOwner(display.0, clipboard) } == 0 {
+            return Ok(None);
+        }
+
+        let window = display.create_window();
+        let target = display.atom(c"text/rtf");
+        let property = display.atom(c"AUMATE_CLIPBOARD");
+        unsafe {
+            xlib::XConvertSelection(
+                display.0,
+                clipboard,
+                target,
+                property,
+                window,
+                xlib::CurrentTime,
+            );
+            xlib::XFlush(display.0);
+        }
+
+        let event = display.wait_for(window, xlib::SelectionNotify, |_| true)?;
+        // The owner sets no property when it cannot provide the target
+        if unsafe { event.selection.property } == 0 {
+            return Ok(None);
+        }
+
+        let (actual_type, mut data) = display.take_property(window, property)?;
+        if actual_type == display.atom(c"INCR") {
+            // Large data arrives in chunks, each announced by a new property value
+            data.clear();
+            loop {
+                display.wait_for(window, xlib::PropertyNotify, |event| unsafe {
+                    event.property.atom == property
+                        && event.property.state == xlib::PropertyNewValue
+                })?;
+                let (_, chunk) = display.take_property(window, property)?;
+                if chunk.is_empty() {
+                    break;
+                }
+                data.extend_from_slice(&chunk);
+            }
+        }
+
+        Ok(Some(String::from_utf8_lossy(&data).into_owned()))
+    }
+
+    pub fn set_rtf(rtf: &str, alt_text: &str) -> Result<()> {
+        let rtf = rtf.as_bytes().to_vec();
+        let text = alt_text.as_bytes().to_vec();
+        let (started_tx, started_rx) = mpsc::channel();
+
+        // X11 has no clipboard storage: the owner serves every paste request
+        // until another program takes the selection
+        thread::Builder::new()
+            .name("aumate-clipboard-rtf".to_string())
+            .spawn(move || {
+                let display = match Display::open() {
+                    Ok(display) => display,
+                    Err(e) => {
+                        let _ = started_tx.send(Err(e));
+                        return;
+                    }
+                };
+                let window = display.create_window();
+                let clipboard = display.atom(c"CLIPBOARD");
+                unsafe {
+                    xlib::XSetSelectionOwner(display.0, clipboard, window, xlib::CurrentTime)
+                };
+                if unsafe { xlib::XGetSelectionOwner(display.0, clipboard) } != window {
+                    let _ = started_tx.send(Err(AumateError::Clipboard(
+                        "Failed to take ownership of the clipboard".to_string(),
+                    )));
+                    return;
+                }
+                let _ = started_tx.send(Ok(()));
+                serve_selection(&display, clipboard, &rtf, &text);
+            })
+            .map_err(|e| {
+                AumateError::Clipboard(format!("Failed to start clipboard owner: {}", e))
+            })?;
+
+        started_rx
+            .recv()
+            .map_err(|_| AumateError::Clipboard("Clipboard owner exited".to_string()))?
+    }
+
+    /// Answer selection requests until another client takes the clipboard
+    fn serve_selection(display: &Display, clipboard: xlib::Atom, rtf: &[u8], text: &[u8]) {
+        let targets = display.atom(c"TARGETS");
+        let rtf_targets = [display.atom(c"text/rtf"), display.atom(c"text/richtext")];
+        let text_targets = [
+            display.atom(c"UTF8_STRING"),
+            display.atom(c"text/plain;charset=utf-8"),
+            display.atom(c"text/plain"),
+            xlib::XA_STRING,
+        ];
+
+        loop {
+            let mut event: xlib::XEvent = unsafe { std::mem::zeroed() };
+            unsafe { xlib::XNextEvent(display.0, &mut event) };
+
+            match event.get_type() {
+                xlib::SelectionClear => {
+                    if unsafe { event.selection_clear.selection } == clipboard {
+                        return;
+                    }
+                }
+                xlib::SelectionRequest => {
+                    let request = unsafe { event.selection_request };
+                    // Obsolete clients pass no property and expect the target
+                    let property =
+                        if request.property == 0 { request.target } else { request.property };
+
+                    let stored = if request.target == targets {
+                        let supported: Vec<xlib::Atom> =
+                            [targets].into_iter().chain(rtf_targets).chain(text_targets).collect();
+                        unsafe {
+                            xlib::XChangeProperty(
+                                display.0,
+                                request.requestor,
+                                property,
+                                xlib::XA_ATOM,
+                                32,
+                                xlib::PropModeReplace,
+                                supported.as_ptr() as *const c_uchar,
+                                supported.len() as c_int,
+                            );
+                        }
+                        true
+                    } else if let Some(data) = if rtf_targets.contains(&request.target) {
+                        Some(rtf)
+                    } else if text_targets.contains(&request.target) {
+                        Some(text)
+                    } else {
+                        None
+                    } {
+                        unsafe {
+                            xlib::XChangeProperty(
+                                display.0,
+                                request.requestor,
+                                property,
+                                request.target,
+                                8,
+                                xlib::PropModeReplace,
+                                data.as_ptr(),
+                                data.len() as c_int,
+                            );
+                        }
+                        true
+                    } else {
+                        false
+                    };
+
+                    let mut reply: xlib::XEvent = unsafe { std::mem::zeroed() };
+                    reply.selection = xlib::XSelectionEvent {
+                        type_: xlib::SelectionNotify,
+                        serial: 0,
+                        send_event: xlib::True,
+                        display: display.0,
+                        requestor: request.requestor,
+                        selection: request.selection,
+                        target: request.target,
+                        property: if stored { property } else { 0 },
+                        time: request.time,
+                    };
+                    unsafe {
+                        xlib::XSendEvent(display.0, request.requestor, xlib::False, 0, &mut reply);
+                        xlib::XFlush(display.0);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+mod platform {
+    use crate::error::{AumateError, Result};
+
+    pub fn get_rtf() -> Result<Option<String>> {
+        Err(AumateError::PlatformUnsupported("RTF clipboard access".to_string()))
+    }
+
+    pub fn set_rtf(_rtf: &str, _alt_text: &str) -> Result<()> {
+        Err(AumateError::PlatformUnsupported("RTF clipboard access".to_string()))
+    }
+}
@@ -21,6 +21,22 @@ pub enum AumateError {
     #[error("Window error: {0}")]
     Window(String),
 
+    /// No window currently has focus
+    #[error("Window error: No focused window")]
+    NoFocusedWindow,
+
+    /// No window matches the given id
+    #[error("Window error: Window not found: {0}")]
+    WindowNotFound(String),
+
+    /// A required OS permission (e.g. macOS Accessibility) has not been granted
+    #[error("Permission denied: {0}")]
+    PermissionDenied(String),
+
+    /// The operation is not available on this platform or session type
+    #[error("Unsupported platform: {0}")]
+    PlatformUnsupported(String),
+
     /// Screenshot errors
     #[error("Screenshot error: {0}")]
     Screenshot(String),
@@ -55,5 +71,28 @@ impl From<candle_core::Error> for AumateError {
     }
 }
 
+#[cfg(all(feature = "input", feature = "eventhooks"))]
+impl AumateError {
+    /// Map a failed global input listener to the matching error kind
+    pub(crate) fn from_listen_error(e: crate::eventhooks::GrabError) -> Self {
+        use crate::eventhooks::GrabError;
+        match e {
+            // The event tap is refused when Accessibility access is missing
+            GrabError::EventTapError => AumateError::PermissionDenied(
+                "Accessibility access is required to listen for input events".to_string(),
+            ),
+            GrabError::WaylandUnsupported => AumateError::PlatformUnsupported(
+                "Global input hooks are not available in a Wayland session".to_string(),
+            ),
+            GrabError::AlreadyListening => AumateError::Input(
+                "Global input listener is already in use, e.g. by global hotkeys or a macro \
+                 recording"
+                    .to_string(),
+            ),
+            e => AumateError::Input(format!("Failed to listen for events: {:?}", e)),
+        }
+    }
+}
+
 /// Result type alias for aumate operations
 pub type Result<T> = std::result::Result<T, AumateError>;
@@ -1,8 +1,9 @@
 //! Common utilities for Linux event handling
 
 use crate::eventhooks::keycodes::linux::key_from_code;
-use crate::eventhooks::types::{Event, EventType};
+use crate::eventhooks::types::{Button, Event, EventType};
 use std::time::SystemTime;
+use x11::xlib;
 
 const KEYPRESS_EVENT: i32 = 2;
 
@@ -17,3 +18,38 @@ pub fn convert_event(code: u32, event_type: i32) -> Event {
 
     Event { time: SystemTime::now(), event_type, platform_code: code }
 }
+
+/// Convert a core X11 input event to our Event type
+///
+/// Buttons 4-7 are reported by X11 as scroll wheel clicks; their presses map to
+/// `Wheel` events and their releases are dropped.
+pub fn convert_input_event(type_: i32, code: u32, x: f64, y: f64) -> Option<Event> {
+    let event_type = match type_ {
+        xlib::KeyPress | xlib::KeyRelease => return Some(convert_event(code, type_)),
+        xlib::ButtonPress => match code {
+            4 => EventType::Wheel { delta_x: 0, delta_y: 1 },
+            5 => EventType::Wheel { delta_x: 0, delta_y: -1 },
+            6 => EventType::Wheel { delta_x: -1, delta_y: 0 },
+            7 => EventType::Wheel { delta_x: 1, delta_y: 0 },
+            _ => EventType::ButtonPress(button_from_code(code)),
+        },
+        xlib::ButtonRelease => match code {
+            4..=7 => return None,
+            _ => EventType::ButtonRelease(button_from_code(code)),
+        },
+        xlib::MotionNotify => EventType::MouseMove { x, y },
+        _ => return None,
+    };
+
+    Some(Event { time: SystemTime::now(), event_type, platform_code: code })
+}
+
+/// Map an X11 button number to our Button type
+fn button_from_code(code: u32) -> Button {
+    match code {
+        1 => Button::Left,
+        2 => Button::Middle,
+        3 => Button::Right,
+        n => Button::Unknown(n as u8),
+    }
+}
@@ -1,7 +1,12 @@
-//! Linux global keyboard grab using X11 XGrabKeyboard
-
-use crate::eventhooks::linux::common::convert_event;
-use crate::eventhooks::types::{Event, GrabError};
+//! Linux global keyboard and pointer grab using X11 XGrabKeyboard/XGrabPointer
+//!
+//! Pointer grabbing is opt-in via `enable_pointer_grab()`. While the pointer is
+//! grabbed, button and motion events go only to the grab callback. Button events
+//! the callback passes through are replayed with XTest; the cursor keeps moving
+//! regardless, so motion events cannot be suppressed.
+
+use crate::eventhooks::linux::common::{convert_event, convert_input_event};
+use crate::eventhooks::types::{Event, EventType, GrabError};
 use mio::{Events, Interest, Poll, Token, unix::SourceFd};
 use std::{
     mem::zeroed,
@@ -10,22 +15,32 @@ use std::{
     sync::{
         Arc, Mutex,
         atomic::{AtomicBool, Ordering},
-        mpsc::{Receiver, Sender, channel},
+        mpsc::{Receiver, RecvTimeoutError, Sender, channel},
     },
     thread,
     time::Duration,
 };
-use x11::xlib::{self, GrabModeAsync, KeyPressMask, KeyReleaseMask, Window};
+use x11::xlib::{
+    self, ButtonPressMask, ButtonReleaseMask, GrabModeAsync, KeyPressMask, KeyReleaseMask,
+    PointerMotionMask, Window,
+};
+use x11::xtest;
 
 // Internal event types
 enum GrabEvent {
     Exit,
     KeyEvent(Event),
+    PointerEvent(Event),
 }
 
 enum GrabControl {
     Grab,
     UnGrab,
+    /// Grab the pointer and report the XGrabPointer status
+    GrabPointer(Sender<c_int>),
+    UnGrabPointer,
+    /// Replay a button event the callback let through (button, is_press)
+    ReplayButton(u32, bool),
     Exit,
 }
 
@@ -51,7 +66,10 @@ lazy_static::lazy_static! {
 
 const KEYPRESS_EVENT: i32 = 2;
 static IS_GRABBING: AtomicBool = AtomicBool::new(false);
+static IS_POINTER_GRABBED: AtomicBool = AtomicBool::new(false);
 const GRAB_RECV: Token = Token(0);
+/// How often to retry the pointer grab after a replayed button press
+const REGRAB_INTERVAL: Duration = Duration::from_millis(20);
 
 impl KeyboardGrabber {
     fn create() -> Result<Self, GrabError> {
@@ -98,6 +116,7 @@ impl Drop for KeyboardGrabber {
     fn drop(&mut self) {
         if !self.display.is_null() {
             ungrab_keys_(self.display);
+            ungrab_pointer_(self.display);
             let _ = unsafe { xlib::XCloseDisplay(self.display) };
         }
     }
@@ -136,6 +155,107 @@ fn ungrab_keys_(display: *mut xlib::Display) {
     }
 }
 
+fn grab_pointer_(display: *mut xlib::Display, grab_window: Window) -> c_int {
+    let event_mask = (ButtonPressMask | ButtonReleaseMask | PointerMotionMask) as u32;
+    unsafe {
+        xlib::XGrabPointer(
+            display,
+            grab_window,
+            c_int::from(true),
+            event_mask,
+            GrabModeAsync,
+            GrabModeAsync,
+            0,
+            0,
+            xlib::CurrentTime,
+        )
+    }
+}
+
+fn grab_pointer(display: Arc<Mutex<u64>>, grab_window: Window) -> c_int {
+    let status = {
+        let lock = display.lock().unwrap();
+        let display = *lock as *mut xlib::Display;
+        let status = grab_pointer_(display, grab_window);
+        if status == xlib::GrabSuccess {
+            IS_POINTER_GRABBED.store(true, Ordering::Relaxed);
+        }
+        unsafe { xlib::XFlush(display) };
+        status
+    };
+    thread::sleep(Duration::from_millis(50));
+    status
+}
+
+/// Take the pointer grab back after a replay, if it is still wanted
+///
+/// Returns false while another client holds the grab.
+fn regrab_pointer(display: Arc<Mutex<u64>>, grab_window: Window) -> bool {
+    if !IS_POINTER_GRABBED.load(Ordering::Relaxed) {
+        return true;
+    }
+    let lock = display.lock().unwrap();
+    let display = *lock as *mut xlib::Display;
+    let status = grab_pointer_(display, grab_window);
+    unsafe { xlib::XFlush(display) };
+    status == xlib::GrabSuccess
+}
+
+fn ungrab_pointer(display: Arc<Mutex<u64>>) {
+    let lock = display.lock().unwrap();
+    let display = *lock as *mut xlib::Display;
+    ungrab_pointer_(display);
+}
+
+fn ungrab_pointer_(display: *mut xlib::Display) {
+    IS_POINTER_GRABBED.store(false, Ordering::Relaxed);
+    unsafe {
+        xlib::XUngrabPointer(display, xlib::CurrentTime);
+        xlib::XFlush(display);
+    }
+}
+
+/// Release the pointer grab just long enough to deliver a faked button event
+///
+/// A replayed press gives the window under the cursor an implicit grab until the
+/// button is released, so grabbing again fails with `AlreadyGrabbed`. Returns
+/// false in that case; the control thread retries until the grab succeeds.
+fn replay_button(
+    display: Arc<Mutex<u64>>,
+    grab_window: Window,
+    button: u32,
+    is_press: bool,
+) -> bool {
+    if !IS_POINTER_GRABBED.load(Ordering::Relaxed) {
+        return true;
+    }
+    {
+        let lock = display.lock().unwrap();
+        let display = *lock as *mut xlib::Display;
+        unsafe {
+            xlib::XUngrabPointer(display, xlib::CurrentTime);
+            xtest::XTestFakeButtonEvent(display, button, c_int::from(is_press), xlib::CurrentTime);
+        }
+    }
+    regrab_pointer(display, grab_window)
+}
+
+/// Ask the control thread to replay a button event that was not suppressed
+fn replay_pointer_event(event: &Event) {
+    let is_press = match event.event_type {
+        EventType::ButtonPress(_) | EventType::Wheel { .. } => true,
+        EventType::ButtonRelease(_) => false,
+        _ => return,
+    };
+    if let Some(sender) = GRAB_CONTROL_SENDER.lock().unwrap().as_ref() {
+        let _ = sender.send(GrabControl::ReplayButton(event.platform_code, is_press));
+        // Wheel clicks only report the press, so release them right away
+        if matches!(event.event_type, EventType::Wheel { .. }) {
+            let _ = sender.send(GrabControl::ReplayButton(event.platform_code, false));
+        }
+    }
+}
+
 fn start_callback_event_thread(recv: Receiver<GrabEvent>) {
     thread::spawn(move || {
         loop {
@@ -148,6 +268,15 @@ fn start_callback_event_thread(recv: Receiver<GrabEvent>) {
                             }
                         }
                     }
+                    GrabEvent::PointerEvent(event) => {
+                        let passed = match GLOBAL_CALLBACK.lock() {
+                            Ok(mut guard) => guard.as_mut().and_then(|callback| callback(event)),
+                            Err(_) => None,
+                        };
+                        if let Some(event) = passed {
+                            replay_pointer_event(&event);
+                        }
+                    }
                     GrabEvent::Exit => break,
                 }
             }
@@ -168,11 +297,33 @@ fn read_x_event(x_event: &mut xlib::XEvent, display: *mut xlib::Display) {
         unsafe {
             xlib::XNextEvent(display, x_event);
         }
-        let keycode = unsafe { x_event.key.keycode };
-        let is_press = unsafe { x_event.type_ == KEYPRESS_EVENT };
-        let event = convert_event(keycode, if is_press { KEYPRESS_EVENT } else { 3 });
-        if let Some(tx) = GRAB_KEY_EVENT_SENDER.lock().unwrap().as_ref() {
-            let _ = tx.send(GrabEvent::KeyEvent(event));
+        let grab_event = match unsafe { x_event.type_ } {
+            xlib::ButtonPress | xlib::ButtonRelease => {
+                let button = unsafe { x_event.button };
+                convert_input_event(
+                    button.type_,
+                    button.button,
+                    button.x_root as f64,
+                    button.y_root as f64,
+                )
+                .map(GrabEvent::PointerEvent)
+            }
+            xlib::MotionNotify => {
+                let motion = unsafe { x_event.motion };
+                convert_input_event(motion.type_, 0, motion.x_root as f64, motion.y_root as f64)
+                    .map(GrabEvent::PointerEvent)
+            }
+            _ => {
+                let keycode = unsafe { x_event.key.keycode };
+                let is_press = unsafe { x_event.type_ == KEYPRESS_EVENT };
+                let event = convert_event(keycode, if is_press { KEYPRESS_EVENT } else { 3 });
+                Some(GrabEvent::KeyEvent(event))
+            }
+        };
+        if let (Some(grab_event), Some(tx)) =
+            (grab_event, GRAB_KEY_EVENT_SENDER.lock().unwrap().as_ref())
+        {
+            let _ = tx.send(grab_event);
         }
     }
 }
@@ -183,8 +334,12 @@ fn start_grab_control_thread(
     rx: Receiver<GrabControl>,
 ) {
     std::thread::spawn(move || {
+        // Set while a replayed press keeps another client's implicit grab active
+        let mut regrab_pending = false;
         loop {
-            match rx.recv() {
+            // Duration::MAX waits without a deadline
+            let timeout = if regrab_pending { REGRAB_INTERVAL } else { Duration::MAX };
+            match rx.recv_timeout(timeout) {
                 Ok(evt) => match evt {
                     GrabControl::Exit => {
                         IS_GRABBING.store(false, Ordering::Relaxed);
@@ -196,7 +351,21 @@ fn start_grab_control_thread(
                     GrabControl::UnGrab => {
                         ungrab_keys(display.clone());
                     }
+                    GrabControl::GrabPointer(reply) => {
+                        let _ = reply.send(grab_pointer(display.clone(), grab_window));
+                    }
+                    GrabControl::UnGrabPointer => {
+                        regrab_pending = false;
+                        ungrab_pointer(display.clone());
+                    }
+                    GrabControl::ReplayButton(button, is_press) => {
+                        regrab_pending =
+                            !replay_button(display.clone(), grab_window, button, is_press);
+                    }
                 },
+                Err(RecvTimeoutError::Timeout) => {
+                    regrab_pending = !regrab_pointer(display.clone(), grab_window);
+                }
                 Err(e) => {
                     log::error!("Failed to receive event: {}", e);
                     break;
@@ -289,6 +458,47 @@ pub fn disable_grab() {
     send_grab_control(GrabControl::UnGrab);
 }
 
+/// Enable pointer (mouse) grabbing
+///
+/// Button, wheel and motion events are delivered to the grab callback. Returning
+/// `None` suppresses a button or wheel event; returning `Some` replays it to other
+/// applications. Only works on X11; Wayland sessions return
+/// `GrabError::WaylandUnsupported` because X clients cannot grab the pointer there.
+///
+/// Returns `GrabError::NotListening` if `start_grab_listen` has not started the
+/// grab thread, and `GrabError::PointerGrabError` if another client holds the
+/// pointer grab.
+pub fn enable_pointer_grab() -> Result<(), GrabError> {
+    if is_wayland_session() {
+        return Err(GrabError::WaylandUnsupported);
+    }
+    let sender = GRAB_CONTROL_SENDER.lock().unwrap().clone().ok_or(GrabError::NotListening)?;
+    let (tx, rx) = channel();
+    sender.send(GrabControl::GrabPointer(tx)).map_err(|_| GrabError::NotListening)?;
+    match rx.recv_timeout(Duration::from_secs(1)) {
+        Ok(xlib::GrabSuccess) => Ok(()),
+        Ok(status) => Err(GrabError::PointerGrabError(status)),
+        Err(_) => Err(GrabError::NotListening),
+    }
+}
+
+/// Disable pointer (mouse) grabbing
+#[inline]
+pub fn disable_pointer_grab() {
+    send_grab_control(GrabControl::UnGrabPointer);
+}
+
+/// Check if the pointer is currently grabbed
+#[inline]
+pub fn is_pointer_grabbed() -> bool {
+    IS_POINTER_GRABBED.load(Ordering::Relaxed)
+}
+
+fn is_wayland_session() -> bool {
+    std::env::var("XDG_SESSION_TYPE").is_ok_and(|t| t.eq_ignore_ascii_case("wayland"))
+        || std::env::var_os("WAYLAND_DISPLAY").is_some()
+}
+
 /// Check if grab is active
 #[inline]
 pub fn is_grabbed() -> bool {
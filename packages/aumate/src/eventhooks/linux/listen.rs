@@ -0,0 +1,182 @@
+//! Linux global event listening using the X11 RECORD extension
+
+use crate::eventhooks::linux::common::convert_input_event;
+use crate::eventhooks::types::{Event, GrabError};
+use std::{
+    mem::size_of,
+    os::raw::{c_char, c_int, c_uchar, c_ulong},
+    ptr,
+    sync::{
+        Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+use x11::{xlib, xrecord};
+
+/// Callback type for listen events
+type ListenCallbackBox = Box<dyn FnMut(Event) + Send>;
+
+static LISTEN_CALLBACK: Mutex<Option<ListenCallbackBox>> = Mutex::new(None);
+static IS_LISTENING: AtomicBool = AtomicBool::new(false);
+
+/// Control connection and record context of the running listener
+struct RecordControl {
+    display: *mut xlib::Display,
+    context: c_ulong,
+}
+
+unsafe impl Send for RecordControl {}
+
+static RECORD_CONTROL: Mutex<Option<RecordControl>> = Mutex::new(None);
+
+/// Wire layout of the core input events delivered by RECORD
+#[repr(C)]
+struct RecordDatum {
+    type_: u8,
+    detail: u8,
+    _sequence: u16,
+    _time: u32,
+    _root: u32,
+    _event: u32,
+    _child: u32,
+    root_x: i16,
+    root_y: i16,
+}
+
+/// Raw callback invoked by XRecordEnableContext
+unsafe extern "C" fn record_callback(
+    _closure: *mut c_char,
+    raw_data: *mut xrecord::XRecordInterceptData,
+) {
+    let Some(data) = (unsafe { raw_data.as_ref() }) else {
+        return;
+    };
+
+    // data_len is measured in 4-byte units
+    if data.category == xrecord::XRecordFromServer
+        && data.data_len as usize * 4 >= size_of::<RecordDatum>()
+    {
+        // SAFETY: RECORD delivers at least one complete xEvent for server data
+        let datum = unsafe { &*(data.data as *const RecordDatum) };
+        if let Some(event) = convert_input_event(
+            datum.type_ as c_int,
+            datum.detail as u32,
+            datum.root_x as f64,
+            datum.root_y as f64,
+        ) {
+            if let Ok(mut guard) = LISTEN_CALLBACK.lock() {
+                if let Some(callback) = guard.as_mut() {
+                    callback(event);
+                }
+            }
+        }
+    }
+
+    unsafe { xrecord::XRecordFreeData(raw_data) };
+}
+
+/// Check if listening is active
+#[inline]
+pub fn is_listening() -> bool {
+    IS_LISTENING.load(Ordering::SeqCst)
+}
+
+/// Start listening for global events without intercepting them
+///
+/// This function blocks until `stop_listen()` is called. Only one listener
+/// can run at a time; a second call fails with `GrabError::AlreadyListening`.
+pub fn listen<T>(callback: T) -> Result<(), GrabError>
+where
+    T: FnMut(Event) + Send + 'static,
+{
+    if IS_LISTENING.swap(true, Ordering::SeqCst) {
+        return Err(GrabError::AlreadyListening);
+    }
+
+    if let Ok(mut guard) = LISTEN_CALLBACK.lock() {
+        *guard = Some(Box::new(callback));
+    }
+
+    let result = unsafe { run_record() };
+
+    if let Ok(mut guard) = LISTEN_CALLBACK.lock() {
+        *guard = None;
+    }
+    IS_LISTENING.store(false, Ordering::SeqCst);
+    result
+}
+
+/// Create a record context and block while it delivers events
+unsafe fn run_record() -> Result<(), GrabError> {
+    // RECORD needs two connections: one to control the context, one to receive data
+    let control = unsafe { xlib::XOpenDisplay(ptr::null()) };
+    if control.is_null() {
+        return Err(GrabError::MissingDisplayError);
+    }
+    let data = unsafe { xlib::XOpenDisplay(ptr::null()) };
+    if data.is_null() {
+        unsafe { xlib::XCloseDisplay(control) };
+        return Err(GrabError::MissingDisplayError);
+    }
+
+    let close_displays = || unsafe {
+        xlib::XCloseDisplay(data);
+        xlib::XCloseDisplay(control);
+    };
+
+    let (mut major, mut minor) = (0, 0);
+    if unsafe { xrecord::XRecordQueryVersion(control, &mut major, &mut minor) } == 0 {
+        close_displays();
+        return Err(GrabError::RecordExtensionError);
+    }
+
+    let mut range = unsafe { xrecord::XRecordAllocRange() };
+    if range.is_null() {
+        close_displays();
+        return Err(GrabError::RecordContextError);
+    }
+    unsafe {
+        (*range).device_events.first = xlib::KeyPress as c_uchar;
+        (*range).device_events.last = xlib::MotionNotify as c_uchar;
+    }
+
+    let mut clients = xrecord::XRecordAllClients;
+    let context =
+        unsafe { xrecord::XRecordCreateContext(control, 0, &mut clients, 1, &mut range, 1) };
+    unsafe { xlib::XFree(range as *mut _) };
+    if context == 0 {
+        close_displays();
+        return Err(GrabError::RecordContextError);
+    }
+    unsafe { xlib::XSync(control, xlib::False) };
+
+    if let Ok(mut guard) = RECORD_CONTROL.lock() {
+        *guard = Some(RecordControl { display: control, context });
+    }
+
+    // Blocks until stop_listen() disables the context
+    let enabled = unsafe {
+        xrecord::XRecordEnableContext(data, context, Some(record_callback), ptr::null_mut())
+    };
+
+    if let Ok(mut guard) = RECORD_CONTROL.lock() {
+        *guard = None;
+    }
+    unsafe { xrecord::XRecordFreeContext(control, context) };
+    close_displays();
+
+    if enabled == 0 { Err(GrabError::RecordContextError) } else { Ok(()) }
+}
+
+/// Stop listening for events
+pub fn stop_listen() -> Result<(), GrabError> {
+    if let Ok(guard) = RECORD_CONTROL.lock() {
+        if let Some(control) = guard.as_ref() {
+            unsafe {
+                xrecord::XRecordDisableContext(control.display, control.context);
+                xlib::XFlush(control.display);
+            }
+        }
+    }
+    Ok(())
+}
@@ -2,5 +2,12 @@
 
 mod common;
 mod grab;
+mod listen;
+mod state;
 
-pub use grab::{disable_grab, enable_grab, exit_grab_listen, is_grabbed, start_grab_listen};
+pub use grab::{
+    disable_grab, disable_pointer_grab, enable_grab, enable_pointer_grab, exit_grab_listen,
+    is_grabbed, is_pointer_grabbed, start_grab_listen,
+};
+pub use listen::{is_listening, listen, stop_listen};
+pub use state::{get_lock_state, get_modifier_state};
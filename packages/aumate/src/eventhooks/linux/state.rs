@@ -0,0 +1,92 @@
+//! Keyboard lock and modifier state via X11
+
+use crate::eventhooks::types::{GrabError, LockState, ModifierState};
+use std::os::raw::c_uint;
+use std::ptr::null;
+use x11::xlib;
+
+/// Xkb device spec for the core keyboard
+const XKB_USE_CORE_KBD: c_uint = 0x0100;
+/// Conventional Xkb indicator bit for Scroll Lock
+const SCROLL_LOCK_INDICATOR: c_uint = 1 << 2;
+
+/// Get the lock key state
+///
+/// Caps Lock and Num Lock come from the core modifier mask (Num Lock is
+/// assumed to be bound to Mod2, the X11 default). Scroll Lock is read from its
+/// keyboard indicator, which most layouts but not all provide.
+pub fn get_lock_state() -> Result<LockState, GrabError> {
+    query(lock_state_from)
+}
+
+/// Get the modifier keys currently held down
+pub fn get_modifier_state() -> Result<ModifierState, GrabError> {
+    query(|mask, _| modifier_state_from(mask))
+}
+
+/// Read the pointer modifier mask and the Xkb indicator bits
+fn query<T>(map: impl FnOnce(c_uint, c_uint) -> T) -> Result<T, GrabError> {
+    unsafe {
+        let display = xlib::XOpenDisplay(null());
+        if display.is_null() {
+            return Err(GrabError::MissingDisplayError);
+        }
+
+        let root = xlib::XDefaultRootWindow(display);
+        let (mut root_return, mut child_return) = (0, 0);
+        let (mut root_x, mut root_y, mut win_x, mut win_y) = (0, 0, 0, 0);
+        let mut mask: c_uint = 0;
+        xlib::XQueryPointer(
+            display,
+            root,
+            &mut root_return,
+            &mut child_return,
+            &mut root_x,
+            &mut root_y,
+            &mut win_x,
+            &mut win_y,
+            &mut mask,
+        );
+
+        let mut indicators: c_uint = 0;
+        xlib::XkbGetIndicatorState(display, XKB_USE_CORE_KBD, &mut indicators);
+
+        xlib::XCloseDisplay(display);
+        Ok(map(mask, indicators))
+    }
+}
+
+fn lock_state_from(mask: c_uint, indicators: c_uint) -> LockState {
+    LockState {
+        caps: mask & xlib::LockMask != 0,
+        num: mask & xlib::Mod2Mask != 0,
+        scroll: indicators & SCROLL_LOCK_INDICATOR != 0,
+    }
+}
+
+fn modifier_state_from(mask: c_uint) -> ModifierState {
+    ModifierState {
+        shift: mask & xlib::ShiftMask != 0,
+        ctrl: mask & xlib::ControlMask != 0,
+        alt: mask & xlib::Mod1Mask != 0,
+        meta: mask & xlib::Mod4Mask != 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lock_state_from_mask() {
+        let state = lock_state_from(xlib::LockMask | xlib::Mod2Mask, SCROLL_LOCK_INDICATOR);
+        assert_eq!(state, LockState { caps: true, num: true, scroll: true });
+        assert_eq!(lock_state_from(xlib::ShiftMask, 0), LockState::default());
+    }
+
+    #[test]
+    fn test_modifier_state_from_mask() {
+        let state = modifier_state_from(xlib::ShiftMask | xlib::Mod4Mask | xlib::LockMask);
+        assert_eq!(state, ModifierState { shift: true, ctrl: false, alt: false, meta: true });
+    }
+}
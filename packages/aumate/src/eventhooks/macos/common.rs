@@ -31,7 +31,6 @@ pub const kCGHeadInsertEventTap: u32 = 0;
 #[repr(u32)]
 pub enum CGEventTapOption {
     Default = 0,
-    #[allow(dead_code)]
     ListenOnly = 1,
 }
 
@@ -79,6 +78,8 @@ unsafe extern "C" {
     pub fn CGEventTapEnable(tap: CFMachPortRef, enable: bool);
     pub fn CFRunLoopRun();
     pub fn CFRunLoopStop(rl: CFRunLoopRef);
+    pub fn CFMachPortInvalidate(port: CFMachPortRef);
+    pub fn CFRelease(cf: *const c_void);
 
     pub static kCFRunLoopCommonModes: CFRunLoopMode;
 }
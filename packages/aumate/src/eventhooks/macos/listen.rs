@@ -0,0 +1,122 @@
+//! macOS global event listening using a listen-only CGEventTap
+#![allow(improper_ctypes_definitions)]
+
+use crate::eventhooks::macos::common::*;
+use crate::eventhooks::types::{Event, GrabError};
+use cocoa::base::nil;
+use cocoa::foundation::NSAutoreleasePool;
+use core_graphics::event::{CGEventTapLocation, CGEventType};
+use std::os::raw::c_void;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Callback type for listen events
+type ListenCallbackBox = Box<dyn FnMut(Event) + Send>;
+
+// Global callback storage using Mutex for safe access
+static LISTEN_CALLBACK: Mutex<Option<ListenCallbackBox>> = Mutex::new(None);
+static IS_LISTENING: AtomicBool = AtomicBool::new(false);
+
+// Current run loop for stopping
+static mut LISTEN_LOOP: CFRunLoopRef = std::ptr::null_mut();
+
+/// Raw callback invoked by CGEventTap
+unsafe extern "C" fn raw_callback(
+    _proxy: CGEventTapProxy,
+    _type: CGEventType,
+    cg_event: CGEventRef,
+    _user_info: *mut c_void,
+) -> CGEventRef {
+    // SAFETY: convert is unsafe, we're in an unsafe extern fn context
+    if let Some(event) = unsafe { convert(_type, &cg_event) } {
+        if let Ok(mut guard) = LISTEN_CALLBACK.lock() {
+            if let Some(callback) = guard.as_mut() {
+                callback(event);
+            }
+        }
+    }
+    // Listen-only taps cannot modify or drop events
+    cg_event
+}
+
+/// Check if listening is active
+#[inline]
+pub fn is_listening() -> bool {
+    IS_LISTENING.load(Ordering::SeqCst)
+}
+
+/// Start listening for global events without intercepting them
+///
+/// This function blocks until `stop_listen()` is called. Only one listener
+/// can run at a time; a second call fails with `GrabError::AlreadyListening`.
+pub fn listen<T>(callback: T) -> Result<(), GrabError>
+where
+    T: FnMut(Event) + Send + 'static,
+{
+    if IS_LISTENING.swap(true, Ordering::SeqCst) {
+        return Err(GrabError::AlreadyListening);
+    }
+
+    if let Ok(mut guard) = LISTEN_CALLBACK.lock() {
+        *guard = Some(Box::new(callback));
+    }
+
+    let result = unsafe { run_tap() };
+
+    if let Ok(mut guard) = LISTEN_CALLBACK.lock() {
+        *guard = None;
+    }
+    IS_LISTENING.store(false, Ordering::SeqCst);
+    result
+}
+
+/// Create a listen-only event tap and run the current run loop until stopped
+unsafe fn run_tap() -> Result<(), GrabError> {
+    unsafe {
+        let _pool = NSAutoreleasePool::new(nil);
+
+        let tap = CGEventTapCreate(
+            CGEventTapLocation::Session,
+            kCGHeadInsertEventTap,
+            CGEventTapOption::ListenOnly,
+            kCGEventMaskForAllEvents,
+            raw_callback,
+            nil,
+        );
+
+        if tap.is_null() {
+            return Err(GrabError::EventTapError);
+        }
+
+        let loop_source = CFMachPortCreateRunLoopSource(nil, tap, 0);
+        if loop_source.is_null() {
+            CFMachPortInvalidate(tap);
+            CFRelease(tap);
+            return Err(GrabError::LoopSourceError);
+        }
+
+        LISTEN_LOOP = CFRunLoopGetCurrent() as _;
+        CFRunLoopAddSource(LISTEN_LOOP, loop_source, kCFRunLoopCommonModes);
+
+        CGEventTapEnable(tap, true);
+        CFRunLoopRun();
+
+        // Invalidating the tap also removes its source from the run loop
+        LISTEN_LOOP = std::ptr::null_mut();
+        CFMachPortInvalidate(tap);
+        CFRelease(loop_source as *const c_void);
+        CFRelease(tap);
+    }
+    Ok(())
+}
+
+/// Stop listening for events
+pub fn stop_listen() -> Result<(), GrabError> {
+    unsafe {
+        if !LISTEN_LOOP.is_null() {
+            CFRunLoopStop(LISTEN_LOOP);
+            LISTEN_LOOP = std::ptr::null_mut();
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,39 @@
+//! Keyboard lock and modifier state via Quartz event sources
+
+use crate::eventhooks::types::{GrabError, LockState, ModifierState};
+use core_graphics::event::CGEventFlags;
+
+/// kCGEventSourceStateCombinedSessionState
+const COMBINED_SESSION_STATE: i32 = 0;
+
+#[link(name = "CoreGraphics", kind = "framework")]
+unsafe extern "C" {
+    fn CGEventSourceFlagsState(state_id: i32) -> u64;
+}
+
+/// Get the lock key state
+///
+/// Macs have no Num Lock or Scroll Lock keys, so only `caps` is ever set.
+pub fn get_lock_state() -> Result<LockState, GrabError> {
+    let flags = current_flags();
+    Ok(LockState {
+        caps: flags.contains(CGEventFlags::CGEventFlagAlphaShift),
+        num: false,
+        scroll: false,
+    })
+}
+
+/// Get the modifier keys currently held down
+pub fn get_modifier_state() -> Result<ModifierState, GrabError> {
+    let flags = current_flags();
+    Ok(ModifierState {
+        shift: flags.contains(CGEventFlags::CGEventFlagShift),
+        ctrl: flags.contains(CGEventFlags::CGEventFlagControl),
+        alt: flags.contains(CGEventFlags::CGEventFlagAlternate),
+        meta: flags.contains(CGEventFlags::CGEventFlagCommand),
+    })
+}
+
+fn current_flags() -> CGEventFlags {
+    CGEventFlags::from_bits_truncate(unsafe { CGEventSourceFlagsState(COMBINED_SESSION_STATE) })
+}
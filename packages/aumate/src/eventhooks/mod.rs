@@ -2,6 +2,7 @@
 //!
 //! This module provides cross-platform global event interception (grab) functionality.
 //! It allows intercepting keyboard and mouse events before they reach other applications.
+//! A passive listen mode is also available for observing events without blocking them.
 //!
 //! # Usage
 //!
@@ -23,19 +24,44 @@
 //! }
 //! ```
 //!
+//! To only observe events (e.g. for logging or macro recording), use `listen`.
+//! Its callback cannot suppress events:
+//!
+//! ```no_run
+//! use aumate::eventhooks::{Event, listen};
+//!
+//! // This blocks until stop_listen() is called
+//! if let Err(e) = listen(|event: Event| println!("{:?}", event.event_type)) {
+//!     eprintln!("Failed to listen: {:?}", e);
+//! }
+//! ```
+//!
 //! # Platform Notes
 //!
 //! ## macOS
 //! - Requires Accessibility permissions (System Preferences > Security & Privacy > Privacy > Accessibility)
-//! - Uses CGEventTap for event interception
+//! - Uses CGEventTap for event interception (a listen-only tap for `listen`)
 //!
 //! ## Linux
-//! - Uses X11 XGrabKeyboard (keyboard only)
+//! - Uses X11 XGrabKeyboard; call `enable_pointer_grab()` to also grab the mouse
+//!   via XGrabPointer. Suppressed button events are dropped, passed-through ones
+//!   are replayed with XTest. Motion cannot be suppressed.
+//! - X11 only: pointer grab returns `GrabError::WaylandUnsupported` on Wayland
+//! - `listen` uses the X11 RECORD extension and reports keyboard and mouse events
 //! - Requires user to be in `input` group for full functionality
 //!
 //! ## Windows
 //! - Uses low-level hooks (SetWindowsHookEx with WH_KEYBOARD_LL and WH_MOUSE_LL)
+//! - `listen` installs the same hooks but always passes events on
 //! - Works without special permissions
+//!
+//! # Key State
+//!
+//! `get_lock_state` and `get_modifier_state` are best-effort snapshots:
+//! - macOS reports Caps Lock only; Num Lock and Scroll Lock do not exist there
+//! - Linux assumes Num Lock is bound to Mod2 and reads Scroll Lock from its
+//!   keyboard indicator, which some layouts lack
+//! - Windows reports all three lock keys and physically held modifiers
 
 mod keycodes;
 mod types;
@@ -48,15 +74,25 @@ mod macos;
 mod windows;
 
 // Re-export types
-pub use types::{Button, Event, EventType, GrabCallback, GrabError, Key};
+pub use types::{
+    Button, Event, EventType, GrabCallback, GrabError, Key, ListenCallback, LockState,
+    ModifierState,
+};
 
 // Platform-specific re-exports
 
 #[cfg(target_os = "macos")]
-pub use macos::{exit_grab, grab, is_grabbed};
+pub use macos::{
+    exit_grab, get_lock_state, get_modifier_state, grab, is_grabbed, is_listening, listen,
+    stop_listen,
+};
 
 #[cfg(target_os = "linux")]
-pub use linux::{disable_grab, enable_grab, exit_grab_listen, is_grabbed, start_grab_listen};
+pub use linux::{
+    disable_grab, disable_pointer_grab, enable_grab, enable_pointer_grab, exit_grab_listen,
+    get_lock_state, get_modifier_state, is_grabbed, is_listening, is_pointer_grabbed, listen,
+    start_grab_listen, stop_listen,
+};
 
 // Convenience aliases for Linux to match other platforms
 #[cfg(target_os = "linux")]
@@ -65,4 +101,7 @@ pub use linux::exit_grab_listen as exit_grab;
 pub use linux::start_grab_listen as grab;
 
 #[cfg(target_os = "windows")]
-pub use windows::{exit_grab, grab, is_grabbed};
+pub use windows::{
+    exit_grab, get_lock_state, get_modifier_state, grab, is_grabbed, is_listening, listen,
+    stop_listen,
+};
@@ -8,6 +8,10 @@ use std::time::SystemTime;
 /// Return `None` to consume the event, `Some(event)` to let it pass through.
 pub type GrabCallback = fn(event: Event) -> Option<Event>;
 
+/// Callback type for listen function.
+/// Events are observed only and always reach other applications.
+pub type ListenCallback = fn(event: Event);
+
 /// Errors that occur when trying to grab OS events.
 #[derive(Debug)]
 #[non_exhaustive]
@@ -24,6 +28,18 @@ pub enum GrabError {
     InvalidFileDescriptor,
     /// Linux: Keyboard error
     KeyboardError,
+    /// Linux: X11 RECORD extension is not available
+    RecordExtensionError,
+    /// Linux: Failed to create or enable the record context
+    RecordContextError,
+    /// Linux: Pointer grabbing is not possible in a Wayland session
+    WaylandUnsupported,
+    /// Linux: XGrabPointer failed with this status, e.g. another client holds a grab
+    PointerGrabError(i32),
+    /// Linux: No grab listener is running
+    NotListening,
+    /// `listen` is already running in this process
+    AlreadyListening,
     /// Windows: Key hook error
     KeyHookError(u32),
     /// Windows: Mouse hook error
@@ -237,3 +253,22 @@ impl Event {
         Self { time: SystemTime::now(), event_type, platform_code }
     }
 }
+
+/// State of the keyboard lock keys
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct LockState {
+    pub caps: bool,
+    pub num: bool,
+    pub scroll: bool,
+}
+
+/// Modifier keys currently held down
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct ModifierState {
+    pub shift: bool,
+    pub ctrl: bool,
+    /// Alt key (Option on macOS)
+    pub alt: bool,
+    /// Command key on macOS, Windows/Super key elsewhere
+    pub meta: bool,
+}
@@ -0,0 +1,145 @@
+//! Windows global event listening using low-level hooks
+//!
+//! Uses the same hooks as grab, but always passes events on to the next hook.
+
+use crate::eventhooks::types::{Event, GrabError};
+use crate::eventhooks::windows::common::{convert, get_scan_code};
+use std::io::Error;
+use std::mem::zeroed;
+use std::ptr::null_mut;
+use std::sync::Mutex;
+use std::time::SystemTime;
+use winapi::shared::minwindef::{DWORD, FALSE, LPARAM, LRESULT, WPARAM};
+use winapi::shared::ntdef::NULL;
+use winapi::um::errhandlingapi::GetLastError;
+use winapi::um::processthreadsapi::GetCurrentThreadId;
+use winapi::um::winuser::{
+    CallNextHookEx, DispatchMessageA, GetMessageA, HC_ACTION, MSG, PostThreadMessageA,
+    SetWindowsHookExA, TranslateMessage, UnhookWindowsHookEx, WH_KEYBOARD_LL, WH_MOUSE_LL, WM_USER,
+};
+
+/// Callback type for listen events
+type ListenCallbackBox = Box<dyn FnMut(Event) + Send>;
+
+static LISTEN_CALLBACK: Mutex<Option<ListenCallbackBox>> = Mutex::new(None);
+
+lazy_static::lazy_static! {
+    static ref CUR_LISTEN_THREAD_ID: Mutex<DWORD> = Mutex::new(0);
+}
+
+const WM_USER_EXIT_LISTEN: u32 = WM_USER + 2;
+
+/// Raw callback for processing hook events
+unsafe fn raw_callback(code: i32, param: WPARAM, lpdata: LPARAM, is_keyboard: bool) -> LRESULT {
+    if code == HC_ACTION as i32 {
+        let (opt, _) = unsafe { convert(param, lpdata) };
+        if let Some(event_type) = opt {
+            let platform_code = if is_keyboard { unsafe { get_scan_code(lpdata) } } else { 0 };
+            let event = Event { event_type, time: SystemTime::now(), platform_code };
+            if let Ok(mut guard) = LISTEN_CALLBACK.lock() {
+                if let Some(callback) = guard.as_mut() {
+                    callback(event);
+                }
+            }
+        }
+    }
+    unsafe { CallNextHookEx(null_mut(), code, param, lpdata) }
+}
+
+/// Mouse hook callback
+unsafe extern "system" fn raw_callback_mouse(code: i32, param: WPARAM, lpdata: LPARAM) -> LRESULT {
+    unsafe { raw_callback(code, param, lpdata, false) }
+}
+
+/// Keyboard hook callback
+unsafe extern "system" fn raw_callback_keyboard(
+    code: i32,
+    param: WPARAM,
+    lpdata: LPARAM,
+) -> LRESULT {
+    unsafe { raw_callback(code, param, lpdata, true) }
+}
+
+/// Check if listening is active
+#[inline]
+pub fn is_listening() -> bool {
+    *CUR_LISTEN_THREAD_ID.lock().unwrap() != 0
+}
+
+/// Start listening for global events without intercepting them
+///
+/// This function blocks until `stop_listen()` is called. Only one listener
+/// can run at a time; a second call fails with `GrabError::AlreadyListening`.
+pub fn listen<T>(callback: T) -> Result<(), GrabError>
+where
+    T: FnMut(Event) + Send + 'static,
+{
+    let (hook_keyboard, hook_mouse) = {
+        let mut cur_thread_id = CUR_LISTEN_THREAD_ID.lock().unwrap();
+        if *cur_thread_id != 0 {
+            return Err(GrabError::AlreadyListening);
+        }
+
+        *LISTEN_CALLBACK.lock().unwrap() = Some(Box::new(callback));
+
+        unsafe {
+            let hook_keyboard =
+                SetWindowsHookExA(WH_KEYBOARD_LL, Some(raw_callback_keyboard), null_mut(), 0);
+            if hook_keyboard.is_null() {
+                let error = GetLastError();
+                *LISTEN_CALLBACK.lock().unwrap() = None;
+                return Err(GrabError::KeyHookError(error));
+            }
+
+            let hook_mouse =
+                SetWindowsHookExA(WH_MOUSE_LL, Some(raw_callback_mouse), null_mut(), 0);
+            if hook_mouse.is_null() {
+                let error = GetLastError();
+                if FALSE == UnhookWindowsHookEx(hook_keyboard) {
+                    log::error!("UnhookWindowsHookEx keyboard: {}", Error::last_os_error());
+                }
+                *LISTEN_CALLBACK.lock().unwrap() = None;
+                return Err(GrabError::MouseHookError(error));
+            }
+
+            *cur_thread_id = GetCurrentThreadId();
+            (hook_keyboard, hook_mouse)
+        }
+    };
+
+    unsafe {
+        let mut msg: MSG = zeroed();
+        while FALSE != GetMessageA(&mut msg, NULL as _, 0, 0) {
+            if msg.message == WM_USER_EXIT_LISTEN {
+                break;
+            }
+            TranslateMessage(&msg);
+            DispatchMessageA(&msg);
+        }
+
+        if FALSE == UnhookWindowsHookEx(hook_keyboard) {
+            log::error!("Failed UnhookWindowsHookEx keyboard: {}", Error::last_os_error());
+        }
+        if FALSE == UnhookWindowsHookEx(hook_mouse) {
+            log::error!("Failed UnhookWindowsHookEx mouse: {}", Error::last_os_error());
+        }
+    }
+
+    *CUR_LISTEN_THREAD_ID.lock().unwrap() = 0;
+    *LISTEN_CALLBACK.lock().unwrap() = None;
+    Ok(())
+}
+
+/// Stop listening for events
+pub fn stop_listen() -> Result<(), GrabError> {
+    let cur_thread_id = CUR_LISTEN_THREAD_ID.lock().unwrap();
+    if *cur_thread_id != 0 {
+        if FALSE == unsafe { PostThreadMessageA(*cur_thread_id, WM_USER_EXIT_LISTEN, 0, 0) } {
+            return Err(GrabError::ExitGrabError(format!(
+                "Failed to post exit message: {}",
+                unsafe { GetLastError() }
+            )));
+        }
+    }
+    Ok(())
+}
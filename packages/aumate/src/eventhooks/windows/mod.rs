@@ -2,5 +2,9 @@
 
 mod common;
 mod grab;
+mod listen;
+mod state;
 
 pub use grab::{exit_grab, grab, is_grabbed};
+pub use listen::{is_listening, listen, stop_listen};
+pub use state::{get_lock_state, get_modifier_state};
@@ -0,0 +1,36 @@
+//! Keyboard lock and modifier state via Win32 key state
+
+use crate::eventhooks::types::{GrabError, LockState, ModifierState};
+use winapi::um::winuser::{
+    GetAsyncKeyState, GetKeyState, VK_CAPITAL, VK_CONTROL, VK_LWIN, VK_MENU, VK_NUMLOCK, VK_RWIN,
+    VK_SCROLL, VK_SHIFT,
+};
+
+/// Get the lock key state
+pub fn get_lock_state() -> Result<LockState, GrabError> {
+    Ok(LockState {
+        caps: toggled(VK_CAPITAL),
+        num: toggled(VK_NUMLOCK),
+        scroll: toggled(VK_SCROLL),
+    })
+}
+
+/// Get the modifier keys currently held down
+pub fn get_modifier_state() -> Result<ModifierState, GrabError> {
+    Ok(ModifierState {
+        shift: held(VK_SHIFT),
+        ctrl: held(VK_CONTROL),
+        alt: held(VK_MENU),
+        meta: held(VK_LWIN) || held(VK_RWIN),
+    })
+}
+
+/// Whether a toggle key (Caps Lock etc.) is on
+fn toggled(vk: i32) -> bool {
+    unsafe { GetKeyState(vk) & 1 != 0 }
+}
+
+/// Whether a key is physically held down right now
+fn held(vk: i32) -> bool {
+    unsafe { (GetAsyncKeyState(vk) as u16) & 0x8000 != 0 }
+}
@@ -15,6 +15,10 @@ pub struct MatchConfig {
     pub limit: usize,
     /// Use parallel processing (default: true)
     pub parallel: bool,
+    /// Template rotation angles in degrees to search (default: [0.0])
+    pub angles: Vec<f32>,
+    /// IoU above which overlapping matches are merged into the best one (default: 0.5)
+    pub nms_iou: f32,
 }
 
 impl Default for MatchConfig {
@@ -26,6 +30,8 @@ impl Default for MatchConfig {
             confidence: 0.8,
             limit: 100,
             parallel: true,
+            angles: vec![0.0],
+            nms_iou: 0.5,
         }
     }
 }
@@ -71,6 +77,18 @@ impl MatchConfig {
         self.parallel = enabled;
         self
     }
+
+    /// Set template rotation angles (degrees)
+    pub fn with_angles(mut self, angles: Vec<f32>) -> Self {
+        self.angles = angles;
+        self
+    }
+
+    /// Set non-maximum suppression IoU threshold
+    pub fn with_nms_iou(mut self, iou: f32) -> Self {
+        self.nms_iou = iou.clamp(0.0, 1.0);
+        self
+    }
 }
 
 #[cfg(test)]
@@ -86,6 +104,8 @@ mod tests {
         assert_eq!(config.limit, 100);
         assert!(config.parallel);
         assert!(!config.scale_steps.is_empty());
+        assert_eq!(config.angles, vec![0.0]);
+        assert_eq!(config.nms_iou, 0.5);
     }
 
     #[test]
@@ -94,12 +114,16 @@ mod tests {
             .with_confidence(0.9)
             .with_multi_scale(false)
             .with_grayscale(true)
-            .with_limit(10);
+            .with_limit(10)
+            .with_angles(vec![0.0, 90.0])
+            .with_nms_iou(0.3);
 
         assert_eq!(config.confidence, 0.9);
         assert!(!config.search_multiple_scales);
         assert!(config.use_grayscale);
         assert_eq!(config.limit, 10);
+        assert_eq!(config.angles, vec![0.0, 90.0]);
+        assert_eq!(config.nms_iou, 0.3);
     }
 
     #[test]
@@ -2,8 +2,12 @@
 
 use super::{MatchConfig, MatchResult};
 use crate::error::Result;
-use image::{DynamicImage, GrayImage, ImageBuffer, Luma, imageops::FilterType};
-use imageproc::template_matching::{MatchTemplateMethod, match_template};
+use image::{DynamicImage, GrayImage, ImageBuffer, Luma, imageops, imageops::FilterType};
+use imageproc::geometric_transformations::{Interpolation, Projection, warp_into};
+use imageproc::template_matching::{MatchTemplateMethod, match_template, match_template_with_mask};
+
+/// Number of scale/angle combinations above which a search is considered expensive
+const MAX_SEARCH_COMBINATIONS: usize = 36;
 
 /// Image template matcher
 pub struct ImageMatcher;
@@ -29,28 +33,37 @@ impl ImageMatcher {
         let screen_gray = screen.to_luma8();
         let template_gray = template.to_luma8();
 
-        let original_width = template_gray.width();
-        let original_height = template_gray.height();
-
         let scales =
             if config.search_multiple_scales { config.scale_steps.clone() } else { vec![1.0] };
+        let angles = if config.angles.is_empty() { vec![0.0] } else { config.angles.clone() };
+
+        let combinations = scales.len() * angles.len();
+        if combinations > MAX_SEARCH_COMBINATIONS {
+            log::warn!(
+                "Template search covers {} scales x {} angles ({} passes), matching may be slow",
+                scales.len(),
+                angles.len(),
+                combinations
+            );
+        }
 
         let mut all_matches = Vec::new();
 
-        for scale in scales {
-            let matches = Self::find_at_scale(
-                &screen_gray,
-                &template_gray,
-                scale,
-                original_width,
-                original_height,
-                config,
-            )?;
-            all_matches.extend(matches);
+        for &angle in &angles {
+            let (rotated, mask) = Self::rotate_template(&template_gray, angle);
+
+            for &scale in &scales {
+                let matches =
+                    Self::find_at_scale(&screen_gray, &rotated, mask.as_ref(), scale, config)?;
+                all_matches.extend(matches.into_iter().map(|mut m| {
+                    m.angle = angle;
+                    m
+                }));
+            }
         }
 
         // Apply Non-Maximum Suppression
-        let matches = Self::non_max_suppression(all_matches, 0.5);
+        let matches = Self::non_max_suppression(all_matches, config.nms_iou);
 
         // Sort by confidence descending and limit
         let mut matches: Vec<_> =
@@ -63,12 +76,52 @@ impl ImageMatcher {
         Ok(matches)
     }
 
+    /// Rotate `template` clockwise by `angle` degrees onto a canvas that holds
+    /// all of it
+    ///
+    /// Quarter turns are exact. Other angles also return a mask of the canvas
+    /// pixels covered by the template, so the fill in the corners is left out
+    /// of the score.
+    fn rotate_template(template: &GrayImage, angle: f32) -> (GrayImage, Option<GrayImage>) {
+        let degrees = angle.rem_euclid(360.0);
+        let quarter_turns = (degrees / 90.0).round();
+        if (degrees - quarter_turns * 90.0).abs() < 0.001 {
+            let rotated = match quarter_turns as u32 % 4 {
+                0 => template.clone(),
+                1 => imageops::rotate90(template),
+                2 => imageops::rotate180(template),
+                _ => imageops::rotate270(template),
+            };
+            return (rotated, None);
+        }
+
+        let (width, height) = (template.width() as f32, template.height() as f32);
+        let (sin, cos) = angle.to_radians().sin_cos();
+        let canvas_width = (width * cos.abs() + height * sin.abs()).ceil() as u32;
+        let canvas_height = (width * sin.abs() + height * cos.abs()).ceil() as u32;
+
+        let projection =
+            Projection::translate(canvas_width as f32 / 2.0, canvas_height as f32 / 2.0)
+                * Projection::rotate(angle.to_radians())
+                * Projection::translate(-width / 2.0, -height / 2.0);
+        let warp = |image: &GrayImage| {
+            let mut out = GrayImage::new(canvas_width, canvas_height);
+            warp_into(image, &projection, Interpolation::Bilinear, Luma([0]), &mut out);
+            out
+        };
+
+        // Bilinear sampling yields the fill for any pixel that reaches outside
+        // the template, so the warped opaque image is non-zero only where the
+        // template landed
+        let opaque = GrayImage::from_pixel(template.width(), template.height(), Luma([255]));
+        (warp(template), Some(warp(&opaque)))
+    }
+
     fn find_at_scale(
         screen: &GrayImage,
         template: &GrayImage,
+        mask: Option<&GrayImage>,
         scale: f32,
-        original_width: u32,
-        original_height: u32,
         config: &MatchConfig,
     ) -> Result<Vec<MatchResult>> {
         // Calculate scaled dimensions
@@ -84,21 +137,35 @@ impl ImageMatcher {
         }
 
         // Resize template
-        let scaled_template = if (scale - 1.0).abs() < 0.001 {
+        let unscaled = (scale - 1.0).abs() < 0.001;
+        let scaled_template = if unscaled {
             template.clone()
         } else {
-            image::imageops::resize(template, scaled_width, scaled_height, FilterType::Triangle)
+            imageops::resize(template, scaled_width, scaled_height, FilterType::Triangle)
         };
 
-        // Run template matching using NCC
-        let result = match_template(
-            screen,
-            &scaled_template,
-            MatchTemplateMethod::CrossCorrelationNormalized,
-        );
+        // Run template matching using NCC, ignoring pixels outside the mask
+        let method = MatchTemplateMethod::CrossCorrelationNormalized;
+        let result = match mask {
+            Some(mask) if unscaled => {
+                match_template_with_mask(screen, &scaled_template, method, mask)
+            }
+            Some(mask) => {
+                let scaled_mask =
+                    imageops::resize(mask, scaled_width, scaled_height, FilterType::Nearest);
+                match_template_with_mask(screen, &scaled_template, method, &scaled_mask)
+            }
+            None => match_template(screen, &scaled_template, method),
+        };
 
         // Extract matches above threshold
-        Self::extract_matches(&result, original_width, original_height, scale, config.confidence)
+        Self::extract_matches(
+            &result,
+            template.width(),
+            template.height(),
+            scale,
+            config.confidence,
+        )
     }
 
     fn extract_matches(
@@ -163,6 +230,7 @@ impl ImageMatcher {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::image_match::tests::noise_image;
     use image::{DynamicImage, Rgb, RgbImage};
 
     fn create_test_image(width: u32, height: u32, color: [u8; 3]) -> DynamicImage {
@@ -235,6 +303,94 @@ mod tests {
         assert!(result.is_none() || result.as_ref().map(|r| r.confidence < 0.95).unwrap_or(false));
     }
 
+    #[test]
+    fn test_find_rotated_template() {
+        let patch = noise_image(16, 16, 42);
+
+        // Screen contains the patch rotated by a quarter turn
+        let mut screen = GrayImage::from_pixel(80, 80, Luma([128]));
+        image::imageops::replace(&mut screen, &image::imageops::rotate90(&patch), 30, 20);
+        let screen = DynamicImage::ImageLuma8(screen);
+        let template = DynamicImage::ImageLuma8(patch);
+
+        let config = MatchConfig {
+            search_multiple_scales: false,
+            confidence: 0.9,
+            angles: vec![0.0, 90.0, 270.0],
+            ..Default::default()
+        };
+
+        let best = ImageMatcher::find(&screen, &template, &config).unwrap().expect("rotated match");
+        assert!(best.angle == 90.0 || best.angle == 270.0, "unexpected angle {}", best.angle);
+        assert!((best.x as i32 - 30).abs() <= 1 && (best.y as i32 - 20).abs() <= 1);
+
+        let upright = MatchConfig { angles: vec![0.0], ..config };
+        assert!(ImageMatcher::find(&screen, &template, &upright).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_find_template_rotated_45_degrees() {
+        let patch = noise_image(24, 12, 7);
+
+        // Paste only the rotated pixels, leaving the corners as background
+        let (rotated, mask) = ImageMatcher::rotate_template(&patch, 45.0);
+        let mask = mask.expect("mask for a non-quarter turn");
+        assert_eq!(rotated.dimensions(), (26, 26));
+        let mut screen = GrayImage::from_pixel(90, 70, Luma([200]));
+        for (x, y, pixel) in rotated.enumerate_pixels() {
+            if mask.get_pixel(x, y).0[0] > 0 {
+                screen.put_pixel(x + 40, y + 25, *pixel);
+            }
+        }
+        let screen = DynamicImage::ImageLuma8(screen);
+        let template = DynamicImage::ImageLuma8(patch);
+
+        let config = MatchConfig {
+            search_multiple_scales: false,
+            confidence: 0.9,
+            angles: vec![0.0, 45.0],
+            ..Default::default()
+        };
+
+        let best = ImageMatcher::find(&screen, &template, &config).unwrap().expect("rotated match");
+        assert_eq!(best.angle, 45.0);
+        assert_eq!((best.x, best.y), (40, 25));
+        assert_eq!((best.width, best.height), (26, 26));
+    }
+
+    #[test]
+    fn test_find_all_two_nearby_copies() {
+        // Smooth blob so positions next to each copy also score highly
+        let blob = GrayImage::from_fn(16, 16, |x, y| {
+            let r = ((x as f32 - 7.5).powi(2) + (y as f32 - 7.5).powi(2)).sqrt();
+            Luma([(255.0 * (1.0 - r / 8.0).max(0.0)) as u8])
+        });
+
+        let mut screen = GrayImage::new(80, 40);
+        image::imageops::replace(&mut screen, &blob, 10, 12);
+        image::imageops::replace(&mut screen, &blob, 36, 12);
+        let screen = DynamicImage::ImageLuma8(screen);
+        let template = DynamicImage::ImageLuma8(blob);
+
+        let config =
+            MatchConfig { search_multiple_scales: false, confidence: 0.9, ..Default::default() };
+
+        let raw = ImageMatcher::find_all(
+            &screen,
+            &template,
+            &MatchConfig { nms_iou: 1.0, ..config.clone() },
+        )
+        .unwrap();
+        assert!(raw.len() > 2, "expected overlapping hits without NMS, got {}", raw.len());
+
+        let results = ImageMatcher::find_all(&screen, &template, &config).unwrap();
+        assert_eq!(results.len(), 2, "got {:?}", results);
+        let mut xs: Vec<_> = results.iter().map(|r| (r.x, r.y)).collect();
+        xs.sort();
+        assert_eq!(xs, vec![(10, 12), (36, 12)]);
+        assert!(results[0].confidence >= results[1].confidence);
+    }
+
     #[test]
     fn test_nms() {
         let matches = vec![
@@ -1,6 +1,8 @@
 //! Image template matching module
 //!
 //! Provides template matching functionality for finding UI elements on screen.
+//! [`find_in_image`] and [`find_all_in_image`] run the same matcher against an
+//! image already in memory, e.g. a previously saved capture.
 //!
 //! # Example
 //!
@@ -26,6 +28,43 @@ pub use result::MatchResult;
 use crate::error::Result;
 use image::DynamicImage;
 
+/// Find first match of template in an existing image
+///
+/// # Arguments
+/// * `haystack` - Image to search in
+/// * `template` - Template image to search for
+/// * `config` - Optional matching configuration
+///
+/// # Returns
+/// * `Ok(Some(MatchResult))` - If template found (coordinates are relative to `haystack`)
+/// * `Ok(None)` - If template not found
+/// * `Err(_)` - If matching failed
+pub fn find_in_image(
+    haystack: &DynamicImage,
+    template: &DynamicImage,
+    config: Option<MatchConfig>,
+) -> Result<Option<MatchResult>> {
+    ImageMatcher::find(haystack, template, &config.unwrap_or_default())
+}
+
+/// Find all matches of template in an existing image
+///
+/// # Arguments
+/// * `haystack` - Image to search in
+/// * `template` - Template image to search for
+/// * `config` - Optional matching configuration
+///
+/// # Returns
+/// * `Ok(Vec<MatchResult>)` - All matches found, sorted by confidence
+/// * `Err(_)` - If matching failed
+pub fn find_all_in_image(
+    haystack: &DynamicImage,
+    template: &DynamicImage,
+    config: Option<MatchConfig>,
+) -> Result<Vec<MatchResult>> {
+    ImageMatcher::find_all(haystack, template, &config.unwrap_or_default())
+}
+
 /// Find first match of template in current screen
 ///
 /// # Arguments
@@ -43,7 +82,7 @@ pub fn find_on_screen(
     let screen_capture = crate::screen::capture_screen()?;
     let screen = image::load_from_memory(&screen_capture.image)
         .map_err(|e| crate::error::AumateError::Other(format!("Failed to decode screen: {}", e)))?;
-    ImageMatcher::find(&screen, template, &config.unwrap_or_default())
+    find_in_image(&screen, template, config)
 }
 
 /// Find all matches of template in current screen
@@ -62,7 +101,7 @@ pub fn find_all_on_screen(
     let screen_capture = crate::screen::capture_screen()?;
     let screen = image::load_from_memory(&screen_capture.image)
         .map_err(|e| crate::error::AumateError::Other(format!("Failed to decode screen: {}", e)))?;
-    ImageMatcher::find_all(&screen, template, &config.unwrap_or_default())
+    find_all_in_image(&screen, template, config)
 }
 
 /// Find first match of template in a region of the screen
@@ -133,3 +172,46 @@ pub fn find_all_in_region(
 
     Ok(results)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{GrayImage, Luma};
+
+    /// Deterministic noise so every window of the image is distinct
+    pub(super) fn noise_image(width: u32, height: u32, seed: u32) -> GrayImage {
+        let mut state = seed;
+        GrayImage::from_fn(width, height, |_, _| {
+            state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            Luma([(state >> 16) as u8])
+        })
+    }
+
+    #[test]
+    fn test_find_in_image_locates_template() {
+        let haystack = noise_image(120, 80, 7);
+        let template = image::imageops::crop_imm(&haystack, 37, 21, 16, 12).to_image();
+
+        let config = MatchConfig::new().with_multi_scale(false).with_confidence(0.99);
+        let result = find_in_image(
+            &DynamicImage::ImageLuma8(haystack),
+            &DynamicImage::ImageLuma8(template),
+            Some(config),
+        )
+        .unwrap()
+        .expect("template should be found");
+
+        assert_eq!((result.x, result.y), (37, 21));
+        assert_eq!((result.width, result.height), (16, 12));
+        assert!(result.confidence > 0.99);
+    }
+
+    #[test]
+    fn test_find_in_image_template_larger_than_haystack() {
+        let haystack = DynamicImage::ImageLuma8(noise_image(10, 10, 1));
+        let template = DynamicImage::ImageLuma8(noise_image(30, 30, 2));
+
+        assert!(find_in_image(&haystack, &template, None).unwrap().is_none());
+        assert!(find_all_in_image(&haystack, &template, None).unwrap().is_empty());
+    }
+}
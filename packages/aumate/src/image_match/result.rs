@@ -7,20 +7,22 @@ pub struct MatchResult {
     pub x: u32,
     /// Y coordinate of match (top-left)
     pub y: u32,
-    /// Width of matched region
+    /// Width of matched region (bounding box of the rotated template)
     pub width: u32,
-    /// Height of matched region
+    /// Height of matched region (bounding box of the rotated template)
     pub height: u32,
     /// Match confidence score (0.0 to 1.0)
     pub confidence: f32,
     /// Scale at which match was found
     pub scale: f32,
+    /// Template rotation in degrees at which match was found
+    pub angle: f32,
 }
 
 impl MatchResult {
     /// Create a new match result
     pub fn new(x: u32, y: u32, width: u32, height: u32, confidence: f32, scale: f32) -> Self {
-        Self { x, y, width, height, confidence, scale }
+        Self { x, y, width, height, confidence, scale, angle: 0.0 }
     }
 
     /// Get center point of match
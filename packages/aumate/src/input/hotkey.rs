@@ -0,0 +1,456 @@
+//! Global hotkeys
+//!
+//! Hotkeys are matched by a passive [`eventhooks::listen`] listener, so the
+//! keystroke still reaches the focused application. The listener is shared
+//! process-wide and cannot run while a macro recording is in progress.
+
+use super::keyboard::parse_key_combo;
+use crate::error::{AumateError, Result};
+use crate::eventhooks::{self, Event, EventType, GrabError, Key, ModifierState};
+use crate::permissions;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// Callback invoked when a registered hotkey is pressed
+pub type HotkeyCallback = Arc<dyn Fn() + Send + Sync>;
+
+/// A key combined with the modifiers that must be held
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hotkey {
+    pub modifiers: ModifierState,
+    pub key: Key,
+}
+
+impl FromStr for Hotkey {
+    type Err = AumateError;
+
+    /// Parse an accelerator such as `"Ctrl+Shift+A"`, `"F3"` or `"Ctrl+,"`
+    fn from_str(accelerator: &str) -> Result<Self> {
+        let (names, key_name) = parse_key_combo(accelerator)?;
+
+        let mut modifiers = ModifierState::default();
+        for name in &names {
+            match name.as_str() {
+                "control" => modifiers.ctrl = true,
+                "shift" => modifiers.shift = true,
+                "alt" => modifiers.alt = true,
+                _ => modifiers.meta = true,
+            }
+        }
+
+        let key = key_from_name(&key_name).ok_or_else(|| {
+            AumateError::Input(format!(
+                "Invalid hotkey {:?}: unknown key {:?}",
+                accelerator, key_name
+            ))
+        })?;
+
+        Ok(Self { modifiers, key })
+    }
+}
+
+impl fmt::Display for Hotkey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ModifierState { shift, ctrl, alt, meta } = self.modifiers;
+        for (held, name) in [(ctrl, "Ctrl"), (shift, "Shift"), (alt, "Alt"), (meta, "Meta")] {
+            if held {
+                write!(f, "{}+", name)?;
+            }
+        }
+        write!(f, "{:?}", self.key)
+    }
+}
+
+/// Map a key name from an accelerator to a hook key
+fn key_from_name(name: &str) -> Option<Key> {
+    let lower = name.to_lowercase();
+    let key = match lower.as_str() {
+        "a" => Key::KeyA,
+        "b" => Key::KeyB,
+        "c" => Key::KeyC,
+        "d" => Key::KeyD,
+        "e" => Key::KeyE,
+        "f" => Key::KeyF,
+        "g" => Key::KeyG,
+        "h" => Key::KeyH,
+        "i" => Key::KeyI,
+        "j" => Key::KeyJ,
+        "k" => Key::KeyK,
+        "l" => Key::KeyL,
+        "m" => Key::KeyM,
+        "n" => Key::KeyN,
+        "o" => Key::KeyO,
+        "p" => Key::KeyP,
+        "q" => Key::KeyQ,
+        "r" => Key::KeyR,
+        "s" => Key::KeyS,
+        "t" => Key::KeyT,
+        "u" => Key::KeyU,
+        "v" => Key::KeyV,
+        "w" => Key::KeyW,
+        "x" => Key::KeyX,
+        "y" => Key::KeyY,
+        "z" => Key::KeyZ,
+        "0" => Key::Num0,
+        "1" => Key::Num1,
+        "2" => Key::Num2,
+        "3" => Key::Num3,
+        "4" => Key::Num4,
+        "5" => Key::Num5,
+        "6" => Key::Num6,
+        "7" => Key::Num7,
+        "8" => Key::Num8,
+        "9" => Key::Num9,
+        "f1" => Key::F1,
+        "f2" => Key::F2,
+        "f3" => Key::F3,
+        "f4" => Key::F4,
+        "f5" => Key::F5,
+        "f6" => Key::F6,
+        "f7" => Key::F7,
+        "f8" => Key::F8,
+        "f9" => Key::F9,
+        "f10" => Key::F10,
+        "f11" => Key::F11,
+        "f12" => Key::F12,
+        "f13" => Key::F13,
+        "f14" => Key::F14,
+        "f15" => Key::F15,
+        "f16" => Key::F16,
+        "f17" => Key::F17,
+        "f18" => Key::F18,
+        "f19" => Key::F19,
+        "f20" => Key::F20,
+        "f21" => Key::F21,
+        "f22" => Key::F22,
+        "f23" => Key::F23,
+        "f24" => Key::F24,
+        "space" => Key::Space,
+        "enter" | "return" => Key::Return,
+        "tab" => Key::Tab,
+        "escape" | "esc" => Key::Escape,
+        "backspace" => Key::Backspace,
+        "delete" | "del" => Key::Delete,
+        "insert" | "ins" => Key::Insert,
+        "home" => Key::Home,
+        "end" => Key::End,
+        "pageup" | "pgup" => Key::PageUp,
+        "pagedown" | "pgdn" => Key::PageDown,
+        "up" | "arrowup" => Key::UpArrow,
+        "down" | "arrowdown" => Key::DownArrow,
+        "left" | "arrowleft" => Key::LeftArrow,
+        "right" | "arrowright" => Key::RightArrow,
+        "printscreen" => Key::PrintScreen,
+        "pause" => Key::Pause,
+        "," | "comma" => Key::Comma,
+        "." | "period" => Key::Dot,
+        "/" | "slash" => Key::Slash,
+        ";" | "semicolon" => Key::SemiColon,
+        "'" | "quote" => Key::Quote,
+        "[" | "bracketleft" => Key::LeftBracket,
+        "]" | "bracketright" => Key::RightBracket,
+        "\\" | "backslash" => Key::BackSlash,
+        "-" | "minus" => Key::Minus,
+        "=" | "equal" => Key::Equal,
+        "`" | "backquote" => Key::BackQuote,
+        _ => return None,
+    };
+    Some(key)
+}
+
+/// Tracks held keys from the event stream and reports fresh hotkey presses
+#[derive(Debug, Default)]
+struct KeyTracker {
+    modifiers: ModifierState,
+    held: Vec<Key>,
+}
+
+impl KeyTracker {
+    /// Feed an event, returning the combo when a non-modifier key goes down
+    ///
+    /// Auto-repeat presses of a key that is already held are ignored.
+    fn update(&mut self, event: &EventType) -> Option<Hotkey> {
+        let (key, pressed) = match *event {
+            EventType::KeyPress(key) => (key, true),
+            EventType::KeyRelease(key) => (key, false),
+            _ => return None,
+        };
+
+        let modifier = match key {
+            Key::ControlLeft | Key::ControlRight => Some(&mut self.modifiers.ctrl),
+            Key::ShiftLeft | Key::ShiftRight => Some(&mut self.modifiers.shift),
+            Key::Alt | Key::AltGr => Some(&mut self.modifiers.alt),
+            Key::MetaLeft | Key::MetaRight => Some(&mut self.modifiers.meta),
+            _ => None,
+        };
+        if let Some(flag) = modifier {
+            *flag = pressed;
+            return None;
+        }
+
+        if !pressed {
+            self.held.retain(|k| *k != key);
+            return None;
+        }
+        if self.held.contains(&key) {
+            return None;
+        }
+        self.held.push(key);
+        Some(Hotkey { modifiers: self.modifiers, key })
+    }
+}
+
+static HOTKEYS: Mutex<Vec<(Hotkey, HotkeyCallback)>> = Mutex::new(Vec::new());
+static LISTENER: Mutex<Option<JoinHandle<std::result::Result<(), GrabError>>>> = Mutex::new(None);
+
+/// How long `spawn_listener` waits for the listener to fail before assuming
+/// it started; startup errors are reported straight away
+const LISTENER_STARTUP_GRACE: Duration = Duration::from_millis(200);
+
+fn lock_error<T>(e: std::sync::PoisonError<T>) -> AumateError {
+    AumateError::Input(format!("Lock error: {}", e))
+}
+
+/// Register a global hotkey
+///
+/// # Arguments
+/// * `accelerator` - Combo such as `"Ctrl+Shift+A"` or `"F3"`
+/// * `callback` - Called on the listener thread each time the combo is pressed
+///
+/// # Errors
+/// Fails if the accelerator is invalid, already registered, or the global
+/// input listener is in use by something else (e.g. a macro recording) or
+/// fails to start. The hotkey is not kept registered on failure.
+/// Fails with `PermissionDenied` if Accessibility access is missing (macOS).
+pub fn register_hotkey<F>(accelerator: &str, callback: F) -> Result<()>
+where
+    F: Fn() + Send + Sync + 'static,
+{
+    let hotkey: Hotkey = accelerator.parse()?;
+
+    let mut listener = LISTENER.lock().map_err(lock_error)?;
+    if listener.is_none() {
+        permissions::require_accessibility("to listen for hotkeys")?;
+    }
+
+    {
+        let mut hotkeys = HOTKEYS.lock().map_err(lock_error)?;
+        if hotkeys.iter().any(|(h, _)| *h == hotkey) {
+            return Err(AumateError::Input(format!("Hotkey {} is already registered", hotkey)));
+        }
+        hotkeys.push((hotkey, Arc::new(callback)));
+    }
+
+    if listener.is_none() {
+        match start_listener() {
+            Ok(thread) => *listener = Some(thread),
+            Err(e) => {
+                // Nothing listens for the hotkey, so don't keep it registered
+                if let Ok(mut hotkeys) = HOTKEYS.lock() {
+                    hotkeys.retain(|(h, _)| *h != hotkey);
+                }
+                return Err(e);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Spawn the hotkey listener thread
+fn start_listener() -> Result<JoinHandle<std::result::Result<(), GrabError>>> {
+    let mut tracker = KeyTracker::default();
+    spawn_listener("aumate-hotkeys", move |event: Event| {
+        let Some(pressed) = tracker.update(&event.event_type) else {
+            return;
+        };
+        // Call outside the lock so callbacks may (un)register hotkeys
+        let callback = HOTKEYS.lock().ok().and_then(|hotkeys| {
+            hotkeys.iter().find(|(h, _)| *h == pressed).map(|(_, cb)| cb.clone())
+        });
+        if let Some(callback) = callback {
+            callback();
+        }
+    })
+}
+
+/// Run `eventhooks::listen` on a named thread and report it failing to start
+///
+/// `eventhooks::listen` blocks while it runs, so an exit reported within
+/// `LISTENER_STARTUP_GRACE` means it failed to start, e.g. with
+/// `GrabError::AlreadyListening` while a macro recording holds the listener.
+pub(crate) fn spawn_listener<F>(
+    name: &str,
+    callback: F,
+) -> Result<JoinHandle<std::result::Result<(), GrabError>>>
+where
+    F: FnMut(Event) + Send + 'static,
+{
+    let (exited_tx, exited_rx) = mpsc::channel();
+    let thread = thread::Builder::new()
+        .name(name.to_string())
+        .spawn(move || {
+            let result = eventhooks::listen(callback);
+            let _ = exited_tx.send(());
+            result
+        })
+        .map_err(|e| AumateError::Input(format!("Failed to start input listener: {}", e)))?;
+
+    if let Err(RecvTimeoutError::Timeout) = exited_rx.recv_timeout(LISTENER_STARTUP_GRACE) {
+        return Ok(thread);
+    }
+    thread
+        .join()
+        .map_err(|_| AumateError::Input("Input listener thread panicked".to_string()))?
+        .map_err(AumateError::from_listen_error)?;
+    Err(AumateError::Input("Input listener stopped before it started".to_string()))
+}
+
+/// Unregister a global hotkey
+///
+/// Stops the listener once no hotkeys remain.
+///
+/// # Returns
+/// `true` if the hotkey was registered
+pub fn unregister_hotkey(accelerator: &str) -> Result<bool> {
+    let hotkey: Hotkey = accelerator.parse()?;
+
+    let thread = {
+        let mut listener = LISTENER.lock().map_err(lock_error)?;
+        let mut hotkeys = HOTKEYS.lock().map_err(lock_error)?;
+        let before = hotkeys.len();
+        hotkeys.retain(|(h, _)| *h != hotkey);
+        if hotkeys.len() == before {
+            return Ok(false);
+        }
+        if hotkeys.is_empty() { listener.take() } else { None }
+    };
+
+    // Wait without holding the lock: a callback still running on the listener
+    // may register a hotkey, which needs it
+    if let Some(thread) = thread {
+        stop_listener(thread)?;
+    }
+
+    Ok(true)
+}
+
+/// Check whether an accelerator is valid and not yet registered here
+///
+/// Shortcuts claimed by other applications cannot be detected because the
+/// listener never grabs keys.
+pub fn is_hotkey_available(accelerator: &str) -> Result<bool> {
+    let hotkey: Hotkey = accelerator.parse()?;
+    let hotkeys = HOTKEYS.lock().map_err(lock_error)?;
+    Ok(!hotkeys.iter().any(|(h, _)| *h == hotkey))
+}
+
+fn stop_listener(thread: JoinHandle<std::result::Result<(), GrabError>>) -> Result<()> {
+    // A callback unregistering the last hotkey runs on the listener itself
+    if thread.thread().id() == thread::current().id() {
+        return eventhooks::stop_listen()
+            .map_err(|e| AumateError::Input(format!("Failed to stop listener: {:?}", e)));
+    }
+
+    // The listener may not be running yet, so keep asking until the thread exits
+    while !thread.is_finished() {
+        eventhooks::stop_listen()
+            .map_err(|e| AumateError::Input(format!("Failed to stop listener: {:?}", e)))?;
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    thread
+        .join()
+        .map_err(|_| AumateError::Input("Hotkey listener thread panicked".to_string()))?
+        .map_err(AumateError::from_listen_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ctrl() -> ModifierState {
+        ModifierState { ctrl: true, ..Default::default() }
+    }
+
+    #[test]
+    fn test_parse_hotkey() {
+        let hotkey: Hotkey = "Ctrl+Shift+A".parse().unwrap();
+        assert_eq!(hotkey.key, Key::KeyA);
+        assert_eq!(
+            hotkey.modifiers,
+            ModifierState { ctrl: true, shift: true, ..Default::default() }
+        );
+
+        let hotkey: Hotkey = "F3".parse().unwrap();
+        assert_eq!(hotkey, Hotkey { modifiers: ModifierState::default(), key: Key::F3 });
+
+        let hotkey: Hotkey = "ctrl+,".parse().unwrap();
+        assert_eq!(hotkey, Hotkey { modifiers: ctrl(), key: Key::Comma });
+
+        assert_eq!("Control+a".parse::<Hotkey>().unwrap(), "Ctrl+A".parse::<Hotkey>().unwrap());
+    }
+
+    #[test]
+    fn test_parse_hotkey_invalid() {
+        assert!("Ctrl+".parse::<Hotkey>().is_err());
+        assert!("Ctrl+Nope".parse::<Hotkey>().is_err());
+        assert!("Hyper+A".parse::<Hotkey>().is_err());
+    }
+
+    #[test]
+    fn test_hotkey_display() {
+        let hotkey: Hotkey = "Shift+Ctrl+F5".parse().unwrap();
+        assert_eq!(hotkey.to_string(), "Ctrl+Shift+F5");
+    }
+
+    #[test]
+    fn test_tracker_reports_fresh_presses() {
+        let mut tracker = KeyTracker::default();
+        let combo = Hotkey { modifiers: ctrl(), key: Key::KeyA };
+
+        assert_eq!(tracker.update(&EventType::KeyPress(Key::ControlLeft)), None);
+        assert_eq!(tracker.update(&EventType::KeyPress(Key::KeyA)), Some(combo));
+        // Auto-repeat while held
+        assert_eq!(tracker.update(&EventType::KeyPress(Key::KeyA)), None);
+        assert_eq!(tracker.update(&EventType::KeyRelease(Key::KeyA)), None);
+        assert_eq!(tracker.update(&EventType::KeyPress(Key::KeyA)), Some(combo));
+
+        tracker.update(&EventType::KeyRelease(Key::KeyA));
+        tracker.update(&EventType::KeyRelease(Key::ControlLeft));
+        assert_eq!(
+            tracker.update(&EventType::KeyPress(Key::KeyA)),
+            Some(Hotkey { modifiers: ModifierState::default(), key: Key::KeyA })
+        );
+    }
+
+    #[test]
+    fn test_unregister_last_hotkey_while_callback_registers() {
+        // Stand-in listener whose callback registers a hotkey while it is being stopped
+        HOTKEYS.lock().unwrap().push(("Ctrl+Shift+F23".parse().unwrap(), Arc::new(|| {})));
+        *LISTENER.lock().unwrap() = Some(thread::spawn(|| {
+            thread::sleep(Duration::from_millis(50));
+            let _ = register_hotkey("Ctrl+Shift+F22", || {});
+            Ok(())
+        }));
+
+        let (done_tx, done_rx) = mpsc::channel();
+        thread::spawn(move || {
+            let _ = done_tx.send(unregister_hotkey("Ctrl+Shift+F23"));
+        });
+        let unregistered = done_rx.recv_timeout(Duration::from_secs(5)).expect("deadlocked");
+        assert!(unregistered.unwrap());
+
+        let _ = unregister_hotkey("Ctrl+Shift+F22");
+    }
+
+    #[test]
+    fn test_is_hotkey_available_rejects_invalid() {
+        assert!(is_hotkey_available("Ctrl+Nope").is_err());
+        assert!(is_hotkey_available("Ctrl+Shift+F24").unwrap());
+    }
+}
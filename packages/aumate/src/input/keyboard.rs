@@ -3,7 +3,11 @@
 //! Provides keyboard input simulation including key presses, typing, and modifier support.
 
 use crate::error::{AumateError, Result};
+#[cfg(feature = "eventhooks")]
+use crate::eventhooks::{self, LockState, ModifierState};
 use enigo::{Direction, Enigo, Key, Keyboard as KeyboardTrait};
+use rand::Rng;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::Duration;
@@ -51,6 +55,17 @@ impl Keyboard {
         Ok(())
     }
 
+    /// Tap a key combination written as a single string, e.g. "Ctrl+Shift+S"
+    ///
+    /// Parts are separated by `+` and matched case-insensitively. Every part
+    /// but the last must be a modifier: `Ctrl`/`Control`, `Shift`,
+    /// `Alt`/`Option` or `Cmd`/`Command`/`Meta`/`Super`/`Win`. The last part is
+    /// the key, which may itself be `+` (e.g. "Ctrl++").
+    pub fn key_combo(&self, combo: &str) -> Result<()> {
+        let (modifiers, key) = parse_key_combo(combo)?;
+        self.key_tap(&key, Some(&modifiers))
+    }
+
     /// Toggle a key (press or release)
     pub fn key_toggle(&self, key: &str, down: &str, modifier: Option<&[String]>) -> Result<()> {
         let mut enigo =
@@ -78,6 +93,42 @@ impl Keyboard {
         Ok(())
     }
 
+    /// Hold a key down for the given duration, then release it
+    ///
+    /// Modifiers are pressed before the key and released after it in reverse
+    /// order. Held keys are released by a drop guard, so they are not left
+    /// stuck down if the thread panics while holding them.
+    pub fn key_hold(
+        &self,
+        key: &str,
+        modifier: Option<&[String]>,
+        duration: Duration,
+    ) -> Result<()> {
+        // Parse all keys up front so nothing is left pressed on an invalid key
+        let mut keys = modifier
+            .unwrap_or_default()
+            .iter()
+            .map(|m| self.parse_key(m))
+            .collect::<Result<Vec<_>>>()?;
+        keys.push(self.parse_key(key)?);
+
+        let mut held = HeldKeys { enigo: &self.enigo, keys: Vec::with_capacity(keys.len()) };
+        {
+            let mut enigo =
+                self.enigo.lock().map_err(|e| AumateError::Input(format!("Lock error: {}", e)))?;
+            for key_code in keys {
+                let _ = enigo.key(key_code, Direction::Press);
+                held.keys.push(key_code);
+            }
+        }
+
+        thread::sleep(duration);
+        drop(held);
+
+        self.apply_delay();
+        Ok(())
+    }
+
     /// Type a string
     pub fn type_string(&self, string: &str) -> Result<()> {
         let mut enigo =
@@ -89,9 +140,29 @@ impl Keyboard {
 
     /// Type a string with delay between characters
     pub fn type_string_delayed(&self, string: &str, cpm: u32) -> Result<()> {
+        self.type_string_delayed_cancellable(string, cpm, &AtomicBool::new(false)).map(|_| ())
+    }
+
+    /// Type a string with delay between characters, stopping early once `cancel` is set
+    ///
+    /// The flag is checked before each character, so another thread can abort
+    /// a long typing run mid-way.
+    ///
+    /// # Returns
+    /// `true` if the whole string was typed, `false` if it was cancelled
+    pub fn type_string_delayed_cancellable(
+        &self,
+        string: &str,
+        cpm: u32,
+        cancel: &AtomicBool,
+    ) -> Result<bool> {
         let delay_ms = if cpm > 0 { (60000.0 / cpm as f64) as u64 } else { 0 };
 
         for ch in string.chars() {
+            if cancel.load(Ordering::SeqCst) {
+                return Ok(false);
+            }
+
             let mut enigo =
                 self.enigo.lock().map_err(|e| AumateError::Input(format!("Lock error: {}", e)))?;
             let _ = enigo.text(&ch.to_string());
@@ -102,6 +173,55 @@ impl Keyboard {
             }
         }
 
+        self.apply_delay();
+        Ok(true)
+    }
+
+    /// Type a string with human-like, randomized delays between characters
+    ///
+    /// Each keystroke delay is derived from `base_cpm` and varied randomly by up
+    /// to `jitter_percent` (0-100) in either direction. When `punctuation_pause`
+    /// is set, a longer pause is inserted after punctuation and line breaks.
+    pub fn type_string_humanized(
+        &self,
+        string: &str,
+        base_cpm: u32,
+        jitter_percent: u32,
+        punctuation_pause: bool,
+    ) -> Result<()> {
+        self.type_string_humanized_with_rng(
+            string,
+            base_cpm,
+            jitter_percent,
+            punctuation_pause,
+            &mut rand::rng(),
+        )
+    }
+
+    /// Type a string with humanized delays drawn from the given random number generator
+    ///
+    /// Using a seeded RNG (e.g. `StdRng::seed_from_u64`) makes the timing reproducible.
+    pub fn type_string_humanized_with_rng<R: Rng + ?Sized>(
+        &self,
+        string: &str,
+        base_cpm: u32,
+        jitter_percent: u32,
+        punctuation_pause: bool,
+        rng: &mut R,
+    ) -> Result<()> {
+        let delays = humanized_delays(string, base_cpm, jitter_percent, punctuation_pause, rng);
+
+        for (ch, delay) in string.chars().zip(delays) {
+            let mut enigo =
+                self.enigo.lock().map_err(|e| AumateError::Input(format!("Lock error: {}", e)))?;
+            let _ = enigo.text(&ch.to_string());
+            drop(enigo);
+
+            if !delay.is_zero() {
+                thread::sleep(delay);
+            }
+        }
+
         self.apply_delay();
         Ok(())
     }
@@ -115,6 +235,24 @@ impl Keyboard {
         Ok(())
     }
 
+    /// Get the state of Caps Lock, Num Lock and Scroll Lock
+    ///
+    /// Best effort: macOS has no Num Lock or Scroll Lock and always reports
+    /// them as off, and Linux reads Scroll Lock from a keyboard indicator that
+    /// some layouts lack. See the `eventhooks` module for details.
+    #[cfg(feature = "eventhooks")]
+    pub fn get_lock_state(&self) -> Result<LockState> {
+        eventhooks::get_lock_state()
+            .map_err(|e| AumateError::Input(format!("Failed to read lock state: {:?}", e)))
+    }
+
+    /// Get which modifier keys are currently held down
+    #[cfg(feature = "eventhooks")]
+    pub fn get_modifier_state(&self) -> Result<ModifierState> {
+        eventhooks::get_modifier_state()
+            .map_err(|e| AumateError::Input(format!("Failed to read modifier state: {:?}", e)))
+    }
+
     /// Set the keyboard delay in milliseconds
     pub fn set_delay(&self, delay_ms: u32) -> Result<()> {
         let mut delay =
@@ -191,3 +329,151 @@ impl Default for Keyboard {
         Self::new().expect("Failed to create Keyboard")
     }
 }
+
+/// Releases pressed keys in reverse order when dropped
+struct HeldKeys<'a> {
+    enigo: &'a Mutex<Enigo>,
+    keys: Vec<Key>,
+}
+
+impl Drop for HeldKeys<'_> {
+    fn drop(&mut self) {
+        // Recover from a poisoned lock: releasing the keys matters more here
+        let mut enigo = self.enigo.lock().unwrap_or_else(|e| e.into_inner());
+        for key_code in self.keys.drain(..).rev() {
+            let _ = enigo.key(key_code, Direction::Release);
+        }
+    }
+}
+
+/// Split a combo string into normalized modifier names and the main key
+pub(super) fn parse_key_combo(combo: &str) -> Result<(Vec<String>, String)> {
+    let invalid =
+        |reason: &str| AumateError::Input(format!("Invalid key combo {:?}: {}", combo, reason));
+
+    let trimmed = combo.trim();
+    // A trailing "+" after a separator is the plus key itself
+    let (head, key) = match trimmed.strip_suffix("++") {
+        Some(head) => (Some(head), "+"),
+        None if trimmed == "+" => (None, "+"),
+        None => match trimmed.rsplit_once('+') {
+            Some((head, key)) => (Some(head), key.trim()),
+            None => (None, trimmed),
+        },
+    };
+    if key.is_empty() {
+        return Err(invalid("missing key"));
+    }
+
+    let mut modifiers: Vec<String> = Vec::new();
+    for part in head.map(|h| h.split('+').collect::<Vec<_>>()).unwrap_or_default() {
+        let modifier = match part.trim().to_lowercase().as_str() {
+            "ctrl" | "control" => "control",
+            "shift" => "shift",
+            "alt" | "option" | "opt" => "alt",
+            "cmd" | "command" | "meta" | "super" | "win" => "meta",
+            "" => return Err(invalid("empty part")),
+            other => return Err(invalid(&format!("unknown modifier {:?}", other))),
+        };
+        if modifiers.iter().any(|m| m == modifier) {
+            return Err(invalid(&format!("duplicate modifier {:?}", part.trim())));
+        }
+        modifiers.push(modifier.to_string());
+    }
+
+    Ok((modifiers, key.to_string()))
+}
+
+/// Characters after which a humanized typist pauses longer
+const PAUSE_CHARS: &[char] = &['.', ',', '!', '?', ';', ':', '\n'];
+
+/// Compute the delay after each character for humanized typing
+fn humanized_delays<R: Rng + ?Sized>(
+    string: &str,
+    base_cpm: u32,
+    jitter_percent: u32,
+    punctuation_pause: bool,
+    rng: &mut R,
+) -> Vec<Duration> {
+    if base_cpm == 0 {
+        return vec![Duration::ZERO; string.chars().count()];
+    }
+
+    let base_ms = 60000.0 / base_cpm as f64;
+    let jitter = jitter_percent.min(100) as f64 / 100.0;
+
+    string
+        .chars()
+        .map(|ch| {
+            let factor =
+                if jitter > 0.0 { rng.random_range(1.0 - jitter..=1.0 + jitter) } else { 1.0 };
+            let mut delay_ms = base_ms * factor;
+            if punctuation_pause && PAUSE_CHARS.contains(&ch) {
+                delay_ms += base_ms * rng.random_range(1.5..=3.0);
+            }
+            Duration::from_secs_f64(delay_ms / 1000.0)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn test_humanized_delays_within_jitter() {
+        let mut rng = StdRng::seed_from_u64(42);
+        // 600 cpm = 100ms per character
+        let delays = humanized_delays("hello world", 600, 20, false, &mut rng);
+        assert_eq!(delays.len(), 11);
+        for delay in delays {
+            let ms = delay.as_secs_f64() * 1000.0;
+            assert!((80.0..=120.0).contains(&ms), "delay {}ms out of range", ms);
+        }
+    }
+
+    #[test]
+    fn test_humanized_delays_deterministic_with_seed() {
+        let a = humanized_delays("abc, def.", 300, 50, true, &mut StdRng::seed_from_u64(7));
+        let b = humanized_delays("abc, def.", 300, 50, true, &mut StdRng::seed_from_u64(7));
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_humanized_delays_punctuation_pause() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let delays = humanized_delays("a.", 600, 0, true, &mut rng);
+        assert_eq!(delays[0], Duration::from_millis(100));
+        assert!(delays[1] >= Duration::from_millis(250));
+    }
+
+    fn combo(modifiers: &[&str], key: &str) -> (Vec<String>, String) {
+        (modifiers.iter().map(|m| m.to_string()).collect(), key.to_string())
+    }
+
+    #[test]
+    fn test_parse_key_combo() {
+        assert_eq!(parse_key_combo("Ctrl+Shift+S").unwrap(), combo(&["control", "shift"], "S"));
+        assert_eq!(parse_key_combo("cmd + c").unwrap(), combo(&["meta"], "c"));
+        assert_eq!(parse_key_combo("Super+Option+F5").unwrap(), combo(&["meta", "alt"], "F5"));
+        assert_eq!(parse_key_combo("Enter").unwrap(), combo(&[], "Enter"));
+        assert_eq!(parse_key_combo("Ctrl++").unwrap(), combo(&["control"], "+"));
+        assert_eq!(parse_key_combo("+").unwrap(), combo(&[], "+"));
+    }
+
+    #[test]
+    fn test_parse_key_combo_errors() {
+        for bad in ["", "Ctrl+", "Ctrl++Shift+a", "Hyper+a", "Ctrl+Control+a", "a+b"] {
+            assert!(parse_key_combo(bad).is_err(), "{:?} should not parse", bad);
+        }
+    }
+
+    #[test]
+    fn test_humanized_delays_zero_cpm() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let delays = humanized_delays("abc", 0, 50, true, &mut rng);
+        assert!(delays.iter().all(|d| d.is_zero()));
+    }
+}
@@ -2,8 +2,18 @@
 //!
 //! Provides mouse and keyboard input simulation for desktop automation.
 
+#[cfg(feature = "eventhooks")]
+mod hotkey;
 mod keyboard;
 mod mouse;
 
+#[cfg(feature = "eventhooks")]
+pub use crate::eventhooks::{LockState, ModifierState};
+#[cfg(feature = "macros")]
+pub(crate) use hotkey::spawn_listener;
+#[cfg(feature = "eventhooks")]
+pub use hotkey::{Hotkey, HotkeyCallback, is_hotkey_available, register_hotkey, unregister_hotkey};
 pub use keyboard::Keyboard;
-pub use mouse::{Mouse, MouseButton, MousePosition};
+pub use mouse::{
+    Mouse, MouseButton, MousePosition, ScrollDirection, bezier_path, random_bezier_control_points,
+};
@@ -4,6 +4,7 @@
 
 use crate::error::{AumateError, Result};
 use enigo::{Axis, Button, Coordinate, Direction, Enigo, Mouse as MouseTrait};
+use rand::Rng;
 use std::str::FromStr;
 use std::sync::{Arc, Mutex};
 use std::thread;
@@ -40,6 +41,41 @@ impl MouseButton {
     }
 }
 
+/// Scroll directions for [`Mouse::scroll_lines`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl FromStr for ScrollDirection {
+    type Err = AumateError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "up" => Ok(ScrollDirection::Up),
+            "down" => Ok(ScrollDirection::Down),
+            "left" => Ok(ScrollDirection::Left),
+            "right" => Ok(ScrollDirection::Right),
+            _ => Err(AumateError::Input(format!("Invalid scroll direction: {}", s))),
+        }
+    }
+}
+
+impl ScrollDirection {
+    /// Unit scroll delta; positive values scroll down/right
+    fn delta(self) -> (i32, i32) {
+        match self {
+            ScrollDirection::Up => (0, -1),
+            ScrollDirection::Down => (0, 1),
+            ScrollDirection::Left => (-1, 0),
+            ScrollDirection::Right => (1, 0),
+        }
+    }
+}
+
 /// Mouse position
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct MousePosition {
@@ -62,6 +98,9 @@ impl Mouse {
     }
 
     /// Move the mouse to the specified coordinates
+    ///
+    /// Coordinates are logical, matching `ScreenSize::width`/`height`. On HiDPI
+    /// displays, divide capture pixel positions by the screen scale factor first.
     pub fn move_mouse(&self, x: i32, y: i32) -> Result<()> {
         let mut enigo =
             self.enigo.lock().map_err(|e| AumateError::Input(format!("Lock error: {}", e)))?;
@@ -100,6 +139,80 @@ impl Mouse {
         Ok(())
     }
 
+    /// Move the mouse along a cubic Bezier curve to the specified coordinates
+    ///
+    /// The curve starts at the current position and ends exactly at `(x, y)`.
+    /// Each step honors the configured mouse delay.
+    pub fn move_mouse_bezier(
+        &self,
+        x: i32,
+        y: i32,
+        control_points: [MousePosition; 2],
+        steps: u32,
+    ) -> Result<()> {
+        let start = self.get_mouse_pos()?;
+        let end = MousePosition { x, y };
+        for point in bezier_path(start, control_points, end, steps) {
+            self.move_mouse(point.x, point.y)?;
+        }
+        Ok(())
+    }
+
+    /// Move the mouse by an offset from its current position
+    ///
+    /// The target is clamped to the bounding box of all monitors.
+    pub fn move_mouse_relative(&self, dx: i32, dy: i32) -> Result<()> {
+        let target = self.relative_target(dx, dy)?;
+        self.move_mouse(target.x, target.y)
+    }
+
+    /// Move the mouse smoothly by an offset from its current position
+    pub fn move_mouse_smooth_relative(&self, dx: i32, dy: i32) -> Result<()> {
+        self.move_mouse_smooth_relative_with_speed(dx, dy, 3.0)
+    }
+
+    /// Move the mouse smoothly by an offset with custom speed
+    pub fn move_mouse_smooth_relative_with_speed(
+        &self,
+        dx: i32,
+        dy: i32,
+        speed: f64,
+    ) -> Result<()> {
+        let target = self.relative_target(dx, dy)?;
+        self.move_mouse_smooth_with_speed(target.x, target.y, speed)
+    }
+
+    /// Resolve an offset from the current position to a point on the desktop
+    fn relative_target(&self, dx: i32, dy: i32) -> Result<MousePosition> {
+        let current = self.get_mouse_pos()?;
+        let bounds = self.desktop_bounds()?;
+        Ok(bounds.clamp(current.x.saturating_add(dx), current.y.saturating_add(dy)))
+    }
+
+    /// Bounding box of all monitors
+    ///
+    /// Falls back to the main display when monitors cannot be listed, which is
+    /// always the case without the `screen` feature.
+    fn desktop_bounds(&self) -> Result<DesktopBounds> {
+        #[cfg(feature = "screen")]
+        match crate::screen::get_monitors() {
+            Ok(monitors) => {
+                let rects = monitors.iter().map(|m| (m.x, m.y, m.width as i32, m.height as i32));
+                if let Some(bounds) = DesktopBounds::enclosing(rects) {
+                    return Ok(bounds);
+                }
+            }
+            Err(e) => log::debug!("Failed to list monitors, using the main display: {}", e),
+        }
+
+        let enigo =
+            self.enigo.lock().map_err(|e| AumateError::Input(format!("Lock error: {}", e)))?;
+        let (width, height) = enigo
+            .main_display()
+            .map_err(|e| AumateError::Input(format!("Failed to get display size: {}", e)))?;
+        Ok(DesktopBounds { left: 0, top: 0, right: width, bottom: height })
+    }
+
     /// Get the current mouse position
     pub fn get_mouse_pos(&self) -> Result<MousePosition> {
         let enigo =
@@ -203,6 +316,45 @@ impl Mouse {
         self.scroll(x, y)
     }
 
+    /// Scroll by `dx`/`dy` wheel notches spread evenly over `duration`
+    ///
+    /// Emits one-notch events instead of a single large delta, which some
+    /// applications need to trigger smooth or momentum scrolling.
+    pub fn scroll_smooth(&self, dx: i32, dy: i32, duration: Duration) -> Result<()> {
+        let steps = smooth_scroll_steps(dx, dy);
+        let interval = duration / steps.len().max(1) as u32;
+
+        for (step_x, step_y) in steps {
+            let mut enigo =
+                self.enigo.lock().map_err(|e| AumateError::Input(format!("Lock error: {}", e)))?;
+            if step_x != 0 {
+                let _ = enigo.scroll(step_x, Axis::Horizontal);
+            }
+            if step_y != 0 {
+                let _ = enigo.scroll(step_y, Axis::Vertical);
+            }
+            drop(enigo);
+            thread::sleep(interval);
+        }
+
+        self.apply_delay();
+        Ok(())
+    }
+
+    /// Scroll `count` lines (wheel notches) in a direction, one event per line
+    pub fn scroll_lines(&self, direction: ScrollDirection, count: u32) -> Result<()> {
+        let (x, y) = direction.delta();
+        let axis = if x != 0 { Axis::Horizontal } else { Axis::Vertical };
+        let mut enigo =
+            self.enigo.lock().map_err(|e| AumateError::Input(format!("Lock error: {}", e)))?;
+        for _ in 0..count {
+            let _ = enigo.scroll(x + y, axis);
+        }
+        drop(enigo);
+        self.apply_delay();
+        Ok(())
+    }
+
     /// Set the mouse delay in milliseconds
     pub fn set_delay(&self, delay_ms: u32) -> Result<()> {
         let mut delay =
@@ -224,8 +376,178 @@ impl Mouse {
     }
 }
 
+/// Sample `steps + 1` points along a cubic Bezier curve from `start` to `end`
+pub fn bezier_path(
+    start: MousePosition,
+    control_points: [MousePosition; 2],
+    end: MousePosition,
+    steps: u32,
+) -> Vec<MousePosition> {
+    let steps = steps.max(1);
+    let [c1, c2] = control_points;
+    (0..=steps)
+        .map(|i| {
+            let t = i as f64 / steps as f64;
+            let u = 1.0 - t;
+            let weights = [u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t];
+            let axis = |a: i32, b: i32, c: i32, d: i32| {
+                (weights[0] * a as f64
+                    + weights[1] * b as f64
+                    + weights[2] * c as f64
+                    + weights[3] * d as f64)
+                    .round() as i32
+            };
+            MousePosition {
+                x: axis(start.x, c1.x, c2.x, end.x),
+                y: axis(start.y, c1.y, c2.y, end.y),
+            }
+        })
+        .collect()
+}
+
+/// Generate control points for a natural-looking arc from `start` to `end`
+///
+/// The points sit at one and two thirds of the way along the straight line,
+/// pushed sideways by a random amount of up to 30% of the distance.
+pub fn random_bezier_control_points(
+    start: MousePosition,
+    end: MousePosition,
+) -> [MousePosition; 2] {
+    let mut rng = rand::rng();
+    let dx = (end.x - start.x) as f64;
+    let dy = (end.y - start.y) as f64;
+    let distance = (dx * dx + dy * dy).sqrt();
+    let (nx, ny) = if distance > 0.0 { (-dy / distance, dx / distance) } else { (0.0, 0.0) };
+    let mut control = |fraction: f64| {
+        let offset = rng.random_range(-0.3..=0.3) * distance;
+        MousePosition {
+            x: (start.x as f64 + dx * fraction + nx * offset).round() as i32,
+            y: (start.y as f64 + dy * fraction + ny * offset).round() as i32,
+        }
+    };
+    [control(1.0 / 3.0), control(2.0 / 3.0)]
+}
+
+/// Split a scroll into steps of at most one notch per axis that sum to `(dx, dy)`
+fn smooth_scroll_steps(dx: i32, dy: i32) -> Vec<(i32, i32)> {
+    let steps = dx.unsigned_abs().max(dy.unsigned_abs()) as i64;
+    let at = |total: i32, i: i64| (total as f64 * i as f64 / steps as f64).round() as i64;
+    (0..steps)
+        .map(|i| ((at(dx, i + 1) - at(dx, i)) as i32, (at(dy, i + 1) - at(dy, i)) as i32))
+        .collect()
+}
+
+/// Rectangle enclosing all monitors; `right` and `bottom` are exclusive
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct DesktopBounds {
+    left: i32,
+    top: i32,
+    right: i32,
+    bottom: i32,
+}
+
+impl DesktopBounds {
+    /// Smallest rectangle enclosing `(x, y, width, height)` rectangles, `None` if empty
+    #[cfg(feature = "screen")]
+    fn enclosing(rects: impl IntoIterator<Item = (i32, i32, i32, i32)>) -> Option<Self> {
+        rects
+            .into_iter()
+            .map(|(x, y, width, height)| Self {
+                left: x,
+                top: y,
+                right: x.saturating_add(width),
+                bottom: y.saturating_add(height),
+            })
+            .reduce(|a, b| Self {
+                left: a.left.min(b.left),
+                top: a.top.min(b.top),
+                right: a.right.max(b.right),
+                bottom: a.bottom.max(b.bottom),
+            })
+    }
+
+    /// Clamp a point into the bounds
+    fn clamp(&self, x: i32, y: i32) -> MousePosition {
+        MousePosition {
+            x: x.clamp(self.left, (self.right - 1).max(self.left)),
+            y: y.clamp(self.top, (self.bottom - 1).max(self.top)),
+        }
+    }
+}
+
 impl Default for Mouse {
     fn default() -> Self {
         Self::new().expect("Failed to create Mouse")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_smooth_scroll_steps() {
+        let steps = smooth_scroll_steps(3, -7);
+        assert_eq!(steps.len(), 7);
+        assert!(steps.iter().all(|&(x, y)| x.abs() <= 1 && y == -1));
+        assert_eq!(steps.iter().map(|s| s.0).sum::<i32>(), 3);
+        assert!(smooth_scroll_steps(0, 0).is_empty());
+    }
+
+    #[test]
+    fn test_scroll_direction_from_str() {
+        assert_eq!("Up".parse::<ScrollDirection>().unwrap(), ScrollDirection::Up);
+        assert_eq!("right".parse::<ScrollDirection>().unwrap().delta(), (1, 0));
+        assert!("sideways".parse::<ScrollDirection>().is_err());
+    }
+
+    #[test]
+    fn test_bezier_path_endpoints() {
+        let start = MousePosition { x: 10, y: 20 };
+        let end = MousePosition { x: 640, y: 480 };
+        let controls = [MousePosition { x: 100, y: 400 }, MousePosition { x: 500, y: -50 }];
+        let path = bezier_path(start, controls, end, 25);
+        assert_eq!(path.len(), 26);
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last(), Some(&end));
+    }
+
+    #[test]
+    fn test_bezier_path_zero_steps() {
+        let start = MousePosition { x: 0, y: 0 };
+        let end = MousePosition { x: 5, y: 5 };
+        let path = bezier_path(start, [start, end], end, 0);
+        assert_eq!(path, vec![start, end]);
+    }
+
+    #[test]
+    fn test_random_bezier_control_points_degenerate() {
+        let point = MousePosition { x: 42, y: 7 };
+        assert_eq!(random_bezier_control_points(point, point), [point, point]);
+    }
+
+    #[test]
+    fn test_desktop_bounds_clamp_inside() {
+        let bounds = DesktopBounds { left: 0, top: 0, right: 1920, bottom: 1080 };
+        assert_eq!(bounds.clamp(100, 200), MousePosition { x: 100, y: 200 });
+    }
+
+    #[test]
+    fn test_desktop_bounds_clamp_edges() {
+        let bounds = DesktopBounds { left: 0, top: 0, right: 1920, bottom: 1080 };
+        assert_eq!(bounds.clamp(-50, 5000), MousePosition { x: 0, y: 1079 });
+        assert_eq!(bounds.clamp(2500, -1), MousePosition { x: 1919, y: 0 });
+    }
+
+    #[cfg(feature = "screen")]
+    #[test]
+    fn test_desktop_bounds_enclosing_monitors() {
+        // Secondary monitor left of and above the primary one
+        let bounds =
+            DesktopBounds::enclosing([(0, 0, 1920, 1080), (-1280, -200, 1280, 1024)]).unwrap();
+        assert_eq!(bounds, DesktopBounds { left: -1280, top: -200, right: 1920, bottom: 1080 });
+        assert_eq!(bounds.clamp(-1500, 500), MousePosition { x: -1280, y: 500 });
+        assert_eq!(bounds.clamp(2500, -300), MousePosition { x: 1919, y: -200 });
+        assert!(DesktopBounds::enclosing([]).is_none());
+    }
+}
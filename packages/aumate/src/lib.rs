@@ -14,6 +14,9 @@
 //! - `clipboard` - Clipboard operations (enabled by default)
 //! - `window` - Window management (enabled by default)
 //! - `image_match` - Image template matching
+//! - `macros` - Input macro recording and replay
+//!
+//! Permission checks (`permissions`) are always available.
 //!
 //! # Example
 //!
@@ -31,6 +34,8 @@
 
 pub mod error;
 
+pub mod permissions;
+
 #[cfg(feature = "input")]
 pub mod input;
 
@@ -46,6 +51,9 @@ pub mod window;
 #[cfg(feature = "eventhooks")]
 pub mod eventhooks;
 
+#[cfg(feature = "macros")]
+pub mod macros;
+
 #[cfg(feature = "ml")]
 pub mod ml;
 
@@ -63,12 +71,14 @@ pub mod prelude {
     pub use crate::error::{AumateError, Result};
 
     #[cfg(feature = "input")]
-    pub use crate::input::{Keyboard, Mouse, MouseButton, MousePosition};
+    pub use crate::input::{Keyboard, Mouse, MouseButton, MousePosition, ScrollDirection};
 
     #[cfg(feature = "screen")]
     pub use crate::screen::{
-        MonitorInfo, PixelColor, ScreenCapture, ScreenSize, capture_screen, capture_screen_region,
-        get_monitors, get_pixel_color, get_screen_size,
+        CaptureFrame, CaptureStream, Hsv, MonitorInfo, PixelColor, ScreenCapture, ScreenSize,
+        capture_screen, capture_screen_region, capture_stream, capture_window, get_monitors,
+        get_pixel_color, get_primary_monitor, get_region_average_color, get_region_dominant_color,
+        get_screen_scale_factor, get_screen_size, wait_for_pixel_color,
     };
 
     #[cfg(feature = "clipboard")]
@@ -77,6 +87,6 @@ pub mod prelude {
     #[cfg(feature = "window")]
     pub use crate::window::{
         WindowInfo, find_windows_by_process, find_windows_by_title, get_active_window_info,
-        get_all_windows,
+        get_all_windows, get_visible_windows,
     };
 }
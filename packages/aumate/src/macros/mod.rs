@@ -0,0 +1,266 @@
+//! Input macro recording and replay
+//!
+//! [`Recorder`] observes global keyboard and mouse input through
+//! [`eventhooks::listen`](crate::eventhooks::listen) and stores it as a
+//! timestamped [`Recording`]. [`Player`] replays a recording through
+//! [`Mouse`](crate::input::Mouse) and [`Keyboard`](crate::input::Keyboard),
+//! keeping the original relative timing or scaling it by a speed multiplier.
+//!
+//! Recordings serialize to JSON so they can be saved to disk:
+//!
+//! ```no_run
+//! use aumate::macros::{Player, Recorder, Recording};
+//!
+//! let recorder = Recorder::start().unwrap();
+//! std::thread::sleep(std::time::Duration::from_secs(5));
+//! let recording = recorder.stop().unwrap();
+//! std::fs::write("macro.json", recording.to_bytes().unwrap()).unwrap();
+//!
+//! let recording = Recording::from_bytes(&std::fs::read("macro.json").unwrap()).unwrap();
+//! Player::new().unwrap().play(&recording, 2.0).unwrap();
+//! ```
+
+mod player;
+mod recorder;
+
+pub use player::Player;
+pub use recorder::Recorder;
+
+use crate::error::{AumateError, Result};
+use crate::eventhooks::{Button, EventType, Key};
+use serde::{Deserialize, Serialize};
+
+/// Current recording format version
+pub const RECORDING_VERSION: u32 = 1;
+
+/// A recorded sequence of input events
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Recording {
+    /// Format version
+    pub version: u32,
+    /// Events in chronological order
+    pub events: Vec<MacroEvent>,
+}
+
+impl Recording {
+    /// Serialize the recording to JSON bytes
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        serde_json::to_vec(self)
+            .map_err(|e| AumateError::Other(format!("Failed to serialize recording: {}", e)))
+    }
+
+    /// Deserialize a recording from JSON bytes
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let recording: Recording = serde_json::from_slice(bytes)
+            .map_err(|e| AumateError::Other(format!("Failed to parse recording: {}", e)))?;
+        if recording.version > RECORDING_VERSION {
+            return Err(AumateError::Other(format!(
+                "Unsupported recording version: {}",
+                recording.version
+            )));
+        }
+        Ok(recording)
+    }
+}
+
+/// A single recorded event
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MacroEvent {
+    /// Milliseconds since the recording started
+    pub offset_ms: u64,
+    /// The input action to replay
+    pub action: MacroAction,
+}
+
+/// Input action in a recording
+///
+/// Keys and buttons are stored by the names accepted by
+/// [`Keyboard::key_toggle`](crate::input::Keyboard::key_toggle) and
+/// [`MouseButton`](crate::input::MouseButton).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MacroAction {
+    KeyPress { key: String },
+    KeyRelease { key: String },
+    ButtonPress { button: String },
+    ButtonRelease { button: String },
+    MouseMove { x: f64, y: f64 },
+    Wheel { delta_x: i64, delta_y: i64 },
+}
+
+impl MacroAction {
+    /// Convert a hook event to a replayable action
+    ///
+    /// Returns `None` for keys and buttons that cannot be replayed.
+    pub fn from_event(event_type: &EventType) -> Option<Self> {
+        let action = match *event_type {
+            EventType::KeyPress(key) => MacroAction::KeyPress { key: key_name(key)?.to_string() },
+            EventType::KeyRelease(key) => {
+                MacroAction::KeyRelease { key: key_name(key)?.to_string() }
+            }
+            EventType::ButtonPress(button) => {
+                MacroAction::ButtonPress { button: button_name(button)?.to_string() }
+            }
+            EventType::ButtonRelease(button) => {
+                MacroAction::ButtonRelease { button: button_name(button)?.to_string() }
+            }
+            EventType::MouseMove { x, y } => MacroAction::MouseMove { x, y },
+            EventType::Wheel { delta_x, delta_y } => MacroAction::Wheel { delta_x, delta_y },
+        };
+        Some(action)
+    }
+}
+
+/// Map a hook key to the name understood by `Keyboard`
+fn key_name(key: Key) -> Option<&'static str> {
+    let name = match key {
+        Key::Alt | Key::AltGr => "alt",
+        Key::ControlLeft | Key::ControlRight => "control",
+        Key::ShiftLeft | Key::ShiftRight => "shift",
+        Key::MetaLeft | Key::MetaRight => "command",
+        Key::F1 => "f1",
+        Key::F2 => "f2",
+        Key::F3 => "f3",
+        Key::F4 => "f4",
+        Key::F5 => "f5",
+        Key::F6 => "f6",
+        Key::F7 => "f7",
+        Key::F8 => "f8",
+        Key::F9 => "f9",
+        Key::F10 => "f10",
+        Key::F11 => "f11",
+        Key::F12 => "f12",
+        Key::Return | Key::KpReturn => "enter",
+        Key::Escape => "escape",
+        Key::Backspace => "backspace",
+        Key::Tab => "tab",
+        Key::Space => "space",
+        Key::Delete => "delete",
+        Key::UpArrow => "up",
+        Key::DownArrow => "down",
+        Key::LeftArrow => "left",
+        Key::RightArrow => "right",
+        Key::Home => "home",
+        Key::End => "end",
+        Key::PageUp => "pageup",
+        Key::PageDown => "pagedown",
+        Key::KeyA => "a",
+        Key::KeyB => "b",
+        Key::KeyC => "c",
+        Key::KeyD => "d",
+        Key::KeyE => "e",
+        Key::KeyF => "f",
+        Key::KeyG => "g",
+        Key::KeyH => "h",
+        Key::KeyI => "i",
+        Key::KeyJ => "j",
+        Key::KeyK => "k",
+        Key::KeyL => "l",
+        Key::KeyM => "m",
+        Key::KeyN => "n",
+        Key::KeyO => "o",
+        Key::KeyP => "p",
+        Key::KeyQ => "q",
+        Key::KeyR => "r",
+        Key::KeyS => "s",
+        Key::KeyT => "t",
+        Key::KeyU => "u",
+        Key::KeyV => "v",
+        Key::KeyW => "w",
+        Key::KeyX => "x",
+        Key::KeyY => "y",
+        Key::KeyZ => "z",
+        Key::Num0 | Key::Kp0 => "0",
+        Key::Num1 | Key::Kp1 => "1",
+        Key::Num2 | Key::Kp2 => "2",
+        Key::Num3 | Key::Kp3 => "3",
+        Key::Num4 | Key::Kp4 => "4",
+        Key::Num5 | Key::Kp5 => "5",
+        Key::Num6 | Key::Kp6 => "6",
+        Key::Num7 | Key::Kp7 => "7",
+        Key::Num8 | Key::Kp8 => "8",
+        Key::Num9 | Key::Kp9 => "9",
+        Key::BackQuote => "`",
+        Key::Minus | Key::KpMinus => "-",
+        Key::Equal | Key::KpEqual => "=",
+        Key::LeftBracket => "[",
+        Key::RightBracket => "]",
+        Key::BackSlash => "\\",
+        Key::SemiColon => ";",
+        Key::Quote => "'",
+        Key::Comma | Key::KpComma => ",",
+        Key::Dot | Key::KpDecimal => ".",
+        Key::Slash | Key::KpDivide => "/",
+        Key::KpPlus => "+",
+        Key::KpMultiply => "*",
+        _ => return None,
+    };
+    Some(name)
+}
+
+/// Map a hook button to the name understood by `MouseButton`
+fn button_name(button: Button) -> Option<&'static str> {
+    match button {
+        Button::Left => Some("left"),
+        Button::Right => Some("right"),
+        Button::Middle => Some("middle"),
+        Button::Unknown(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_event_maps_keys_and_buttons() {
+        assert_eq!(
+            MacroAction::from_event(&EventType::KeyPress(Key::KeyA)),
+            Some(MacroAction::KeyPress { key: "a".to_string() })
+        );
+        assert_eq!(
+            MacroAction::from_event(&EventType::KeyRelease(Key::ShiftRight)),
+            Some(MacroAction::KeyRelease { key: "shift".to_string() })
+        );
+        assert_eq!(
+            MacroAction::from_event(&EventType::ButtonPress(Button::Right)),
+            Some(MacroAction::ButtonPress { button: "right".to_string() })
+        );
+        assert_eq!(
+            MacroAction::from_event(&EventType::MouseMove { x: 1.0, y: 2.0 }),
+            Some(MacroAction::MouseMove { x: 1.0, y: 2.0 })
+        );
+    }
+
+    #[test]
+    fn test_from_event_skips_unreplayable() {
+        assert_eq!(MacroAction::from_event(&EventType::KeyPress(Key::F20)), None);
+        assert_eq!(MacroAction::from_event(&EventType::ButtonPress(Button::Unknown(8))), None);
+    }
+
+    #[test]
+    fn test_recording_round_trip() {
+        let recording = Recording {
+            version: RECORDING_VERSION,
+            events: vec![
+                MacroEvent { offset_ms: 0, action: MacroAction::MouseMove { x: 10.0, y: 20.0 } },
+                MacroEvent {
+                    offset_ms: 150,
+                    action: MacroAction::KeyPress { key: "enter".to_string() },
+                },
+                MacroEvent {
+                    offset_ms: 300,
+                    action: MacroAction::Wheel { delta_x: 0, delta_y: -1 },
+                },
+            ],
+        };
+        let bytes = recording.to_bytes().unwrap();
+        assert_eq!(Recording::from_bytes(&bytes).unwrap(), recording);
+    }
+
+    #[test]
+    fn test_recording_rejects_newer_version() {
+        let bytes = br#"{"version":99,"events":[]}"#;
+        assert!(Recording::from_bytes(bytes).is_err());
+    }
+}
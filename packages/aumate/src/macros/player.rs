@@ -0,0 +1,155 @@
+//! Input macro player
+
+use super::{MacroAction, Recording};
+use crate::error::{AumateError, Result};
+use crate::input::{Keyboard, Mouse, MouseButton};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Longest sleep between checks of the cancellation flag
+const CANCEL_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Replays recordings through `Mouse` and `Keyboard`
+pub struct Player {
+    mouse: Mouse,
+    keyboard: Keyboard,
+}
+
+impl Player {
+    /// Create a new player
+    ///
+    /// Input delays are disabled because timing comes from the recording.
+    pub fn new() -> Result<Self> {
+        let mouse = Mouse::new()?;
+        mouse.set_delay(0)?;
+        let keyboard = Keyboard::new()?;
+        keyboard.set_delay(0)?;
+        Ok(Self { mouse, keyboard })
+    }
+
+    /// Replay a recording
+    ///
+    /// `speed` scales playback: `1.0` keeps the original timing, `2.0` plays
+    /// twice as fast.
+    pub fn play(&self, recording: &Recording, speed: f64) -> Result<()> {
+        self.play_cancellable(recording, speed, &AtomicBool::new(false)).map(|_| ())
+    }
+
+    /// Replay a recording, stopping early once `cancel` is set
+    ///
+    /// The flag is checked before each event and while waiting for it, so
+    /// another thread can abort playback mid-way. Keys and buttons the
+    /// recording left pressed are released when playback ends, fails or is
+    /// cancelled.
+    ///
+    /// # Returns
+    /// `true` if the whole recording was played, `false` if it was cancelled
+    pub fn play_cancellable(
+        &self,
+        recording: &Recording,
+        speed: f64,
+        cancel: &AtomicBool,
+    ) -> Result<bool> {
+        if !speed.is_finite() || speed <= 0.0 {
+            return Err(AumateError::Input(format!("Invalid playback speed: {}", speed)));
+        }
+
+        let mut held = HeldInputs { player: self, keys: Vec::new(), buttons: Vec::new() };
+        let started = Instant::now();
+        for event in &recording.events {
+            let due = scaled_offset(event.offset_ms, speed);
+            while let Some(wait) = due.checked_sub(started.elapsed()) {
+                if cancel.load(Ordering::SeqCst) {
+                    return Ok(false);
+                }
+                thread::sleep(wait.min(CANCEL_POLL_INTERVAL));
+            }
+            if cancel.load(Ordering::SeqCst) {
+                return Ok(false);
+            }
+            self.apply(&event.action)?;
+            held.track(&event.action)?;
+        }
+        Ok(true)
+    }
+
+    fn apply(&self, action: &MacroAction) -> Result<()> {
+        match action {
+            MacroAction::KeyPress { key } => self.keyboard.key_toggle(key, "down", None),
+            MacroAction::KeyRelease { key } => self.keyboard.key_toggle(key, "up", None),
+            MacroAction::ButtonPress { button } => self.mouse.press(MouseButton::from_str(button)?),
+            MacroAction::ButtonRelease { button } => {
+                self.mouse.release(MouseButton::from_str(button)?)
+            }
+            MacroAction::MouseMove { x, y } => {
+                self.mouse.move_mouse(x.round() as i32, y.round() as i32)
+            }
+            // Hook deltas are positive for scrolling up, enigo scrolls down for positive values
+            MacroAction::Wheel { delta_x, delta_y } => {
+                self.mouse.scroll(*delta_x as i32, -(*delta_y as i32))
+            }
+        }
+    }
+}
+
+/// Keys and buttons pressed during playback, released when dropped
+struct HeldInputs<'a> {
+    player: &'a Player,
+    keys: Vec<String>,
+    buttons: Vec<MouseButton>,
+}
+
+impl HeldInputs<'_> {
+    /// Record the effect of an action that was just replayed
+    fn track(&mut self, action: &MacroAction) -> Result<()> {
+        match action {
+            MacroAction::KeyPress { key } if !self.keys.contains(key) => {
+                self.keys.push(key.clone())
+            }
+            MacroAction::KeyRelease { key } => self.keys.retain(|held| held != key),
+            MacroAction::ButtonPress { button } => {
+                let button = MouseButton::from_str(button)?;
+                if !self.buttons.contains(&button) {
+                    self.buttons.push(button);
+                }
+            }
+            MacroAction::ButtonRelease { button } => {
+                let button = MouseButton::from_str(button)?;
+                self.buttons.retain(|&held| held != button);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+impl Drop for HeldInputs<'_> {
+    fn drop(&mut self) {
+        for key in self.keys.drain(..).rev() {
+            let _ = self.player.keyboard.key_toggle(&key, "up", None);
+        }
+        for button in self.buttons.drain(..).rev() {
+            let _ = self.player.mouse.release(button);
+        }
+    }
+}
+
+/// Playback time of an event recorded at `offset_ms`
+fn scaled_offset(offset_ms: u64, speed: f64) -> Duration {
+    Duration::from_secs_f64(offset_ms as f64 / 1000.0 / speed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scaled_offset() {
+        assert_eq!(scaled_offset(1000, 1.0), Duration::from_secs(1));
+        assert_eq!(scaled_offset(1000, 2.0), Duration::from_millis(500));
+        assert_eq!(scaled_offset(300, 0.5), Duration::from_millis(600));
+        assert_eq!(scaled_offset(0, 3.0), Duration::ZERO);
+    }
+}
@@ -0,0 +1,66 @@
+//! Input macro recorder
+
+use super::{MacroAction, MacroEvent, RECORDING_VERSION, Recording};
+use crate::error::{AumateError, Result};
+use crate::eventhooks::{self, Event, GrabError};
+use crate::input;
+use crate::permissions;
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, SystemTime};
+
+/// Records global input events until stopped
+pub struct Recorder {
+    events: Arc<Mutex<Vec<MacroEvent>>>,
+    thread: JoinHandle<std::result::Result<(), GrabError>>,
+}
+
+impl Recorder {
+    /// Start recording on a background listener thread
+    ///
+    /// Fails if the global input listener is already in use, e.g. by global
+    /// hotkeys.
+    pub fn start() -> Result<Self> {
+        permissions::require_accessibility("to record input")?;
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = events.clone();
+        let started = SystemTime::now();
+
+        let thread = input::spawn_listener("aumate-macro-recorder", move |event: Event| {
+            let Some(action) = MacroAction::from_event(&event.event_type) else {
+                return;
+            };
+            let offset_ms =
+                event.time.duration_since(started).unwrap_or(Duration::ZERO).as_millis() as u64;
+            if let Ok(mut events) = sink.lock() {
+                events.push(MacroEvent { offset_ms, action });
+            }
+        })?;
+
+        Ok(Self { events, thread })
+    }
+
+    /// Stop recording and return the captured events
+    pub fn stop(self) -> Result<Recording> {
+        // The listener may not be running yet, so keep asking until the thread exits
+        while !self.thread.is_finished() {
+            eventhooks::stop_listen()
+                .map_err(|e| AumateError::Input(format!("Failed to stop listener: {:?}", e)))?;
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        self.thread
+            .join()
+            .map_err(|_| AumateError::Input("Recorder thread panicked".to_string()))?
+            .map_err(AumateError::from_listen_error)?;
+
+        let events = self
+            .events
+            .lock()
+            .map_err(|e| AumateError::Input(format!("Lock error: {}", e)))?
+            .drain(..)
+            .collect();
+        Ok(Recording { version: RECORDING_VERSION, events })
+    }
+}
@@ -6,17 +6,42 @@
 
 use crate::error::Result;
 use candle_core::Device as CandleDevice;
+use serde::{Deserialize, Serialize};
 
 /// Re-export Candle's Device type
 pub type Device = CandleDevice;
 
+/// Which device to run inference on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DevicePreference {
+    /// CPU only
+    #[default]
+    Cpu,
+    /// Best available GPU: Metal on macOS, otherwise the first CUDA device
+    Gpu,
+    /// A specific CUDA device (requires the `ml-cuda` feature)
+    Cuda { index: usize },
+    /// Metal (requires macOS and the `ml-metal` feature)
+    Metal,
+}
+
+impl std::fmt::Display for DevicePreference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DevicePreference::Cpu => write!(f, "CPU"),
+            DevicePreference::Gpu => write!(f, "GPU"),
+            DevicePreference::Cuda { index } => write!(f, "CUDA {}", index),
+            DevicePreference::Metal => write!(f, "Metal"),
+        }
+    }
+}
+
 /// Configuration for device selection
 #[derive(Debug, Clone, Default)]
 pub struct DeviceConfig {
-    /// Prefer GPU acceleration if available
-    pub prefer_gpu: bool,
-    /// Specific CUDA device ordinal (if using CUDA)
-    pub cuda_ordinal: usize,
+    /// Preferred device; falls back to CPU with a warning if unavailable
+    pub prefer: DevicePreference,
 }
 
 impl DeviceConfig {
@@ -27,49 +52,111 @@ impl DeviceConfig {
 
     /// Create a config that prefers GPU acceleration
     pub fn with_gpu() -> Self {
-        Self { prefer_gpu: true, cuda_ordinal: 0 }
+        Self { prefer: DevicePreference::Gpu }
     }
 
     /// Create a config for CPU only
     pub fn cpu_only() -> Self {
-        Self { prefer_gpu: false, cuda_ordinal: 0 }
+        Self { prefer: DevicePreference::Cpu }
+    }
+
+    /// Create a config for a specific CUDA device
+    pub fn cuda(index: usize) -> Self {
+        Self { prefer: DevicePreference::Cuda { index } }
+    }
+
+    /// Create a config for Metal
+    pub fn metal() -> Self {
+        Self { prefer: DevicePreference::Metal }
     }
 }
 
+impl From<DevicePreference> for DeviceConfig {
+    fn from(prefer: DevicePreference) -> Self {
+        Self { prefer }
+    }
+}
+
+/// Whether any GPU backend was compiled in
+const GPU_BACKEND_BUILT: bool =
+    cfg!(any(feature = "ml-cuda", all(target_os = "macos", feature = "ml-metal")));
+
 /// Get the appropriate device based on configuration and available hardware
+///
+/// If the preferred device cannot be used, logs a warning and returns the CPU.
+/// [`DevicePreference::Gpu`] only asks for a GPU when there is one, so on builds
+/// without GPU support it falls back quietly.
 pub fn get_device(config: &DeviceConfig) -> Result<Device> {
-    if config.prefer_gpu {
-        // Try Metal on macOS
-        #[cfg(all(target_os = "macos", feature = "ml-metal"))]
-        {
-            match CandleDevice::new_metal(0) {
-                Ok(device) => {
-                    log::info!("Using Metal device for acceleration");
-                    return Ok(device);
-                }
-                Err(e) => {
-                    log::warn!("Metal not available, falling back to CPU: {}", e);
-                }
+    let device = match config.prefer {
+        DevicePreference::Cpu => None,
+        DevicePreference::Gpu if !GPU_BACKEND_BUILT => {
+            log::info!("No GPU support built, using CPU");
+            None
+        }
+        DevicePreference::Gpu => {
+            if cfg!(target_os = "macos") {
+                try_metal()
+            } else {
+                try_cuda(0)
+            }
+        }
+        DevicePreference::Cuda { index } => try_cuda(index),
+        DevicePreference::Metal => try_metal(),
+    };
+
+    Ok(device.unwrap_or_else(|| {
+        log::info!("Using CPU device");
+        CandleDevice::Cpu
+    }))
+}
+
+/// Open a Metal device, or None (with a warning) if unavailable
+fn try_metal() -> Option<Device> {
+    #[cfg(all(target_os = "macos", feature = "ml-metal"))]
+    {
+        match CandleDevice::new_metal(0) {
+            Ok(device) => {
+                log::info!("Using Metal device for acceleration");
+                Some(device)
+            }
+            Err(e) => {
+                log::warn!("Metal not available, falling back to CPU: {}", e);
+                None
             }
         }
+    }
+
+    #[cfg(not(all(target_os = "macos", feature = "ml-metal")))]
+    {
+        log::warn!("Metal support not built (requires macOS and ml-metal), falling back to CPU");
+        None
+    }
+}
 
-        // Try CUDA on Linux/Windows
-        #[cfg(feature = "ml-cuda")]
-        {
-            match CandleDevice::new_cuda(config.cuda_ordinal) {
-                Ok(device) => {
-                    log::info!("Using CUDA device {} for acceleration", config.cuda_ordinal);
-                    return Ok(device);
-                }
-                Err(e) => {
-                    log::warn!("CUDA not available, falling back to CPU: {}", e);
-                }
+/// Open a CUDA device, or None (with a warning) if unavailable
+fn try_cuda(index: usize) -> Option<Device> {
+    #[cfg(feature = "ml-cuda")]
+    {
+        match CandleDevice::new_cuda(index) {
+            Ok(device) => {
+                log::info!("Using CUDA device {} for acceleration", index);
+                Some(device)
+            }
+            Err(e) => {
+                log::warn!("CUDA device {} not available, falling back to CPU: {}", index, e);
+                None
             }
         }
     }
 
-    log::info!("Using CPU device");
-    Ok(CandleDevice::Cpu)
+    #[cfg(not(feature = "ml-cuda"))]
+    {
+        log::warn!(
+            "CUDA support not built (requires ml-cuda), device {} unavailable, falling back to CPU",
+            index
+        );
+        None
+    }
 }
 
 /// Get the best available device (prefers GPU)
@@ -116,6 +203,27 @@ mod tests {
         assert!(matches!(device, Device::Cpu));
     }
 
+    #[cfg(not(feature = "ml-cuda"))]
+    #[test]
+    fn test_unavailable_device_falls_back_to_cpu() {
+        let device = get_device(&DeviceConfig::cuda(1)).unwrap();
+        assert!(matches!(device, Device::Cpu));
+    }
+
+    #[test]
+    fn test_device_preference_serialization() {
+        let json = serde_json::to_string(&DevicePreference::Cuda { index: 1 }).unwrap();
+        assert_eq!(json, r#"{"cuda":{"index":1}}"#);
+        let parsed: DevicePreference = serde_json::from_str(r#""metal""#).unwrap();
+        assert_eq!(parsed, DevicePreference::Metal);
+    }
+
+    #[test]
+    fn test_device_preference_display() {
+        assert_eq!(DevicePreference::Cpu.to_string(), "CPU");
+        assert_eq!(DevicePreference::Cuda { index: 1 }.to_string(), "CUDA 1");
+    }
+
     #[test]
     fn test_device_name() {
         let device = Device::Cpu;
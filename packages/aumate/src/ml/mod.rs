@@ -11,11 +11,14 @@ mod download;
 mod model;
 mod text_decoder;
 
-pub use device::{Device, DeviceConfig, device_name, get_device, is_gpu_available};
+pub use device::{
+    Device, DeviceConfig, DevicePreference, device_name, get_device, is_gpu_available,
+};
 pub use download::{ModelDownloader, ModelSource, download_model};
 pub use model::{
     DownloadProgress, DownloadStatus, ModelInfo, ModelManager, ModelType, TROCR_FILES,
     TROCR_MODELS, VAD_MODEL_ID, VAD_MODEL_SIZE, VAD_MODEL_URL, WHISPER_FILES, WHISPER_MODELS,
+    WHISPER_QUANTIZED_FILES, WHISPER_QUANTIZED_MODELS,
 };
 pub use text_decoder::{DecodingConfig, TextDecoder};
 
@@ -48,6 +48,10 @@ pub struct ModelInfo {
     pub repo_id: String,
     /// Files to download
     pub files: Vec<String>,
+    /// Whether the weights are quantized (GGUF) rather than full precision
+    ///
+    /// Only Whisper has quantized variants; TrOCR is always full precision
+    pub quantized: bool,
     /// Whether the model is downloaded
     pub is_downloaded: bool,
     /// Local directory path if downloaded
@@ -144,6 +148,47 @@ pub const WHISPER_MODELS: &[(&str, &str, &str, u64)] = &[
 /// Standard files for Whisper models
 pub const WHISPER_FILES: &[&str] = &["model.safetensors", "config.json", "tokenizer.json"];
 
+/// Available quantized Whisper models: (id, name, repo, variant, size)
+///
+/// 8-bit (Q8_0) weights are about half the download of the full precision
+/// models and decode faster on CPU, for a small loss in accuracy that shows
+/// mostly on noisy or accented speech. On GPU full precision is about as fast,
+/// so prefer it there. TrOCR has no quantized variants.
+///
+/// The repo holds every variant side by side; files are renamed to
+/// [`WHISPER_QUANTIZED_FILES`] on download
+pub const WHISPER_QUANTIZED_MODELS: &[(&str, &str, &str, &str, u64)] = &[
+    ("whisper-tiny-q8", "Whisper Tiny (Q8)", "lmz/candle-whisper", "tiny", 41_000_000),
+    (
+        "whisper-tiny.en-q8",
+        "Whisper Tiny (English, Q8)",
+        "lmz/candle-whisper",
+        "tiny-en",
+        41_000_000,
+    ),
+];
+
+/// Files for quantized Whisper models, as stored locally
+pub const WHISPER_QUANTIZED_FILES: &[&str] = &["model.gguf", "config.json", "tokenizer.json"];
+
+/// Remote file names for a quantized Whisper variant
+fn whisper_quantized_files(variant: &str) -> Vec<String> {
+    vec![
+        format!("model-{}-q80.gguf", variant),
+        format!("config-{}.json", variant),
+        format!("tokenizer-{}.json", variant),
+    ]
+}
+
+/// Name a quantized model file is stored under locally
+///
+/// Strips the variant suffix, e.g. `model-tiny-q80.gguf` becomes `model.gguf`
+fn quantized_local_name(remote: &str) -> String {
+    let (stem, ext) = remote.rsplit_once('.').unwrap_or((remote, ""));
+    let base = stem.split('-').next().unwrap_or(stem);
+    if ext.is_empty() { base.to_string() } else { format!("{}.{}", base, ext) }
+}
+
 // ==================== TrOCR Models ====================
 
 /// Available TrOCR models
@@ -179,6 +224,9 @@ pub const TROCR_MODELS: &[(&str, &str, &str, &str, u64)] = &[
 ];
 
 /// Standard files for TrOCR models
+///
+/// Candle has no quantized TrOCR implementation, so only full precision
+/// weights are available.
 /// TrOCR uses RoBERTa tokenizer with vocab.json + merges.txt instead of tokenizer.json
 pub const TROCR_FILES: &[&str] = &[
     "model.safetensors",
@@ -238,6 +286,7 @@ impl ModelManager {
 
         // Check if all required files exist
         let files = match model_type {
+            ModelType::Whisper if is_quantized_whisper(model_id) => WHISPER_QUANTIZED_FILES,
             ModelType::Whisper => WHISPER_FILES,
             ModelType::TrOCR => TROCR_FILES,
             ModelType::Vad => return dir.join("silero_vad.onnx").exists(),
@@ -246,27 +295,44 @@ impl ModelManager {
         files.iter().all(|f| dir.join(f).exists())
     }
 
-    /// List all available Whisper models
+    /// List all available Whisper models, full precision first, then quantized
     pub fn list_whisper_models(&self) -> Vec<ModelInfo> {
-        WHISPER_MODELS
-            .iter()
-            .map(|(id, name, repo_id, size)| {
-                let is_downloaded = self.is_downloaded(ModelType::Whisper, id);
-                let local_path =
-                    if is_downloaded { Some(self.model_dir(ModelType::Whisper, id)) } else { None };
-                ModelInfo {
-                    id: id.to_string(),
-                    name: name.to_string(),
-                    description: format!("~{}", format_size(*size)),
-                    model_type: ModelType::Whisper,
-                    size_bytes: *size,
-                    repo_id: repo_id.to_string(),
-                    files: WHISPER_FILES.iter().map(|s| s.to_string()).collect(),
-                    is_downloaded,
-                    local_path,
-                }
-            })
-            .collect()
+        let normal = WHISPER_MODELS.iter().map(|(id, name, repo_id, size)| {
+            let files = WHISPER_FILES.iter().map(|s| s.to_string()).collect();
+            self.whisper_model_info(id, name, repo_id, *size, files, false)
+        });
+        let quantized =
+            WHISPER_QUANTIZED_MODELS.iter().map(|(id, name, repo_id, variant, size)| {
+                let files = whisper_quantized_files(variant);
+                self.whisper_model_info(id, name, repo_id, *size, files, true)
+            });
+        normal.chain(quantized).collect()
+    }
+
+    fn whisper_model_info(
+        &self,
+        id: &str,
+        name: &str,
+        repo_id: &str,
+        size: u64,
+        files: Vec<String>,
+        quantized: bool,
+    ) -> ModelInfo {
+        let is_downloaded = self.is_downloaded(ModelType::Whisper, id);
+        let local_path =
+            if is_downloaded { Some(self.model_dir(ModelType::Whisper, id)) } else { None };
+        ModelInfo {
+            id: id.to_string(),
+            name: name.to_string(),
+            description: format!("~{}", format_size(size)),
+            model_type: ModelType::Whisper,
+            size_bytes: size,
+            repo_id: repo_id.to_string(),
+            files,
+            quantized,
+            is_downloaded,
+            local_path,
+        }
     }
 
     /// List all available TrOCR models
@@ -285,6 +351,7 @@ impl ModelManager {
                     size_bytes: *size,
                     repo_id: repo_id.to_string(),
                     files: TROCR_FILES.iter().map(|s| s.to_string()).collect(),
+                    quantized: false,
                     is_downloaded,
                     local_path,
                 }
@@ -314,6 +381,7 @@ impl ModelManager {
             size_bytes: VAD_MODEL_SIZE,
             repo_id: String::new(),
             files: vec!["silero_vad.onnx".to_string()],
+            quantized: false,
             is_downloaded,
             local_path,
         }
@@ -373,6 +441,7 @@ impl ModelManager {
             .map_err(|e| AumateError::Other(format!("Failed to create runtime: {}", e)))?;
 
         let repo_id = model_info.repo_id.clone();
+        let quantized = model_info.quantized;
         let downloads = self.downloads.clone();
         let model_id_owned = model_id.to_string();
 
@@ -424,7 +493,11 @@ impl ModelManager {
                 })?;
 
                 // Copy to our model directory
-                let dest_path = output_dir.join(filename);
+                let dest_path = if quantized {
+                    output_dir.join(quantized_local_name(filename))
+                } else {
+                    output_dir.join(filename)
+                };
                 std::fs::copy(&cached_path, &dest_path).map_err(|e| {
                     AumateError::Other(format!("Failed to copy {}: {}", filename, e))
                 })?;
@@ -617,6 +690,11 @@ fn format_size(bytes: u64) -> String {
     }
 }
 
+/// Whether a Whisper model id refers to a quantized model
+fn is_quantized_whisper(model_id: &str) -> bool {
+    WHISPER_QUANTIZED_MODELS.iter().any(|(id, ..)| *id == model_id)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -631,6 +709,7 @@ mod tests {
             size_bytes: 142_000_000,
             repo_id: "test/model".to_string(),
             files: vec![],
+            quantized: false,
             is_downloaded: false,
             local_path: None,
         };
@@ -659,4 +738,14 @@ mod tests {
         assert_eq!(ModelType::TrOCR.subdir(), "trocr");
         assert_eq!(ModelType::Vad.subdir(), "vad");
     }
+
+    #[test]
+    fn test_quantized_whisper_files() {
+        assert!(is_quantized_whisper("whisper-tiny-q8"));
+        assert!(!is_quantized_whisper("whisper-tiny"));
+
+        let local: Vec<_> =
+            whisper_quantized_files("tiny-en").iter().map(|f| quantized_local_name(f)).collect();
+        assert_eq!(local, WHISPER_QUANTIZED_FILES);
+    }
 }
@@ -11,21 +11,11 @@ use egui::TextureHandle;
 
 use crate::error::Result;
 use crate::gui::controller::{AsyncTask, ControllerContext, ControllerFeature, TabInfo};
-use crate::ml::{DeviceConfig, device_name, is_gpu_available};
+use crate::ml::{DevicePreference, device_name, is_gpu_available};
 use crate::ocr::{
     DownloadProgress, DownloadStatus, ModelInfo, ModelManager, ModelType, OcrEngine, OcrResult,
 };
 
-/// Available device options for OCR inference
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
-pub enum OcrDevice {
-    /// CPU (always available)
-    #[default]
-    Cpu,
-    /// GPU acceleration (Metal on macOS, CUDA on Linux/Windows)
-    Gpu,
-}
-
 /// OCR feature for optical character recognition
 pub struct OcrFeature {
     /// OCR engine (loaded asynchronously)
@@ -58,7 +48,7 @@ pub struct OcrFeature {
     /// Returns (result, engine) so we can recover the engine after recognition
     recognize_task: Option<AsyncTask<(std::result::Result<OcrResult, String>, OcrEngine)>>,
     /// Selected device for inference
-    selected_device: OcrDevice,
+    ocr_device: DevicePreference,
     /// Whether GPU is available on this system
     gpu_available: bool,
 }
@@ -80,7 +70,7 @@ impl OcrFeature {
             ocr_is_processing: false,
             load_model_task: None,
             recognize_task: None,
-            selected_device: OcrDevice::default(),
+            ocr_device: DevicePreference::default(),
             gpu_available: is_gpu_available(),
         }
     }
@@ -217,15 +207,13 @@ impl OcrFeature {
     fn start_model_load(&mut self, model_path: PathBuf) {
         let task = AsyncTask::new();
         let callback = task.callback();
-        let use_gpu = self.selected_device == OcrDevice::Gpu;
+        let device = self.ocr_device;
 
         self.ocr_status = "Loading model...".to_string();
 
         thread::spawn(move || {
-            log::info!("Loading OCR model async: {:?} (GPU: {})", model_path, use_gpu);
-            let device_config =
-                if use_gpu { DeviceConfig::with_gpu() } else { DeviceConfig::cpu_only() };
-            let result = match OcrEngine::with_device(device_config) {
+            log::info!("Loading OCR model async: {:?} (device: {})", model_path, device);
+            let result = match OcrEngine::with_device(device.into()) {
                 Ok(mut engine) => {
                     engine.load_model(&model_path).map(|_| engine).map_err(|e| e.to_string())
                 }
@@ -432,33 +420,28 @@ impl ControllerFeature for OcrFeature {
             ui.horizontal(|ui| {
                 ui.label("Device:");
 
-                // CPU option (always available)
-                let cpu_selected = self.selected_device == OcrDevice::Cpu;
-                if ui.selectable_label(cpu_selected, "CPU").clicked()
-                    && self.selected_device != OcrDevice::Cpu
-                {
-                    self.selected_device = OcrDevice::Cpu;
-                    // Unload engine when switching device
-                    self.ocr_engine = None;
-                    self.ocr_initialized = false;
-                    self.ocr_status = "Device changed - reload model".to_string();
-                }
-
-                // GPU option (only if available)
-                let gpu_text = if cfg!(target_os = "macos") { "Metal" } else { "CUDA" };
+                let mut device = self.ocr_device;
+                egui::ComboBox::from_id_salt("ocr_device_selector")
+                    .selected_text(device.to_string())
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut device, DevicePreference::Cpu, "CPU");
+                        // GPU options (only if available)
+                        ui.add_enabled_ui(self.gpu_available, |ui| {
+                            ui.selectable_value(&mut device, DevicePreference::Gpu, "GPU (auto)");
+                            if cfg!(target_os = "macos") {
+                                ui.selectable_value(&mut device, DevicePreference::Metal, "Metal");
+                            } else {
+                                let is_cuda = matches!(device, DevicePreference::Cuda { .. });
+                                if ui.selectable_label(is_cuda, "CUDA").clicked() && !is_cuda {
+                                    device = DevicePreference::Cuda { index: 0 };
+                                }
+                            }
+                        });
+                    });
 
-                ui.add_enabled_ui(self.gpu_available, |ui| {
-                    let gpu_selected = self.selected_device == OcrDevice::Gpu;
-                    if ui.selectable_label(gpu_selected, gpu_text).clicked()
-                        && self.selected_device != OcrDevice::Gpu
-                    {
-                        self.selected_device = OcrDevice::Gpu;
-                        // Unload engine when switching device
-                        self.ocr_engine = None;
-                        self.ocr_initialized = false;
-                        self.ocr_status = "Device changed - reload model".to_string();
-                    }
-                });
+                if let DevicePreference::Cuda { index } = &mut device {
+                    ui.add(egui::DragValue::new(index).prefix("#"));
+                }
 
                 if !self.gpu_available {
                     ui.label(
@@ -467,6 +450,14 @@ impl ControllerFeature for OcrFeature {
                             .color(egui::Color32::GRAY),
                     );
                 }
+
+                if device != self.ocr_device {
+                    self.ocr_device = device;
+                    // Unload engine when switching device
+                    self.ocr_engine = None;
+                    self.ocr_initialized = false;
+                    self.ocr_status = "Device changed - reload model".to_string();
+                }
             });
 
             ui.add_space(8.0);
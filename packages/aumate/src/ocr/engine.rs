@@ -3,6 +3,7 @@
 //! Provides optical character recognition using TrOCR model via Candle ML framework.
 
 use super::OcrModelVariant;
+use super::preprocess::{OcrPreprocess, adaptive_binarize};
 use crate::error::{AumateError, Result};
 use crate::ml::{Device, DeviceConfig, get_device};
 use candle_core::{DType, Tensor};
@@ -57,6 +58,39 @@ pub struct OcrResult {
     pub duration_ms: u64,
     /// Confidence score (0.0 to 1.0) if available
     pub confidence: Option<f32>,
+    /// Recognized text regions with their location in the source image
+    pub regions: Vec<OcrRegion>,
+}
+
+/// A recognized text region
+///
+/// Coordinates are in pixels relative to the top-left corner of the
+/// recognized image.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OcrRegion {
+    /// Recognized text within the region
+    pub text: String,
+    /// X coordinate of the region's top-left corner
+    pub x: u32,
+    /// Y coordinate of the region's top-left corner
+    pub y: u32,
+    /// Width of the region
+    pub width: u32,
+    /// Height of the region
+    pub height: u32,
+    /// Confidence score (0.0 to 1.0)
+    pub confidence: f32,
+}
+
+/// Candidate text region found by layout analysis
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TextBox {
+    /// Index of the text line this region belongs to
+    line: usize,
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
 }
 
 /// TrOCR-based OCR engine
@@ -75,6 +109,8 @@ pub struct OcrEngine {
     image_size: usize,
     /// Whether to use KV cache for decoding
     use_cache: bool,
+    /// Preprocessing applied to each text region before inference
+    preprocess: OcrPreprocess,
 }
 
 impl OcrEngine {
@@ -89,6 +125,7 @@ impl OcrEngine {
             variant: None,
             image_size: 384,  // Default TrOCR image size
             use_cache: false, // Default to no cache (TrOCR base models don't use it)
+            preprocess: OcrPreprocess::default(),
         }
     }
 
@@ -103,6 +140,7 @@ impl OcrEngine {
             variant: None,
             image_size: 384,
             use_cache: false,
+            preprocess: OcrPreprocess::default(),
         })
     }
 
@@ -274,12 +312,28 @@ impl OcrEngine {
         self.variant
     }
 
+    /// Get the preprocessing applied before recognition
+    pub fn preprocess(&self) -> OcrPreprocess {
+        self.preprocess
+    }
+
+    /// Set the preprocessing applied to each text region before recognition
+    pub fn set_preprocess(&mut self, preprocess: OcrPreprocess) {
+        self.preprocess = preprocess;
+    }
+
     /// Get device being used
     pub fn device(&self) -> &Device {
         &self.device
     }
 
     /// Recognize text from an image (supports multi-line text)
+    ///
+    /// The image is first split into candidate text regions (see
+    /// [`OcrResult::regions`]), each of which is recognized separately.
+    /// Layout analysis is heuristic (projection profiles over a binarized
+    /// image), so rotated text, tables or overlapping elements may be merged
+    /// into a single region or split unexpectedly.
     pub fn recognize(&mut self, image: &image::DynamicImage) -> Result<OcrResult> {
         let tokenizer = self
             .tokenizer
@@ -288,29 +342,50 @@ impl OcrEngine {
 
         let start_time = Instant::now();
 
-        // Detect text lines in the image
-        let line_images = self.detect_text_lines(image);
-
-        let text = if line_images.len() > 1 {
-            // Multi-line: process each line separately and join with newlines
-            log::info!("Detected {} text lines, processing each separately", line_images.len());
-            let mut line_results = Vec::new();
-            for (i, line_image) in line_images.iter().enumerate() {
-                let image_tensor = self.preprocess_image(line_image)?;
-                let line_text = self.decode_image(&image_tensor, &tokenizer)?;
-                if !line_text.is_empty() {
-                    log::info!("Line {}: \"{}\"", i + 1, line_text);
-                    line_results.push(line_text);
-                }
+        // Detect candidate text regions in the image
+        let boxes = self.detect_text_regions(image);
+        if boxes.len() > 1 {
+            log::info!("Detected {} text regions, processing each separately", boxes.len());
+        }
+
+        let mut regions = Vec::with_capacity(boxes.len());
+        let mut text = String::new();
+        let mut last_line = None;
+        for (i, text_box) in boxes.iter().enumerate() {
+            let crop = image.crop_imm(text_box.x, text_box.y, text_box.width, text_box.height);
+            let crop = self.preprocess.apply(&crop);
+            let image_tensor = self.preprocess_image(&crop)?;
+            let (region_text, confidence) = self.decode_image(&image_tensor, &tokenizer)?;
+            if region_text.is_empty() {
+                continue;
             }
-            line_results.join("\n")
-        } else {
-            // Single line or no clear line detection: process whole image
-            let image_tensor = self.preprocess_image(image)?;
-            self.decode_image(&image_tensor, &tokenizer)?
-        };
+            log::info!("Region {}: \"{}\"", i + 1, region_text);
+
+            // Regions on the same line are separated by spaces, lines by newlines
+            match last_line {
+                Some(line) if line == text_box.line => text.push(' '),
+                Some(_) => text.push('\n'),
+                None => {}
+            }
+            text.push_str(&region_text);
+            last_line = Some(text_box.line);
+
+            regions.push(OcrRegion {
+                text: region_text,
+                x: text_box.x,
+                y: text_box.y,
+                width: text_box.width,
+                height: text_box.height,
+                confidence,
+            });
+        }
 
         let duration_ms = start_time.elapsed().as_millis() as u64;
+        let confidence = if regions.is_empty() {
+            None
+        } else {
+            Some(regions.iter().map(|r| r.confidence).sum::<f32>() / regions.len() as f32)
+        };
 
         log::info!(
             "OCR completed in {}ms: \"{}\"",
@@ -318,24 +393,50 @@ impl OcrEngine {
             if text.len() > 50 { format!("{}...", &text[..50]) } else { text.clone() }
         );
 
-        Ok(OcrResult { text, duration_ms, confidence: None })
+        Ok(OcrResult { text, duration_ms, confidence, regions })
     }
 
-    /// Detect text lines in an image using horizontal projection analysis
+    /// Recognize text from multiple images in one call
     ///
-    /// Returns a vector of cropped line images. If only one line is detected
-    /// or detection fails, returns the original image.
-    fn detect_text_lines(&self, image: &image::DynamicImage) -> Vec<image::DynamicImage> {
+    /// The loaded model is reused for every image, so batching crops avoids
+    /// paying the model load cost per image. Results are returned in the same
+    /// order as the input images.
+    pub fn transcribe_batch(&mut self, images: &[image::DynamicImage]) -> Result<Vec<OcrResult>> {
+        if !self.is_loaded() {
+            return Err(AumateError::Other("No model loaded".to_string()));
+        }
+
+        let start_time = Instant::now();
+        let results =
+            images.iter().map(|image| self.recognize(image)).collect::<Result<Vec<_>>>()?;
+
+        log::info!(
+            "Batch OCR of {} images completed in {}ms",
+            images.len(),
+            start_time.elapsed().as_millis()
+        );
+        Ok(results)
+    }
+
+    /// Detect candidate text regions using projection analysis
+    ///
+    /// Rows are first grouped into text lines (horizontal projection), then
+    /// each line is split into blocks separated by wide horizontal gaps
+    /// (vertical projection) and trimmed to its content. Regions are returned
+    /// in reading order. If no text is found, the whole image is returned as a
+    /// single region.
+    fn detect_text_regions(&self, image: &image::DynamicImage) -> Vec<TextBox> {
         let gray = image.to_luma8();
         let (width, height) = gray.dimensions();
+        let whole_image = vec![TextBox { line: 0, x: 0, y: 0, width, height }];
 
         if height < 20 || width < 20 {
-            return vec![image.clone()];
+            return whole_image;
         }
 
         // Apply adaptive binarization to handle complex backgrounds
         // This helps detect text lines regardless of background color
-        let binary = self.adaptive_binarize(&gray);
+        let binary = adaptive_binarize(&gray);
 
         // Calculate horizontal projection (count of "text" pixels per row)
         let mut projection: Vec<u32> = Vec::with_capacity(height as usize);
@@ -353,6 +454,9 @@ impl OcrEngine {
         // Find threshold for detecting gaps between lines
         // A row is considered a "gap" if it has very few text pixels
         let max_projection = *projection.iter().max().unwrap_or(&1);
+        if max_projection == 0 {
+            return whole_image;
+        }
         let gap_threshold = max_projection / 10; // Rows with <10% of max are gaps
 
         // Find line boundaries (runs of non-gap rows)
@@ -404,95 +508,81 @@ impl OcrEngine {
             }
         }
 
-        // If we detected less than 2 lines, return original image
-        if lines.len() < 2 {
-            return vec![image.clone()];
-        }
-
-        // Crop each detected line with some vertical padding
+        // Split each line into horizontal blocks and pad them
         let padding = (height / 40).max(2);
-        let mut line_images = Vec::new();
+        let mut boxes = Vec::new();
 
-        for (start_y, end_y) in lines {
+        for (line, (start_y, end_y)) in lines.into_iter().enumerate() {
+            let line_height = end_y - start_y;
+            // Gaps wider than this separate independent blocks on the same line
+            let min_block_gap = (line_height * 3 / 2).max(min_gap_height);
             let padded_start = start_y.saturating_sub(padding);
             let padded_end = (end_y + padding).min(height);
-            let crop_height = padded_end - padded_start;
 
-            if crop_height > 0 {
-                let cropped = image.crop_imm(0, padded_start, width, crop_height);
-                line_images.push(cropped);
+            let mut block_start: Option<u32> = None;
+            let mut last_text_x = 0u32;
+            for x in 0..width {
+                let has_text = (start_y..end_y).any(|y| binary.get_pixel(x, y).0[0] == 0);
+                if !has_text {
+                    continue;
+                }
+                match block_start {
+                    Some(start) if x - last_text_x > min_block_gap => {
+                        boxes.push(Self::padded_box(
+                            line,
+                            start,
+                            last_text_x + 1,
+                            padded_start,
+                            padded_end,
+                            padding,
+                            width,
+                        ));
+                        block_start = Some(x);
+                    }
+                    Some(_) => {}
+                    None => block_start = Some(x),
+                }
+                last_text_x = x;
+            }
+            if let Some(start) = block_start {
+                boxes.push(Self::padded_box(
+                    line,
+                    start,
+                    last_text_x + 1,
+                    padded_start,
+                    padded_end,
+                    padding,
+                    width,
+                ));
             }
         }
 
-        if line_images.is_empty() {
-            vec![image.clone()]
+        if boxes.is_empty() {
+            whole_image
         } else {
             log::info!(
-                "Text line detection: found {} lines in {}x{} image",
-                line_images.len(),
+                "Text region detection: found {} regions in {}x{} image",
+                boxes.len(),
                 width,
                 height
             );
-            line_images
+            boxes
         }
     }
 
-    /// Apply adaptive binarization to handle various backgrounds
-    ///
-    /// Uses local mean thresholding to separate text from background,
-    /// works for both dark-on-light and light-on-dark text.
-    fn adaptive_binarize(&self, gray: &image::GrayImage) -> image::GrayImage {
-        let (width, height) = gray.dimensions();
-
-        // Window size for local thresholding (adapt to image size)
-        let window_size = ((width.min(height) / 15) as usize).clamp(15, 51);
-        let half_window = window_size / 2;
-
-        // Create integral image for fast mean calculation
-        let mut integral: Vec<Vec<u64>> =
-            vec![vec![0; (width + 1) as usize]; (height + 1) as usize];
-        for y in 0..height {
-            for x in 0..width {
-                let pixel = gray.get_pixel(x, y).0[0] as u64;
-                integral[(y + 1) as usize][(x + 1) as usize] = pixel
-                    + integral[y as usize][(x + 1) as usize]
-                    + integral[(y + 1) as usize][x as usize]
-                    - integral[y as usize][x as usize];
-            }
-        }
-
-        let mut binary = image::GrayImage::new(width, height);
-
-        for y in 0..height {
-            for x in 0..width {
-                // Calculate local mean using integral image
-                let x1 = (x as i32 - half_window as i32).max(0) as usize;
-                let y1 = (y as i32 - half_window as i32).max(0) as usize;
-                let x2 = ((x as usize + half_window + 1).min(width as usize)).min(width as usize);
-                let y2 = ((y as usize + half_window + 1).min(height as usize)).min(height as usize);
-
-                let area = ((x2 - x1) * (y2 - y1)) as u64;
-                // Reorder to avoid underflow: (a + d) - b - c instead of a - b - c + d
-                let sum = (integral[y2][x2] + integral[y1][x1])
-                    .saturating_sub(integral[y1][x2])
-                    .saturating_sub(integral[y2][x1]);
-                let local_mean = (sum / area.max(1)) as u8;
-
-                let pixel = gray.get_pixel(x, y).0[0];
-
-                // Adaptive threshold with bias towards detecting text
-                // Text is darker than local mean (dark text) or lighter (light text)
-                let threshold_bias = 15u8;
-                let lower_bound = local_mean.saturating_sub(threshold_bias);
-                let upper_bound = local_mean.saturating_add(threshold_bias);
-                // Text pixels deviate significantly from local mean
-                let is_text = pixel < lower_bound || pixel > upper_bound;
-
-                binary.put_pixel(x, y, image::Luma([if is_text { 0 } else { 255 }]));
-            }
-        }
-
-        binary
+    /// Build a text box from a horizontal extent, padding it within image bounds
+    fn padded_box(
+        line: usize,
+        start_x: u32,
+        end_x: u32,
+        start_y: u32,
+        end_y: u32,
+        padding: u32,
+        image_width: u32,
+    ) -> TextBox {
+        let x = start_x.saturating_sub(padding);
+        let end_x = (end_x + padding).min(image_width);
+        TextBox { line, x, y: start_y, width: end_x - x, height: end_y - start_y }
     }
 
     /// Recognize text from image bytes (PNG, JPEG, etc.)
@@ -569,7 +659,10 @@ impl OcrEngine {
     }
 
     /// Decode image to text using autoregressive generation
-    fn decode_image(&mut self, image: &Tensor, tokenizer: &Tokenizer) -> Result<String> {
+    ///
+    /// Returns the decoded text and its confidence, computed as the mean
+    /// probability of the greedily selected tokens.
+    fn decode_image(&mut self, image: &Tensor, tokenizer: &Tokenizer) -> Result<(String, f32)> {
         let model =
             self.model.as_mut().ok_or_else(|| AumateError::Other("No model loaded".to_string()))?;
 
@@ -586,6 +679,7 @@ impl OcrEngine {
 
         // Start with decoder start token
         let mut token_ids: Vec<u32> = vec![decoder_start_token_id];
+        let mut token_probs: Vec<f32> = Vec::new();
         let max_tokens = 512;
 
         // Autoregressive decoding loop (following official Candle example pattern)
@@ -623,6 +717,16 @@ impl OcrEngine {
                 .to_scalar::<u32>()
                 .map_err(|e| AumateError::Ml(format!("Failed to get scalar: {}", e)))?;
 
+            let next_prob = candle_nn::ops::softmax_last_dim(&last_logits)
+                .map_err(|e| AumateError::Ml(format!("Failed to compute softmax: {}", e)))?
+                .squeeze(0)
+                .map_err(|e| AumateError::Ml(format!("Failed to squeeze probs: {}", e)))?
+                .get(next_token as usize)
+                .map_err(|e| AumateError::Ml(format!("Failed to index probs: {}", e)))?
+                .to_scalar::<f32>()
+                .map_err(|e| AumateError::Ml(format!("Failed to get scalar: {}", e)))?;
+            token_probs.push(next_prob);
+
             // Check for end of sequence
             if next_token == eos_token_id {
                 break;
@@ -642,7 +746,13 @@ impl OcrEngine {
             .decode(&text_tokens, true)
             .map_err(|e| AumateError::Other(format!("Failed to decode tokens: {}", e)))?;
 
-        Ok(text.trim().to_string())
+        let confidence = if token_probs.is_empty() {
+            0.0
+        } else {
+            token_probs.iter().sum::<f32>() / token_probs.len() as f32
+        };
+
+        Ok((text.trim().to_string(), confidence))
     }
 }
 
@@ -717,7 +827,14 @@ mod tests {
     }
 
     #[test]
-    fn test_detect_text_lines_single_line() {
+    fn test_transcribe_batch_without_model() {
+        let mut engine = OcrEngine::new();
+        let images = vec![image::DynamicImage::new_rgb8(100, 100)];
+        assert!(engine.transcribe_batch(&images).is_err());
+    }
+
+    #[test]
+    fn test_detect_text_regions_single_line() {
         use image::{DynamicImage, Rgb, RgbImage};
 
         let engine = OcrEngine::new();
@@ -736,14 +853,17 @@ mod tests {
         }
 
         let dynamic_img = DynamicImage::ImageRgb8(img);
-        let lines = engine.detect_text_lines(&dynamic_img);
+        let regions = engine.detect_text_regions(&dynamic_img);
 
-        // Should return original image for single line
-        assert_eq!(lines.len(), 1);
+        // Should detect a single region around the line
+        assert_eq!(regions.len(), 1);
+        let region = regions[0];
+        assert!(region.x <= 20 && region.x + region.width >= 180);
+        assert!(region.y <= 20 && region.y + region.height >= 30);
     }
 
     #[test]
-    fn test_detect_text_lines_multi_line() {
+    fn test_detect_text_regions_multi_line() {
         use image::{DynamicImage, Rgb, RgbImage};
 
         let engine = OcrEngine::new();
@@ -768,14 +888,19 @@ mod tests {
         }
 
         let dynamic_img = DynamicImage::ImageRgb8(img);
-        let lines = engine.detect_text_lines(&dynamic_img);
-
-        // Should detect 2 lines
-        assert_eq!(lines.len(), 2);
+        let regions = engine.detect_text_regions(&dynamic_img);
+
+        // Should detect 2 lines, top to bottom
+        assert_eq!(regions.len(), 2);
+        assert_eq!(regions[0].line, 0);
+        assert_eq!(regions[1].line, 1);
+        assert!(regions[0].y <= 15 && regions[0].y + regions[0].height >= 25);
+        assert!(regions[1].y <= 60 && regions[1].y + regions[1].height >= 70);
+        assert!(regions[0].y + regions[0].height < regions[1].y);
     }
 
     #[test]
-    fn test_detect_text_lines_small_image() {
+    fn test_detect_text_regions_small_image() {
         use image::{DynamicImage, RgbImage};
 
         let engine = OcrEngine::new();
@@ -783,8 +908,33 @@ mod tests {
         // Very small image should return original
         let img = RgbImage::new(10, 10);
         let dynamic_img = DynamicImage::ImageRgb8(img);
-        let lines = engine.detect_text_lines(&dynamic_img);
+        let regions = engine.detect_text_regions(&dynamic_img);
+
+        assert_eq!(regions.len(), 1);
+        assert_eq!((regions[0].width, regions[0].height), (10, 10));
+    }
+
+    #[test]
+    fn test_detect_text_regions_same_line_blocks() {
+        use image::{DynamicImage, Rgb, RgbImage};
+
+        let engine = OcrEngine::new();
+
+        // Two blocks of "text" on the same row, separated by a wide gap
+        let mut img = RgbImage::from_pixel(300, 50, Rgb([255, 255, 255]));
+        for y in 20..30 {
+            for x in (20..100).chain(200..280) {
+                img.put_pixel(x, y, Rgb([0, 0, 0]));
+            }
+        }
+
+        let dynamic_img = DynamicImage::ImageRgb8(img);
+        let regions = engine.detect_text_regions(&dynamic_img);
 
-        assert_eq!(lines.len(), 1);
+        assert_eq!(regions.len(), 2);
+        assert_eq!(regions[0].line, regions[1].line);
+        assert!(regions[0].x <= 20 && regions[0].x + regions[0].width >= 100);
+        assert!(regions[0].x + regions[0].width < 200);
+        assert!(regions[1].x <= 200 && regions[1].x + regions[1].width >= 280);
     }
 }
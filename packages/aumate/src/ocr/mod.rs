@@ -3,15 +3,18 @@
 //! This module provides OCR functionality using TrOCR (Transformer-based OCR):
 //! - Image to text recognition
 //! - Support for handwritten and printed text
+//! - Configurable image preprocessing (upscale, grayscale, deskew, binarize)
 //! - Model management with download support
 
 #[cfg(feature = "gui")]
 mod controller;
 mod engine;
+mod preprocess;
 
 #[cfg(feature = "gui")]
 pub use controller::OcrFeature;
-pub use engine::{OcrEngine, OcrResult, TrOCRModel};
+pub use engine::{OcrEngine, OcrRegion, OcrResult, TrOCRModel};
+pub use preprocess::OcrPreprocess;
 
 // Re-export shared model types from ml module
 pub use crate::ml::{
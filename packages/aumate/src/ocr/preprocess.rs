@@ -0,0 +1,313 @@
+//! Image preprocessing applied before OCR inference
+//!
+//! TrOCR is trained on scanned text lines, so screen captures (small,
+//! anti-aliased, colored, sometimes slightly rotated) benefit from being
+//! cleaned up first. Preprocessing runs on each detected text region, after
+//! layout analysis, so region coordinates always refer to the original image.
+
+use image::{DynamicImage, GrayImage, ImageBuffer, Pixel};
+
+/// Regions shorter than this (in pixels) are upscaled when `upscale` is set
+const SMALL_TEXT_HEIGHT: u32 = 32;
+
+/// Largest skew (in degrees) that deskewing looks for
+const MAX_SKEW_DEGREES: f32 = 10.0;
+
+/// Angle step (in degrees) of the skew search
+const SKEW_STEP_DEGREES: f32 = 0.5;
+
+/// Skew below this (in degrees) is left alone
+const MIN_SKEW_DEGREES: f32 = 0.5;
+
+/// Preprocessing steps applied to each text region before recognition
+///
+/// Steps run in field order: upscale, grayscale, deskew, binarize.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OcrPreprocess {
+    /// Upscale regions shorter than 32px by 2x so small text keeps its detail
+    pub upscale: bool,
+    /// Drop color information
+    pub grayscale: bool,
+    /// Straighten text rotated by up to 10 degrees
+    pub deskew: bool,
+    /// Convert to black text on white with a local-mean threshold
+    pub binarize: bool,
+}
+
+impl Default for OcrPreprocess {
+    /// Mild preset for screen text: upscale small text and drop color
+    fn default() -> Self {
+        Self { upscale: true, grayscale: true, deskew: false, binarize: false }
+    }
+}
+
+impl OcrPreprocess {
+    /// No preprocessing: regions are passed to the model unchanged
+    pub fn none() -> Self {
+        Self { upscale: false, grayscale: false, deskew: false, binarize: false }
+    }
+
+    /// Every preprocessing step, for noisy or rotated sources
+    pub fn all() -> Self {
+        Self { upscale: true, grayscale: true, deskew: true, binarize: true }
+    }
+
+    /// Apply the enabled steps to an image
+    pub fn apply(&self, image: &DynamicImage) -> DynamicImage {
+        let mut image = image.clone();
+
+        if self.upscale && image.height() < SMALL_TEXT_HEIGHT {
+            image = image.resize_exact(
+                image.width() * 2,
+                image.height() * 2,
+                image::imageops::FilterType::CatmullRom,
+            );
+        }
+
+        if self.grayscale {
+            image = DynamicImage::ImageLuma8(image.to_luma8());
+        }
+
+        if self.deskew {
+            let angle = estimate_skew(&adaptive_binarize(&image.to_luma8()));
+            if angle.abs() >= MIN_SKEW_DEGREES {
+                log::debug!("Deskewing OCR region by {:.1} degrees", angle);
+                image = match image {
+                    DynamicImage::ImageLuma8(gray) => {
+                        DynamicImage::ImageLuma8(rotate(&gray, -angle))
+                    }
+                    other => DynamicImage::ImageRgb8(rotate(&other.to_rgb8(), -angle)),
+                };
+            }
+        }
+
+        if self.binarize {
+            image = DynamicImage::ImageLuma8(binarize_text(&image.to_luma8()));
+        }
+
+        image
+    }
+}
+
+/// Deviation from the local mean (in gray levels) that counts as text
+const THRESHOLD_BIAS: u8 = 15;
+
+/// Mean brightness around each pixel
+///
+/// The window adapts to the image size so thin strokes and large glyphs are
+/// both compared against their surrounding background.
+fn local_means(gray: &GrayImage) -> GrayImage {
+    let (width, height) = gray.dimensions();
+
+    // Window size for local thresholding (adapt to image size)
+    let window_size = ((width.min(height) / 15) as usize).clamp(15, 51);
+    let half_window = window_size / 2;
+
+    // Create integral image for fast mean calculation
+    let mut integral: Vec<Vec<u64>> = vec![vec![0; (width + 1) as usize]; (height + 1) as usize];
+    for y in 0..height {
+        for x in 0..width {
+            let pixel = gray.get_pixel(x, y).0[0] as u64;
+            integral[(y + 1) as usize][(x + 1) as usize] = pixel
+                + integral[y as usize][(x + 1) as usize]
+                + integral[(y + 1) as usize][x as usize]
+                - integral[y as usize][x as usize];
+        }
+    }
+
+    GrayImage::from_fn(width, height, |x, y| {
+        // Calculate local mean using integral image
+        let x1 = (x as i32 - half_window as i32).max(0) as usize;
+        let y1 = (y as i32 - half_window as i32).max(0) as usize;
+        let x2 = (x as usize + half_window + 1).min(width as usize);
+        let y2 = (y as usize + half_window + 1).min(height as usize);
+
+        let area = ((x2 - x1) * (y2 - y1)) as u64;
+        // Reorder to avoid underflow: (a + d) - b - c instead of a - b - c + d
+        let sum = (integral[y2][x2] + integral[y1][x1])
+            .saturating_sub(integral[y1][x2])
+            .saturating_sub(integral[y2][x1]);
+        image::Luma([(sum / area.max(1)) as u8])
+    })
+}
+
+/// Apply adaptive binarization to handle various backgrounds
+///
+/// Uses local mean thresholding to separate text from background,
+/// works for both dark-on-light and light-on-dark text. Every pixel that
+/// deviates from its surroundings is marked, which suits layout analysis but
+/// also marks the background between close strokes.
+pub(super) fn adaptive_binarize(gray: &GrayImage) -> GrayImage {
+    let means = local_means(gray);
+    GrayImage::from_fn(gray.width(), gray.height(), |x, y| {
+        let pixel = gray.get_pixel(x, y).0[0];
+        let local_mean = means.get_pixel(x, y).0[0];
+
+        // Text is darker than local mean (dark text) or lighter (light text)
+        let is_text = pixel < local_mean.saturating_sub(THRESHOLD_BIAS)
+            || pixel > local_mean.saturating_add(THRESHOLD_BIAS);
+        image::Luma([if is_text { 0 } else { 255 }])
+    })
+}
+
+/// Binarize to black text on white for recognition
+///
+/// Unlike [`adaptive_binarize`], only deviations in the text direction count.
+/// The background is assumed to be the dominant tone: on a light image text
+/// is darker than its surroundings, on a dark image lighter.
+fn binarize_text(gray: &GrayImage) -> GrayImage {
+    let total: u64 = gray.pixels().map(|p| p.0[0] as u64).sum();
+    let light_background = total >= 128 * (gray.width() as u64 * gray.height() as u64);
+
+    let means = local_means(gray);
+    GrayImage::from_fn(gray.width(), gray.height(), |x, y| {
+        let pixel = gray.get_pixel(x, y).0[0];
+        let local_mean = means.get_pixel(x, y).0[0];
+
+        let is_text = if light_background {
+            pixel < local_mean.saturating_sub(THRESHOLD_BIAS)
+        } else {
+            pixel > local_mean.saturating_add(THRESHOLD_BIAS)
+        };
+        image::Luma([if is_text { 0 } else { 255 }])
+    })
+}
+
+/// Estimate text rotation in degrees (positive = clockwise on screen)
+///
+/// Runs a Hough transform over the text pixels of a binarized image,
+/// restricted to near-horizontal lines. At the true text angle, pixels
+/// concentrate on few lines (the text baselines and x-heights), so the angle
+/// whose accumulator has the highest sum of squared votes wins.
+fn estimate_skew(binary: &GrayImage) -> f32 {
+    let (width, height) = binary.dimensions();
+    let points: Vec<(f32, f32)> = binary
+        .enumerate_pixels()
+        .filter(|(_, _, p)| p.0[0] == 0)
+        .map(|(x, y, _)| (x as f32, y as f32))
+        .collect();
+    if points.is_empty() {
+        return 0.0;
+    }
+
+    // rho ranges over [-width, width + height] for |angle| <= 45 degrees
+    let offset = width as f32;
+    let bins = (2 * width + height + 1) as usize;
+    let steps = (MAX_SKEW_DEGREES / SKEW_STEP_DEGREES).round() as i32;
+
+    let mut best = (0.0f32, 0u64);
+    for step in -steps..=steps {
+        let angle = step as f32 * SKEW_STEP_DEGREES;
+        let (sin, cos) = angle.to_radians().sin_cos();
+
+        let mut accumulator = vec![0u64; bins];
+        for &(x, y) in &points {
+            let rho = y * cos - x * sin + offset;
+            accumulator[(rho.round() as usize).min(bins - 1)] += 1;
+        }
+
+        let score = accumulator.iter().map(|v| v * v).sum();
+        // Prefer the smallest rotation on ties
+        if score > best.1 || (score == best.1 && angle.abs() < best.0.abs()) {
+            best = (angle, score);
+        }
+    }
+
+    best.0
+}
+
+/// Rotate an image about its center (positive = clockwise on screen)
+///
+/// Keeps the original size; areas rotated in from outside repeat the nearest
+/// edge pixel so no artificial borders appear.
+fn rotate<P: Pixel>(
+    image: &ImageBuffer<P, Vec<P::Subpixel>>,
+    degrees: f32,
+) -> ImageBuffer<P, Vec<P::Subpixel>> {
+    let (width, height) = image.dimensions();
+    let (sin, cos) = degrees.to_radians().sin_cos();
+    let cx = (width as f32 - 1.0) / 2.0;
+    let cy = (height as f32 - 1.0) / 2.0;
+
+    ImageBuffer::from_fn(width, height, |x, y| {
+        // Map each output pixel back into the source image
+        let dx = x as f32 - cx;
+        let dy = y as f32 - cy;
+        let sx = (dx * cos + dy * sin + cx).round().clamp(0.0, width as f32 - 1.0);
+        let sy = (-dx * sin + dy * cos + cy).round().clamp(0.0, height as f32 - 1.0);
+        *image.get_pixel(sx as u32, sy as u32)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{GenericImageView, Luma};
+
+    /// White image with three dark horizontal bars, like lines of text
+    fn text_lines(width: u32, height: u32) -> GrayImage {
+        GrayImage::from_fn(width, height, |x, y| {
+            let in_line = (y % 20) < 6 && y > 10 && y + 10 < height;
+            let in_margin = x < 10 || x + 10 >= width;
+            if in_line && !in_margin { Luma([0]) } else { Luma([255]) }
+        })
+    }
+
+    #[test]
+    fn test_none_leaves_image_unchanged() {
+        let image =
+            DynamicImage::ImageRgb8(image::RgbImage::from_pixel(40, 20, image::Rgb([9, 99, 199])));
+        assert_eq!(OcrPreprocess::none().apply(&image), image);
+    }
+
+    #[test]
+    fn test_default_upscales_small_text_only() {
+        let small = DynamicImage::ImageRgb8(image::RgbImage::new(100, 20));
+        let processed = OcrPreprocess::default().apply(&small);
+        assert_eq!(processed.dimensions(), (200, 40));
+        assert!(matches!(processed, DynamicImage::ImageLuma8(_)));
+
+        let large = DynamicImage::ImageRgb8(image::RgbImage::new(100, 40));
+        assert_eq!(OcrPreprocess::default().apply(&large).dimensions(), (100, 40));
+    }
+
+    #[test]
+    fn test_binarize_outputs_black_and_white() {
+        let gray = GrayImage::from_fn(60, 60, |x, _| Luma([if x % 10 < 3 { 40 } else { 200 }]));
+        let image = DynamicImage::ImageLuma8(gray);
+        let preprocess = OcrPreprocess { binarize: true, ..OcrPreprocess::none() };
+
+        let binary = preprocess.apply(&image).to_luma8();
+        assert!(binary.pixels().all(|p| p.0[0] == 0 || p.0[0] == 255));
+        assert_eq!(binary.get_pixel(1, 30).0[0], 0);
+        assert_eq!(binary.get_pixel(6, 30).0[0], 255);
+    }
+
+    #[test]
+    fn test_binarize_light_text_on_dark() {
+        let gray = GrayImage::from_fn(60, 60, |x, _| Luma([if x % 10 < 3 { 220 } else { 30 }]));
+        let binary = binarize_text(&gray);
+        assert_eq!(binary.get_pixel(1, 30).0[0], 0);
+        assert_eq!(binary.get_pixel(6, 30).0[0], 255);
+    }
+
+    #[test]
+    fn test_estimate_skew() {
+        let straight = text_lines(200, 100);
+        assert_eq!(estimate_skew(&straight), 0.0);
+
+        // Pixel positions only approximate the rotation, allow one step of error
+        let skewed = rotate(&straight, 4.0);
+        let angle = estimate_skew(&skewed);
+        assert!((angle - 4.0).abs() <= SKEW_STEP_DEGREES, "estimated {}", angle);
+
+        let skewed = rotate(&straight, -6.0);
+        let angle = estimate_skew(&skewed);
+        assert!((angle + 6.0).abs() <= SKEW_STEP_DEGREES, "estimated {}", angle);
+    }
+
+    #[test]
+    fn test_estimate_skew_blank_image() {
+        assert_eq!(estimate_skew(&GrayImage::from_pixel(50, 50, Luma([255]))), 0.0);
+    }
+}
@@ -0,0 +1,188 @@
+//! System permission checks
+//!
+//! macOS gates input simulation and event hooks behind Accessibility access,
+//! and screen capture behind Screen Recording access. Screen capture, hotkeys
+//! and macro recording fail with `AumateError::PermissionDenied` without
+//! them, but simulated input is silently dropped. Check up front and ask the
+//! user to grant access before automating.
+//!
+//! Other platforms have no equivalent prompts, so every permission is
+//! reported as granted there.
+
+#[cfg(any(all(feature = "input", feature = "eventhooks"), feature = "screen"))]
+use crate::error::{AumateError, Result};
+
+/// Permission status for the current process
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PermissionStatus {
+    /// Accessibility access, needed for input simulation and event hooks
+    pub accessibility: bool,
+    /// Screen Recording access, needed for screen capture
+    pub screen_recording: bool,
+    /// Microphone access, needed for speech-to-text
+    pub microphone: bool,
+}
+
+/// Check which permissions the current process has
+pub fn check_permissions() -> PermissionStatus {
+    PermissionStatus {
+        accessibility: platform::accessibility_granted(),
+        screen_recording: platform::screen_recording_granted(),
+        microphone: platform::microphone_granted(),
+    }
+}
+
+/// Ask the OS to prompt for Accessibility access
+///
+/// On macOS this shows the system dialog pointing at System Settings, unless
+/// access was already granted. The user has to toggle the switch manually,
+/// so the result usually stays `false` until the process is restarted.
+///
+/// # Returns
+/// Whether access is granted right now
+pub fn request_accessibility_permission() -> bool {
+    platform::request_accessibility()
+}
+
+/// Ask the OS to prompt for Screen Recording access
+///
+/// On macOS the prompt is shown at most once per app; after that the user has
+/// to enable access in System Settings. Granting takes effect after a restart.
+///
+/// # Returns
+/// Whether access is granted right now
+pub fn request_screen_recording_permission() -> bool {
+    platform::request_screen_recording()
+}
+
+/// Fail with [`AumateError::PermissionDenied`] unless Accessibility access is granted
+#[cfg(all(feature = "input", feature = "eventhooks"))]
+pub(crate) fn require_accessibility(purpose: &str) -> Result<()> {
+    if platform::accessibility_granted() {
+        Ok(())
+    } else {
+        Err(AumateError::PermissionDenied(format!("Accessibility access is required {}", purpose)))
+    }
+}
+
+/// Fail with [`AumateError::PermissionDenied`] unless Screen Recording access is granted
+#[cfg(feature = "screen")]
+pub(crate) fn require_screen_recording(purpose: &str) -> Result<()> {
+    if platform::screen_recording_granted() {
+        Ok(())
+    } else {
+        Err(AumateError::PermissionDenied(format!(
+            "Screen Recording access is required {}",
+            purpose
+        )))
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use objc::runtime::{Object, YES};
+    use objc::{class, msg_send};
+    use std::ffi::{CStr, c_void};
+
+    /// AVAuthorizationStatusAuthorized
+    const AV_AUTHORIZED: isize = 3;
+
+    #[link(name = "ApplicationServices", kind = "framework")]
+    unsafe extern "C" {
+        fn AXIsProcessTrusted() -> bool;
+        fn AXIsProcessTrustedWithOptions(options: *const c_void) -> bool;
+    }
+
+    #[link(name = "CoreGraphics", kind = "framework")]
+    unsafe extern "C" {
+        fn CGPreflightScreenCaptureAccess() -> bool;
+        fn CGRequestScreenCaptureAccess() -> bool;
+    }
+
+    #[link(name = "AVFoundation", kind = "framework")]
+    unsafe extern "C" {}
+
+    #[link(name = "Foundation", kind = "framework")]
+    unsafe extern "C" {}
+
+    fn ns_string(s: &CStr) -> *mut Object {
+        unsafe { msg_send![class!(NSString), stringWithUTF8String: s.as_ptr()] }
+    }
+
+    pub fn accessibility_granted() -> bool {
+        unsafe { AXIsProcessTrusted() }
+    }
+
+    pub fn request_accessibility() -> bool {
+        unsafe {
+            // NSDictionary is toll-free bridged to CFDictionaryRef
+            let key = ns_string(c"AXTrustedCheckOptionPrompt");
+            let yes: *mut Object = msg_send![class!(NSNumber), numberWithBool: YES];
+            let options: *mut Object =
+                msg_send![class!(NSDictionary), dictionaryWithObject: yes forKey: key];
+            AXIsProcessTrustedWithOptions(options as *const c_void)
+        }
+    }
+
+    pub fn screen_recording_granted() -> bool {
+        unsafe { CGPreflightScreenCaptureAccess() }
+    }
+
+    pub fn request_screen_recording() -> bool {
+        unsafe { CGRequestScreenCaptureAccess() }
+    }
+
+    pub fn microphone_granted() -> bool {
+        unsafe {
+            // Value of AVMediaTypeAudio
+            let media_type = ns_string(c"soun");
+            let status: isize =
+                msg_send![class!(AVCaptureDevice), authorizationStatusForMediaType: media_type];
+            status == AV_AUTHORIZED
+        }
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+mod platform {
+    pub fn accessibility_granted() -> bool {
+        true
+    }
+
+    pub fn request_accessibility() -> bool {
+        true
+    }
+
+    pub fn screen_recording_granted() -> bool {
+        true
+    }
+
+    pub fn request_screen_recording() -> bool {
+        true
+    }
+
+    pub fn microphone_granted() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(target_os = "macos"))]
+    #[test]
+    fn test_permissions_granted_off_macos() {
+        let status = check_permissions();
+        assert_eq!(
+            status,
+            PermissionStatus { accessibility: true, screen_recording: true, microphone: true }
+        );
+        assert!(request_accessibility_permission());
+        assert!(request_screen_recording_permission());
+        #[cfg(all(feature = "input", feature = "eventhooks"))]
+        assert!(require_accessibility("to test").is_ok());
+        #[cfg(feature = "screen")]
+        assert!(require_screen_recording("to test").is_ok());
+    }
+}
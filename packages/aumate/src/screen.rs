@@ -3,8 +3,13 @@
 //! Provides screen capture and pixel operations for desktop automation.
 
 use crate::error::{AumateError, Result};
+use crate::permissions;
 use image::{ImageBuffer, ImageEncoder, RgbaImage};
-use xcap::Monitor;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use xcap::{Monitor, Window};
 
 /// Screen capture result containing image data
 #[derive(Debug, Clone)]
@@ -15,10 +20,20 @@ pub struct ScreenCapture {
 }
 
 /// Screen size information
+///
+/// `width`/`height` are logical dimensions, the coordinate space expected by
+/// mouse movement. `physical_width`/`physical_height` are device pixels, the
+/// space of screen captures and pixel lookups. On HiDPI displays the two differ
+/// by the scale factor reported by [`get_screen_scale_factor`].
+///
+/// The OS reports logical sizes on macOS and Linux and physical ones on
+/// Windows; the other pair is derived with the scale factor.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ScreenSize {
     pub width: u32,
     pub height: u32,
+    pub physical_width: u32,
+    pub physical_height: u32,
 }
 
 /// Pixel color information
@@ -30,6 +45,14 @@ pub struct PixelColor {
     pub a: u8,
 }
 
+/// Color in HSV space: hue in degrees `[0, 360)`, saturation and value in `[0, 1]`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsv {
+    pub h: f64,
+    pub s: f64,
+    pub v: f64,
+}
+
 impl PixelColor {
     /// Create a new PixelColor
     pub fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
@@ -45,6 +68,77 @@ impl PixelColor {
     pub fn to_hex_with_alpha(&self) -> String {
         format!("#{:02X}{:02X}{:02X}{:02X}", self.r, self.g, self.b, self.a)
     }
+
+    /// Parse a hex string such as "#FF0000", "ff0000" or "#FF000080"
+    ///
+    /// Alpha defaults to 255 when only RGB is given.
+    pub fn from_hex(hex: &str) -> Result<Self> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        let invalid = || AumateError::Screen(format!("Invalid hex color: {}", hex));
+        if !matches!(digits.len(), 6 | 8) || !digits.is_ascii() {
+            return Err(invalid());
+        }
+        let channel = |i: usize| u8::from_str_radix(&digits[i..i + 2], 16).map_err(|_| invalid());
+        let a = if digits.len() == 8 { channel(6)? } else { 255 };
+        Ok(Self::new(channel(0)?, channel(2)?, channel(4)?, a))
+    }
+
+    /// Check whether every RGB channel is within `tolerance` of `other`
+    ///
+    /// Alpha is ignored since screen pixels are always opaque.
+    pub fn matches(&self, other: &PixelColor, tolerance: u8) -> bool {
+        self.r.abs_diff(other.r) <= tolerance
+            && self.g.abs_diff(other.g) <= tolerance
+            && self.b.abs_diff(other.b) <= tolerance
+    }
+
+    /// Convert to HSV, ignoring alpha
+    pub fn to_hsv(&self) -> Hsv {
+        let [r, g, b] = [self.r, self.g, self.b].map(|c| c as f64 / 255.0);
+        let max = r.max(g).max(b);
+        let delta = max - r.min(g).min(b);
+
+        let h = if delta == 0.0 {
+            0.0
+        } else if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+        let s = if max == 0.0 { 0.0 } else { delta / max };
+
+        Hsv { h, s, v: max }
+    }
+
+    /// Convert to CIE L*a*b* (D65 white point), ignoring alpha
+    pub fn to_lab(&self) -> [f64; 3] {
+        let [r, g, b] = [self.r, self.g, self.b].map(|c| {
+            let c = c as f64 / 255.0;
+            if c <= 0.04045 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+        });
+
+        // Linear sRGB to XYZ, normalized by the D65 reference white
+        let x = (0.4124 * r + 0.3576 * g + 0.1805 * b) / 0.95047;
+        let y = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+        let z = (0.0193 * r + 0.1192 * g + 0.9505 * b) / 1.08883;
+
+        let f = |t: f64| {
+            if t > 216.0 / 24389.0 { t.cbrt() } else { (24389.0 / 27.0 * t + 16.0) / 116.0 }
+        };
+        let (fx, fy, fz) = (f(x), f(y), f(z));
+        [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+    }
+
+    /// Perceptual distance to another color (CIE76 ΔE)
+    ///
+    /// Around 2.3 is a just-noticeable difference; pure black to pure white is 100.
+    pub fn distance(&self, other: &PixelColor) -> f64 {
+        let [l1, a1, b1] = self.to_lab();
+        let [l2, a2, b2] = other.to_lab();
+        ((l1 - l2).powi(2) + (a1 - a2).powi(2) + (b1 - b2).powi(2)).sqrt()
+    }
 }
 
 /// Capture the entire screen
@@ -71,16 +165,8 @@ pub fn capture_screen_region(
     width: Option<u32>,
     height: Option<u32>,
 ) -> Result<ScreenCapture> {
-    let monitors = Monitor::all()
-        .map_err(|e| AumateError::Screen(format!("Failed to get monitors: {}", e)))?;
-
-    if monitors.is_empty() {
-        return Err(AumateError::Screen("No monitors found".to_string()));
-    }
-
-    // Use the first monitor
-    let monitor = &monitors[0];
-    let image = monitor
+    permissions::require_screen_recording("to capture the screen")?;
+    let image = primary_monitor()?
         .capture_image()
         .map_err(|e| AumateError::Screen(format!("Failed to capture screen: {}", e)))?;
 
@@ -124,7 +210,12 @@ pub fn capture_screen_region(
     let rgba_image: RgbaImage = ImageBuffer::from_raw(width, height, region_buffer)
         .ok_or_else(|| AumateError::Screen("Failed to create image buffer".to_string()))?;
 
-    // Convert to PNG bytes
+    encode_capture(&rgba_image)
+}
+
+/// Encode an RGBA image as a PNG ScreenCapture
+fn encode_capture(rgba_image: &RgbaImage) -> Result<ScreenCapture> {
+    let (width, height) = rgba_image.dimensions();
     let mut png_bytes = Vec::new();
     {
         let encoder = image::codecs::png::PngEncoder::new(&mut png_bytes);
@@ -136,28 +227,192 @@ pub fn capture_screen_region(
     Ok(ScreenCapture { width, height, image: png_bytes })
 }
 
+/// Capture the contents of a single window
+///
+/// Uses the platform window capture API, so the window is captured even when
+/// other windows cover it. Where that is not supported, falls back to
+/// capturing the window's bounds on the primary monitor, which then includes
+/// anything on top of it.
+///
+/// # Arguments
+/// * `window_id` - `window_id` of a window from `get_all_windows`
+///
+/// # Returns
+/// A ScreenCapture object containing the captured image as PNG buffer
+pub fn capture_window(window_id: &str) -> Result<ScreenCapture> {
+    permissions::require_screen_recording("to capture windows")?;
+    let id: u32 = window_id
+        .parse()
+        .map_err(|_| AumateError::Screen(format!("Invalid window id: {}", window_id)))?;
+
+    let windows =
+        Window::all().map_err(|e| AumateError::Screen(format!("Failed to list windows: {}", e)))?;
+    let window = windows
+        .into_iter()
+        .find(|w| w.id().is_ok_and(|wid| wid == id))
+        .ok_or_else(|| AumateError::WindowNotFound(window_id.to_string()))?;
+
+    match window.capture_image() {
+        Ok(image) => encode_capture(&image),
+        Err(e) => {
+            log::debug!("Window capture failed, capturing its bounds instead: {}", e);
+            capture_window_bounds(&window)
+        }
+    }
+}
+
+/// Capture the part of the primary monitor covered by a window
+fn capture_window_bounds(window: &Window) -> Result<ScreenCapture> {
+    let bounds =
+        |e: xcap::XCapError| AumateError::Screen(format!("Failed to get window bounds: {}", e));
+    let window_rect = (
+        window.x().map_err(bounds)?,
+        window.y().map_err(bounds)?,
+        window.width().map_err(bounds)?,
+        window.height().map_err(bounds)?,
+    );
+
+    let monitor = primary_monitor()?;
+    let monitor_bounds =
+        |e: xcap::XCapError| AumateError::Screen(format!("Failed to get monitor bounds: {}", e));
+    let monitor_rect = (
+        monitor.x().map_err(monitor_bounds)?,
+        monitor.y().map_err(monitor_bounds)?,
+        monitor.width().map_err(monitor_bounds)?,
+        monitor.height().map_err(monitor_bounds)?,
+    );
+
+    let (x, y, width, height) = window_region_on_monitor(window_rect, monitor_rect)
+        .ok_or_else(|| AumateError::Screen("Window is not on the primary monitor".to_string()))?;
+
+    let scale_factor = monitor_scale_factor(&monitor)?;
+    let physical = |v| geometry_to_physical(v, scale_factor, GEOMETRY_IS_PHYSICAL);
+    capture_screen_region(
+        Some(physical(x)),
+        Some(physical(y)),
+        Some(physical(width)),
+        Some(physical(height)),
+    )
+}
+
+/// Intersect a window with a monitor, both as `(x, y, width, height)` in
+/// the units xcap reports
+///
+/// Returns the overlap relative to the monitor's top-left corner, or `None` if
+/// they do not overlap.
+fn window_region_on_monitor(
+    window: (i32, i32, u32, u32),
+    monitor: (i32, i32, u32, u32),
+) -> Option<(u32, u32, u32, u32)> {
+    let (wx, wy, ww, wh) = window;
+    let (mx, my, mw, mh) = monitor;
+
+    let left = (wx as i64).max(mx as i64);
+    let top = (wy as i64).max(my as i64);
+    let right = (wx as i64 + ww as i64).min(mx as i64 + mw as i64);
+    let bottom = (wy as i64 + wh as i64).min(my as i64 + mh as i64);
+
+    if right <= left || bottom <= top {
+        return None;
+    }
+
+    Some((
+        (left - mx as i64) as u32,
+        (top - my as i64) as u32,
+        (right - left) as u32,
+        (bottom - top) as u32,
+    ))
+}
+
 /// Get the screen size of the primary monitor
 ///
 /// # Returns
-/// A ScreenSize object containing width and height
+/// A ScreenSize object containing logical and physical width and height
 pub fn get_screen_size() -> Result<ScreenSize> {
+    let monitor = primary_monitor()?;
+
+    let width = monitor
+        .width()
+        .map_err(|e| AumateError::Screen(format!("Failed to get monitor width: {}", e)))?;
+    let height = monitor
+        .height()
+        .map_err(|e| AumateError::Screen(format!("Failed to get monitor height: {}", e)))?;
+    let scale_factor = monitor_scale_factor(&monitor)?;
+
+    Ok(screen_size(width, height, scale_factor, GEOMETRY_IS_PHYSICAL))
+}
+
+/// Build a ScreenSize from a monitor size reported in logical or physical units
+fn screen_size(width: u32, height: u32, scale_factor: f32, is_physical: bool) -> ScreenSize {
+    if is_physical {
+        ScreenSize {
+            width: to_logical(width, scale_factor),
+            height: to_logical(height, scale_factor),
+            physical_width: width,
+            physical_height: height,
+        }
+    } else {
+        ScreenSize {
+            width,
+            height,
+            physical_width: to_physical(width, scale_factor),
+            physical_height: to_physical(height, scale_factor),
+        }
+    }
+}
+
+/// Get the scale factor of the primary monitor
+///
+/// This is the ratio between physical pixels and logical coordinates, e.g.
+/// `2.0` on a Retina display. Divide screenshot pixel coordinates by it to get
+/// the logical coordinates expected by mouse movement.
+pub fn get_screen_scale_factor() -> Result<f32> {
+    monitor_scale_factor(&primary_monitor()?)
+}
+
+fn primary_monitor() -> Result<Monitor> {
     let monitors = Monitor::all()
         .map_err(|e| AumateError::Screen(format!("Failed to get monitors: {}", e)))?;
+    let index = primary_index(&monitors);
+    monitors
+        .into_iter()
+        .nth(index)
+        .ok_or_else(|| AumateError::Screen("No monitors found".to_string()))
+}
 
-    if monitors.is_empty() {
-        return Err(AumateError::Screen("No monitors found".to_string()));
-    }
+/// Index of the monitor the OS reports as primary, falling back to the first
+fn primary_index(monitors: &[Monitor]) -> usize {
+    monitors.iter().position(|m| m.is_primary().unwrap_or(false)).unwrap_or(0)
+}
 
-    let monitor = &monitors[0];
+fn monitor_scale_factor(monitor: &Monitor) -> Result<f32> {
+    monitor
+        .scale_factor()
+        .map_err(|e| AumateError::Screen(format!("Failed to get monitor scale factor: {}", e)))
+}
 
-    Ok(ScreenSize {
-        width: monitor
-            .width()
-            .map_err(|e| AumateError::Screen(format!("Failed to get monitor width: {}", e)))?,
-        height: monitor
-            .height()
-            .map_err(|e| AumateError::Screen(format!("Failed to get monitor height: {}", e)))?,
-    })
+/// Whether xcap reports monitor and window geometry in physical pixels
+///
+/// On Windows it comes from `dmPelsWidth` and window rects, which are device
+/// pixels. macOS and Linux report logical points.
+const GEOMETRY_IS_PHYSICAL: bool = cfg!(target_os = "windows");
+
+/// Convert a logical dimension to physical pixels
+fn to_physical(logical: u32, scale_factor: f32) -> u32 {
+    (logical as f64 * scale_factor as f64).round() as u32
+}
+
+/// Convert a physical dimension to logical coordinates
+fn to_logical(physical: u32, scale_factor: f32) -> u32 {
+    if scale_factor <= 0.0 {
+        return physical;
+    }
+    (physical as f64 / scale_factor as f64).round() as u32
+}
+
+/// Convert a coordinate or dimension reported by xcap to physical pixels
+fn geometry_to_physical(value: u32, scale_factor: f32, is_physical: bool) -> u32 {
+    if is_physical { value } else { to_physical(value, scale_factor) }
 }
 
 /// Get the pixel color at the specified coordinates
@@ -169,15 +424,13 @@ pub fn get_screen_size() -> Result<ScreenSize> {
 /// # Returns
 /// A PixelColor object containing RGBA values
 pub fn get_pixel_color(x: u32, y: u32) -> Result<PixelColor> {
-    let monitors = Monitor::all()
-        .map_err(|e| AumateError::Screen(format!("Failed to get monitors: {}", e)))?;
-
-    if monitors.is_empty() {
-        return Err(AumateError::Screen("No monitors found".to_string()));
-    }
+    permissions::require_screen_recording("to read screen colors")?;
+    read_pixel_color(x, y)
+}
 
-    let monitor = &monitors[0];
-    let image = monitor
+/// Read a pixel color without checking for Screen Recording access
+fn read_pixel_color(x: u32, y: u32) -> Result<PixelColor> {
+    let image = primary_monitor()?
         .capture_image()
         .map_err(|e| AumateError::Screen(format!("Failed to capture screen: {}", e)))?;
 
@@ -207,10 +460,240 @@ pub fn get_pixel_color(x: u32, y: u32) -> Result<PixelColor> {
     })
 }
 
+/// Get the average color of a screen region
+///
+/// The region is clamped to the screen bounds. Useful for coarse checks such
+/// as "is this area mostly green?" without transferring the whole capture.
+pub fn get_region_average_color(x: u32, y: u32, width: u32, height: u32) -> Result<PixelColor> {
+    permissions::require_screen_recording("to read screen colors")?;
+    let region = capture_region_rgba(x, y, width, height)?;
+    Ok(average_color(region.as_raw()))
+}
+
+/// Get the dominant color of a screen region
+///
+/// The region is downscaled and its colors are bucketed into a coarse
+/// histogram; the result is the average color of the most populated bucket.
+pub fn get_region_dominant_color(x: u32, y: u32, width: u32, height: u32) -> Result<PixelColor> {
+    permissions::require_screen_recording("to read screen colors")?;
+    let region = capture_region_rgba(x, y, width, height)?;
+    Ok(dominant_color(&region))
+}
+
+/// Capture a region of the primary monitor as raw RGBA
+///
+/// Does not check for Screen Recording access; callers check once up front.
+fn capture_region_rgba(x: u32, y: u32, width: u32, height: u32) -> Result<RgbaImage> {
+    let image = primary_monitor()?
+        .capture_image()
+        .map_err(|e| AumateError::Screen(format!("Failed to capture screen: {}", e)))?;
+
+    let x = x.min(image.width());
+    let y = y.min(image.height());
+    let width = width.min(image.width() - x);
+    let height = height.min(image.height() - y);
+    if width == 0 || height == 0 {
+        return Err(AumateError::Screen(format!(
+            "Region ({}, {}, {}x{}) is outside the screen",
+            x, y, width, height
+        )));
+    }
+
+    Ok(image::imageops::crop_imm(&image, x, y, width, height).to_image())
+}
+
+/// Average an RGBA buffer channel by channel
+fn average_color(rgba: &[u8]) -> PixelColor {
+    let count = (rgba.len() / 4).max(1) as u64;
+    let mut sums = [0u64; 4];
+    for px in rgba.chunks_exact(4) {
+        for (sum, &channel) in sums.iter_mut().zip(px) {
+            *sum += channel as u64;
+        }
+    }
+    PixelColor::new(
+        (sums[0] / count) as u8,
+        (sums[1] / count) as u8,
+        (sums[2] / count) as u8,
+        (sums[3] / count) as u8,
+    )
+}
+
+/// Find the dominant color using a 4-bit-per-channel histogram
+fn dominant_color(image: &RgbaImage) -> PixelColor {
+    const MAX_SIDE: u32 = 64;
+
+    let (width, height) = image.dimensions();
+    let sample = if width > MAX_SIDE || height > MAX_SIDE {
+        let scale = MAX_SIDE as f64 / width.max(height) as f64;
+        image::imageops::resize(
+            image,
+            ((width as f64 * scale).round() as u32).max(1),
+            ((height as f64 * scale).round() as u32).max(1),
+            image::imageops::FilterType::Nearest,
+        )
+    } else {
+        image.clone()
+    };
+
+    let bucket_of = |px: &[u8]| {
+        ((px[0] as usize >> 4) << 8) | ((px[1] as usize >> 4) << 4) | (px[2] as usize >> 4)
+    };
+    let mut histogram = vec![0u32; 1 << 12];
+    for px in sample.as_raw().chunks_exact(4) {
+        histogram[bucket_of(px)] += 1;
+    }
+    let best = histogram
+        .iter()
+        .enumerate()
+        .max_by_key(|&(_, count)| *count)
+        .map(|(bucket, _)| bucket)
+        .unwrap_or(0);
+
+    let members: Vec<u8> = sample
+        .as_raw()
+        .chunks_exact(4)
+        .filter(|px| bucket_of(px) == best)
+        .flatten()
+        .copied()
+        .collect();
+    average_color(&members)
+}
+
+/// Wait until the pixel at the given coordinates matches a color
+///
+/// Polls every `poll_interval` until the pixel is within `tolerance` of
+/// `target` (see [`PixelColor::matches`]) or `timeout` elapses.
+///
+/// # Returns
+/// `true` if the color matched, `false` on timeout
+pub fn wait_for_pixel_color(
+    x: u32,
+    y: u32,
+    target: PixelColor,
+    tolerance: u8,
+    timeout: Duration,
+    poll_interval: Duration,
+) -> Result<bool> {
+    permissions::require_screen_recording("to read screen colors")?;
+    let deadline = Instant::now() + timeout;
+    loop {
+        if read_pixel_color(x, y)?.matches(&target, tolerance) {
+            return Ok(true);
+        }
+        let now = Instant::now();
+        if now >= deadline {
+            return Ok(false);
+        }
+        std::thread::sleep(poll_interval.min(deadline - now));
+    }
+}
+
+/// A raw frame delivered by [`capture_stream`]
+#[derive(Debug, Clone)]
+pub struct CaptureFrame {
+    pub width: u32,
+    pub height: u32,
+    /// Raw RGBA pixels, `width * height * 4` bytes
+    pub data: Vec<u8>,
+}
+
+/// Handle to a running capture stream; capturing stops when it is dropped
+pub struct CaptureStream {
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl CaptureStream {
+    /// Stop capturing and wait for the capture thread to exit
+    pub fn stop(self) {
+        drop(self);
+    }
+}
+
+impl Drop for CaptureStream {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Continuously capture the screen and call `callback` with raw RGBA frames
+///
+/// Frames are captured on a background thread at up to `fps` frames per
+/// second. `region` is `(x, y, width, height)`, clamped to the screen; `None`
+/// captures the whole primary monitor. If capturing or the callback falls
+/// behind, missed frames are skipped rather than queued. Failed captures are
+/// skipped as well.
+pub fn capture_stream<F>(
+    fps: u32,
+    region: Option<(u32, u32, u32, u32)>,
+    mut callback: F,
+) -> Result<CaptureStream>
+where
+    F: FnMut(CaptureFrame) + Send + 'static,
+{
+    let interval = frame_interval(fps)?;
+    permissions::require_screen_recording("to capture the screen")?;
+    let stop = Arc::new(AtomicBool::new(false));
+    let stop_flag = stop.clone();
+
+    let thread = thread::Builder::new()
+        .name("aumate-capture-stream".to_string())
+        .spawn(move || {
+            let mut next_frame = Instant::now();
+            while !stop_flag.load(Ordering::SeqCst) {
+                let now = Instant::now();
+                if now < next_frame {
+                    thread::sleep(next_frame - now);
+                    continue;
+                }
+                next_frame = next_frame_deadline(next_frame, interval, now);
+
+                let (x, y, width, height) = region.unwrap_or((0, 0, u32::MAX, u32::MAX));
+                if let Ok(image) = capture_region_rgba(x, y, width, height) {
+                    callback(CaptureFrame {
+                        width: image.width(),
+                        height: image.height(),
+                        data: image.into_raw(),
+                    });
+                }
+            }
+        })
+        .map_err(|e| AumateError::Screen(format!("Failed to start capture stream: {}", e)))?;
+
+    Ok(CaptureStream { stop, thread: Some(thread) })
+}
+
+/// Time between frames for a frame rate
+fn frame_interval(fps: u32) -> Result<Duration> {
+    if fps == 0 {
+        return Err(AumateError::Screen("Frame rate must be greater than 0".to_string()));
+    }
+    Ok(Duration::from_secs(1) / fps)
+}
+
+/// Schedule the frame after `deadline`, skipping ticks that were already missed
+fn next_frame_deadline(deadline: Instant, interval: Duration, now: Instant) -> Instant {
+    let next = deadline + interval;
+    if next > now {
+        return next;
+    }
+    let missed = ((now - deadline).as_nanos() / interval.as_nanos()) as u32;
+    deadline + interval * (missed + 1)
+}
+
 /// Get all monitors
+///
+/// `x`/`y`/`width`/`height` are positions in the virtual desktop as the OS
+/// reports them: logical points on macOS and Linux, device pixels on Windows.
+/// `scale_factor` converts between the two.
 pub fn get_monitors() -> Result<Vec<MonitorInfo>> {
     let monitors = Monitor::all()
         .map_err(|e| AumateError::Screen(format!("Failed to get monitors: {}", e)))?;
+    let primary = primary_index(&monitors);
 
     monitors
         .iter()
@@ -229,12 +712,21 @@ pub fn get_monitors() -> Result<Vec<MonitorInfo>> {
                     .map_err(|e| AumateError::Screen(format!("Failed to get monitor x: {}", e)))?,
                 y: m.y()
                     .map_err(|e| AumateError::Screen(format!("Failed to get monitor y: {}", e)))?,
-                is_primary: i == 0,
+                is_primary: i == primary,
+                scale_factor: monitor_scale_factor(m)?,
             })
         })
         .collect()
 }
 
+/// Get the primary monitor
+pub fn get_primary_monitor() -> Result<MonitorInfo> {
+    get_monitors()?
+        .into_iter()
+        .find(|m| m.is_primary)
+        .ok_or_else(|| AumateError::Screen("No monitors found".to_string()))
+}
+
 /// Monitor information
 #[derive(Debug, Clone)]
 pub struct MonitorInfo {
@@ -245,4 +737,157 @@ pub struct MonitorInfo {
     pub x: i32,
     pub y: i32,
     pub is_primary: bool,
+    pub scale_factor: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_physical() {
+        assert_eq!(to_physical(1440, 1.0), 1440);
+        assert_eq!(to_physical(1440, 2.0), 2880);
+        assert_eq!(to_physical(1280, 1.25), 1600);
+        assert_eq!(to_physical(1707, 1.5), 2561);
+    }
+
+    #[test]
+    fn test_screen_size_from_logical() {
+        // macOS and Linux report logical points
+        let size = screen_size(1440, 900, 2.0, false);
+        assert_eq!(
+            size,
+            ScreenSize { width: 1440, height: 900, physical_width: 2880, physical_height: 1800 }
+        );
+    }
+
+    #[test]
+    fn test_screen_size_from_physical() {
+        // Windows reports device pixels, which must not be scaled again
+        let size = screen_size(2880, 1620, 1.5, true);
+        assert_eq!(
+            size,
+            ScreenSize { width: 1920, height: 1080, physical_width: 2880, physical_height: 1620 }
+        );
+        assert_eq!(geometry_to_physical(2880, 1.5, true), 2880);
+        assert_eq!(geometry_to_physical(1920, 1.5, false), 2880);
+    }
+
+    #[test]
+    fn test_window_region_on_monitor() {
+        let monitor = (0, 0, 1920, 1080);
+        assert_eq!(
+            window_region_on_monitor((100, 50, 800, 600), monitor),
+            Some((100, 50, 800, 600))
+        );
+        // Clipped at the left and bottom edges
+        assert_eq!(
+            window_region_on_monitor((-200, 900, 800, 600), monitor),
+            Some((0, 900, 600, 180))
+        );
+        // On a secondary monitor to the right
+        assert_eq!(window_region_on_monitor((2000, 100, 800, 600), monitor), None);
+
+        // Offsets are relative to the monitor origin
+        assert_eq!(
+            window_region_on_monitor((1950, 10, 100, 100), (1920, 0, 1280, 1024)),
+            Some((30, 10, 100, 100))
+        );
+    }
+
+    #[test]
+    fn test_pixel_color_from_hex() {
+        assert_eq!(PixelColor::from_hex("#FF8000").unwrap(), PixelColor::new(255, 128, 0, 255));
+        assert_eq!(PixelColor::from_hex("ff800080").unwrap(), PixelColor::new(255, 128, 0, 128));
+        assert!(PixelColor::from_hex("#FF80").is_err());
+        assert!(PixelColor::from_hex("#GG0000").is_err());
+        assert!(PixelColor::from_hex("#ÿÿÿ").is_err());
+    }
+
+    fn assert_hsv(color: PixelColor, h: f64, s: f64, v: f64) {
+        let hsv = color.to_hsv();
+        assert!(
+            (hsv.h - h).abs() < 1e-9 && (hsv.s - s).abs() < 1e-9 && (hsv.v - v).abs() < 1e-9,
+            "{:?} -> {:?}, expected ({}, {}, {})",
+            color,
+            hsv,
+            h,
+            s,
+            v
+        );
+    }
+
+    #[test]
+    fn test_to_hsv_primaries() {
+        assert_hsv(PixelColor::new(255, 0, 0, 255), 0.0, 1.0, 1.0);
+        assert_hsv(PixelColor::new(0, 255, 0, 255), 120.0, 1.0, 1.0);
+        assert_hsv(PixelColor::new(0, 0, 255, 255), 240.0, 1.0, 1.0);
+        assert_hsv(PixelColor::new(255, 0, 255, 255), 300.0, 1.0, 1.0);
+        assert_hsv(PixelColor::new(0, 0, 0, 255), 0.0, 0.0, 0.0);
+        assert_hsv(PixelColor::new(255, 255, 255, 255), 0.0, 0.0, 1.0);
+    }
+
+    #[test]
+    fn test_to_lab_reference_values() {
+        let close = |a: [f64; 3], b: [f64; 3]| a.iter().zip(b).all(|(x, y)| (x - y).abs() < 0.1);
+        assert!(close(PixelColor::new(255, 255, 255, 255).to_lab(), [100.0, 0.0, 0.0]));
+        assert!(close(PixelColor::new(255, 0, 0, 255).to_lab(), [53.24, 80.09, 67.20]));
+        assert!(close(PixelColor::new(0, 255, 0, 255).to_lab(), [87.73, -86.18, 83.18]));
+        assert!(close(PixelColor::new(0, 0, 255, 255).to_lab(), [32.30, 79.19, -107.86]));
+    }
+
+    #[test]
+    fn test_color_distance() {
+        let black = PixelColor::new(0, 0, 0, 255);
+        let white = PixelColor::new(255, 255, 255, 255);
+        assert!((black.distance(&white) - 100.0).abs() < 0.01);
+        assert_eq!(white.distance(&white), 0.0);
+        // Near-identical colors are closer than clearly different ones
+        let red = PixelColor::new(255, 0, 0, 255);
+        assert!(red.distance(&PixelColor::new(250, 5, 5, 255)) < red.distance(&white));
+    }
+
+    #[test]
+    fn test_pixel_color_matches() {
+        let color = PixelColor::new(100, 150, 200, 255);
+        assert!(color.matches(&PixelColor::new(100, 150, 200, 0), 0));
+        assert!(color.matches(&PixelColor::new(105, 145, 200, 255), 5));
+        assert!(!color.matches(&PixelColor::new(106, 150, 200, 255), 5));
+    }
+
+    #[test]
+    fn test_frame_interval() {
+        assert_eq!(frame_interval(10).unwrap(), Duration::from_millis(100));
+        assert_eq!(frame_interval(1).unwrap(), Duration::from_secs(1));
+        assert!(frame_interval(0).is_err());
+        assert!(capture_stream(0, None, |_| {}).is_err());
+    }
+
+    #[test]
+    fn test_next_frame_deadline_skips_missed_frames() {
+        let start = Instant::now();
+        let interval = Duration::from_millis(100);
+        // On time: next tick
+        assert_eq!(next_frame_deadline(start, interval, start), start + interval);
+        // 350ms late: ticks at 100/200/300 are dropped, next is 400
+        let late = start + Duration::from_millis(350);
+        assert_eq!(next_frame_deadline(start, interval, late), start + interval * 4);
+    }
+
+    #[test]
+    fn test_average_color() {
+        let rgba = [255, 0, 0, 255, 0, 0, 255, 255];
+        assert_eq!(average_color(&rgba), PixelColor::new(127, 0, 127, 255));
+        assert_eq!(average_color(&[]), PixelColor::new(0, 0, 0, 0));
+    }
+
+    #[test]
+    fn test_dominant_color() {
+        // 3/4 green, 1/4 red: green wins even though the average is muddy
+        let image = RgbaImage::from_fn(100, 100, |x, _| {
+            if x < 25 { image::Rgba([255, 0, 0, 255]) } else { image::Rgba([0, 200, 0, 255]) }
+        });
+        assert_eq!(dominant_color(&image), PixelColor::new(0, 200, 0, 255));
+    }
 }
@@ -1,6 +1,7 @@
 //! STT configuration and settings
 
 use crate::error::Result;
+use crate::ml::DevicePreference;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
@@ -36,6 +37,31 @@ impl OutputMode {
     }
 }
 
+fn default_output_modes() -> Vec<OutputMode> {
+    vec![OutputMode::default()]
+}
+
+/// Accept both a list of output modes and the single-value form written by
+/// older versions
+fn deserialize_output_modes<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Vec<OutputMode>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(OutputMode),
+        Many(Vec<OutputMode>),
+    }
+
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(mode) => vec![mode],
+        OneOrMany::Many(modes) => modes,
+    })
+}
+
 /// Hotkey activation mode
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
@@ -133,14 +159,22 @@ pub struct SttConfig {
     /// Whether the global hotkey listener is enabled
     #[serde(default = "default_hotkey_enabled")]
     pub hotkey_enabled: bool,
-    /// Output mode for transcribed text
-    pub output_mode: OutputMode,
+    /// Output modes for transcribed text, applied in order
+    #[serde(
+        alias = "output_mode",
+        default = "default_output_modes",
+        deserialize_with = "deserialize_output_modes"
+    )]
+    pub output_modes: Vec<OutputMode>,
     /// Selected model ID
     pub model_id: String,
     /// Language for transcription (None = auto-detect)
     pub language: Option<String>,
     /// Input device name (None = default)
     pub input_device: Option<String>,
+    /// Device to run Whisper on
+    #[serde(default)]
+    pub device: DevicePreference,
 }
 
 impl Default for SttConfig {
@@ -148,10 +182,11 @@ impl Default for SttConfig {
         Self {
             hotkey: HotkeyConfig::default(),
             hotkey_enabled: true,
-            output_mode: OutputMode::default(),
+            output_modes: default_output_modes(),
             model_id: "whisper-base".to_string(),
             language: None,
             input_device: None,
+            device: DevicePreference::default(),
         }
     }
 }
@@ -315,5 +350,44 @@ mod tests {
         let json = serde_json::to_string(&config).unwrap();
         let parsed: SttConfig = serde_json::from_str(&json).unwrap();
         assert_eq!(parsed.model_id, config.model_id);
+        assert_eq!(parsed.output_modes, config.output_modes);
+    }
+
+    #[test]
+    fn test_device_deserialization() {
+        let mut value = serde_json::to_value(SttConfig::default()).unwrap();
+        let fields = value.as_object_mut().unwrap();
+
+        // Configs written before the device setting existed run on the CPU
+        fields.remove("device");
+        let parsed: SttConfig = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(parsed.device, DevicePreference::Cpu);
+
+        let fields = value.as_object_mut().unwrap();
+        fields.insert("device".into(), serde_json::json!({ "cuda": { "index": 1 } }));
+        let parsed: SttConfig = serde_json::from_value(value).unwrap();
+        assert_eq!(parsed.device, DevicePreference::Cuda { index: 1 });
+    }
+
+    #[test]
+    fn test_output_modes_deserialization() {
+        let mut value = serde_json::to_value(SttConfig::default()).unwrap();
+        let fields = value.as_object_mut().unwrap();
+
+        // Single-value form written by older versions
+        fields.remove("output_modes");
+        fields.insert("output_mode".into(), "clipboard".into());
+        let parsed: SttConfig = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(parsed.output_modes, [OutputMode::Clipboard]);
+
+        let fields = value.as_object_mut().unwrap();
+        fields.remove("output_mode");
+        fields.insert("output_modes".into(), serde_json::json!(["keystrokes", "clipboard"]));
+        let parsed: SttConfig = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(parsed.output_modes, [OutputMode::Keystrokes, OutputMode::Clipboard]);
+
+        value.as_object_mut().unwrap().remove("output_modes");
+        let parsed: SttConfig = serde_json::from_value(value).unwrap();
+        assert_eq!(parsed.output_modes, [OutputMode::Keystrokes]);
     }
 }
@@ -10,7 +10,7 @@ use std::thread;
 use crate::error::Result;
 use crate::gui::controller::{AsyncTask, ControllerContext, ControllerFeature, TabInfo};
 use crate::ml::{
-    DeviceConfig, DownloadProgress, DownloadStatus, ModelInfo, ModelManager, ModelType,
+    DevicePreference, DownloadProgress, DownloadStatus, ModelInfo, ModelManager, ModelType,
     device_name, is_gpu_available,
 };
 use crate::stt::{
@@ -18,16 +18,6 @@ use crate::stt::{
     OutputMode, SttConfig, WhisperEngine,
 };
 
-/// Available device options for STT inference
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
-pub enum SttDevice {
-    /// CPU (always available)
-    #[default]
-    Cpu,
-    /// GPU acceleration (Metal on macOS, CUDA on Linux/Windows)
-    Gpu,
-}
-
 /// STT feature for speech-to-text
 pub struct SttFeature {
     /// STT configuration
@@ -60,8 +50,6 @@ pub struct SttFeature {
     stt_whisper_engine: Option<WhisperEngine>,
     /// Async task for model loading
     load_model_task: Option<AsyncTask<std::result::Result<WhisperEngine, String>>>,
-    /// Selected device for inference
-    selected_device: SttDevice,
     /// Whether GPU is available on this system
     gpu_available: bool,
     /// Whether audio playback is in progress
@@ -86,7 +74,6 @@ impl SttFeature {
             stt_transcribing: Arc::new(AtomicBool::new(false)),
             stt_whisper_engine: None,
             load_model_task: None,
-            selected_device: SttDevice::default(),
             gpu_available: is_gpu_available(),
             stt_is_playing: Arc::new(AtomicBool::new(false)),
         }
@@ -123,8 +110,9 @@ impl SttFeature {
         let last_audio = self.stt_last_audio.clone();
         let model_id = self.stt_config.model_id.clone();
         let language = self.stt_config.language.clone();
+        let device = self.stt_config.device;
         let input_device = self.stt_config.input_device.clone();
-        let output_mode = self.stt_config.output_mode;
+        let output_modes = self.stt_config.output_modes.clone();
 
         self.add_debug_message(&format!(
             "Initializing hotkey: {} (mode: {:?})",
@@ -162,7 +150,7 @@ impl SttFeature {
                 let model_id_thread = model_id.clone();
                 let language_thread = language.clone();
                 let input_device_thread = input_device.clone();
-                let output_mode_thread = output_mode;
+                let output_modes_thread = output_modes.clone();
 
                 // Spawn recording thread
                 thread::spawn(move || {
@@ -175,8 +163,9 @@ impl SttFeature {
                         last_audio_thread,
                         model_id_thread,
                         language_thread,
+                        device,
                         input_device_thread,
-                        output_mode_thread,
+                        output_modes_thread,
                     );
                 });
             }
@@ -212,8 +201,9 @@ impl SttFeature {
         last_audio: Arc<Mutex<Option<crate::stt::AudioData>>>,
         model_id: String,
         language: Option<String>,
+        device: DevicePreference,
         input_device: Option<String>,
-        output_mode: OutputMode,
+        output_modes: Vec<OutputMode>,
     ) {
         // Create audio recorder
         let mut recorder = match AudioRecorder::new() {
@@ -310,7 +300,16 @@ impl SttFeature {
         let model_path = model_manager.model_dir(ModelType::Whisper, &model_id);
 
         // Load and run Whisper
-        let mut engine = WhisperEngine::new();
+        let mut engine = match WhisperEngine::with_device(device.into()) {
+            Ok(engine) => engine,
+            Err(e) => {
+                let msg = format!("Failed to create Whisper engine: {}", e);
+                log::error!("STT: {}", msg);
+                Self::add_debug_message_to_log(&debug_log, &msg);
+                is_transcribing.store(false, Ordering::Relaxed);
+                return;
+            }
+        };
         engine.set_language(language);
 
         if let Err(e) = engine.load_model(&model_path) {
@@ -334,7 +333,7 @@ impl SttFeature {
                 *last_transcription.lock().unwrap() = Some(result.text.clone());
 
                 // Handle output
-                Self::handle_output(&result.text, output_mode);
+                Self::handle_output(&result.text, &output_modes);
             }
             Err(e) => {
                 let msg = format!("Transcription failed: {}", e);
@@ -346,12 +345,19 @@ impl SttFeature {
         is_transcribing.store(false, Ordering::Relaxed);
     }
 
-    /// Handle transcription output
-    fn handle_output(text: &str, output_mode: OutputMode) {
+    /// Handle transcription output, applying each output mode in order
+    fn handle_output(text: &str, output_modes: &[OutputMode]) {
         if text.is_empty() {
             return;
         }
 
+        for &output_mode in output_modes {
+            Self::handle_output_mode(text, output_mode);
+        }
+    }
+
+    /// Send transcription output to a single destination
+    fn handle_output_mode(text: &str, output_mode: OutputMode) {
         match output_mode {
             OutputMode::Keystrokes => {
                 #[cfg(feature = "input")]
@@ -546,21 +552,19 @@ impl SttFeature {
     fn start_model_load(&mut self, model_path: PathBuf) {
         let task = AsyncTask::new();
         let callback = task.callback();
-        let use_gpu = self.selected_device == SttDevice::Gpu;
+        let device = self.stt_config.device;
         let language = self.stt_config.language.clone();
 
         self.stt_status = "Loading model...".to_string();
         self.add_debug_message(&format!(
-            "Loading model: {:?} (GPU: {})",
+            "Loading model: {:?} (device: {})",
             model_path.file_name().unwrap_or_default(),
-            use_gpu
+            device
         ));
 
         thread::spawn(move || {
-            log::info!("Loading STT model async: {:?} (GPU: {})", model_path, use_gpu);
-            let device_config =
-                if use_gpu { DeviceConfig::with_gpu() } else { DeviceConfig::cpu_only() };
-            let result = match WhisperEngine::with_device(device_config) {
+            log::info!("Loading STT model async: {:?} (device: {})", model_path, device);
+            let result = match WhisperEngine::with_device(device.into()) {
                 Ok(mut engine) => {
                     engine.set_language(language);
                     engine.load_model(&model_path).map(|_| engine).map_err(|e| e.to_string())
@@ -906,16 +910,21 @@ impl ControllerFeature for SttFeature {
 
             ui.add_space(8.0);
 
-            // Output mode selection
+            // Output mode selection, several modes can be active at once
             ui.label("Output:");
             ui.horizontal(|ui| {
                 for mode in OutputMode::all() {
-                    if ui
-                        .selectable_label(self.stt_config.output_mode == *mode, mode.display_name())
-                        .clicked()
-                    {
-                        self.stt_config.output_mode = *mode;
-                        config_changed = true;
+                    let modes = &mut self.stt_config.output_modes;
+                    let selected = modes.contains(mode);
+                    if ui.selectable_label(selected, mode.display_name()).clicked() {
+                        if !selected {
+                            modes.push(*mode);
+                            config_changed = true;
+                        } else if modes.len() > 1 {
+                            // Keep at least one output
+                            modes.retain(|m| m != mode);
+                            config_changed = true;
+                        }
                     }
                 }
             });
@@ -971,33 +980,28 @@ impl ControllerFeature for SttFeature {
             ui.horizontal(|ui| {
                 ui.label("Device:");
 
-                // CPU option (always available)
-                let cpu_selected = self.selected_device == SttDevice::Cpu;
-                if ui.selectable_label(cpu_selected, "CPU").clicked()
-                    && self.selected_device != SttDevice::Cpu
-                {
-                    self.selected_device = SttDevice::Cpu;
-                    // Unload engine when switching device
-                    self.stt_whisper_engine = None;
-                    self.stt_initialized = false;
-                    self.stt_status = "Device changed - reload model".to_string();
-                }
+                let mut device = self.stt_config.device;
+                egui::ComboBox::from_id_salt("stt_device_selector")
+                    .selected_text(device.to_string())
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut device, DevicePreference::Cpu, "CPU");
+                        // GPU options (only if available)
+                        ui.add_enabled_ui(self.gpu_available, |ui| {
+                            ui.selectable_value(&mut device, DevicePreference::Gpu, "GPU (auto)");
+                            if cfg!(target_os = "macos") {
+                                ui.selectable_value(&mut device, DevicePreference::Metal, "Metal");
+                            } else {
+                                let is_cuda = matches!(device, DevicePreference::Cuda { .. });
+                                if ui.selectable_label(is_cuda, "CUDA").clicked() && !is_cuda {
+                                    device = DevicePreference::Cuda { index: 0 };
+                                }
+                            }
+                        });
+                    });
 
-                // GPU option (only if available)
-                let gpu_text = if cfg!(target_os = "macos") { "Metal" } else { "CUDA" };
-
-                ui.add_enabled_ui(self.gpu_available, |ui| {
-                    let gpu_selected = self.selected_device == SttDevice::Gpu;
-                    if ui.selectable_label(gpu_selected, gpu_text).clicked()
-                        && self.selected_device != SttDevice::Gpu
-                    {
-                        self.selected_device = SttDevice::Gpu;
-                        // Unload engine when switching device
-                        self.stt_whisper_engine = None;
-                        self.stt_initialized = false;
-                        self.stt_status = "Device changed - reload model".to_string();
-                    }
-                });
+                if let DevicePreference::Cuda { index } = &mut device {
+                    ui.add(egui::DragValue::new(index).prefix("#"));
+                }
 
                 if !self.gpu_available {
                     ui.label(
@@ -1006,6 +1010,15 @@ impl ControllerFeature for SttFeature {
                             .color(egui::Color32::GRAY),
                     );
                 }
+
+                if device != self.stt_config.device {
+                    self.stt_config.device = device;
+                    let _ = self.stt_config.save();
+                    // Unload engine when switching device
+                    self.stt_whisper_engine = None;
+                    self.stt_initialized = false;
+                    self.stt_status = "Device changed - reload model".to_string();
+                }
             });
 
             ui.add_space(4.0);
@@ -1064,7 +1077,20 @@ impl ControllerFeature for SttFeature {
                     let mut model_to_delete: Option<String> = None;
 
                     for model in &self.stt_available_models {
-                        ui.label(&model.name);
+                        ui.horizontal(|ui| {
+                            ui.label(&model.name);
+                            if model.quantized {
+                                ui.label(
+                                    egui::RichText::new("quantized")
+                                        .small()
+                                        .color(egui::Color32::LIGHT_BLUE),
+                                )
+                                .on_hover_text(
+                                    "8-bit weights: smaller and faster on CPU, \
+                                     slightly less accurate",
+                                );
+                            }
+                        });
                         ui.label(model.size_display());
 
                         // Status
@@ -21,18 +21,17 @@ const MEL_FILTERS_128: &[u8] = include_bytes!("melfilters128.bytes");
 /// Whisper model variant
 pub enum WhisperModel {
     Normal(m::model::Whisper),
-    // Quantized variant could be added later
+    Quantized(m::quantized_model::Whisper),
 }
 
 impl WhisperModel {
     /// Run encoder forward pass
     pub fn encoder_forward(&mut self, mel: &Tensor, flush: bool) -> Result<Tensor> {
         match self {
-            Self::Normal(model) => model
-                .encoder
-                .forward(mel, flush)
-                .map_err(|e| AumateError::Other(format!("Encoder forward failed: {}", e))),
+            Self::Normal(model) => model.encoder.forward(mel, flush),
+            Self::Quantized(model) => model.encoder.forward(mel, flush),
         }
+        .map_err(|e| AumateError::Other(format!("Encoder forward failed: {}", e)))
     }
 
     /// Run decoder forward pass
@@ -42,17 +41,19 @@ impl WhisperModel {
         audio_features: &Tensor,
         flush: bool,
     ) -> Result<Tensor> {
+        let decoder_error = |e| AumateError::Other(format!("Decoder forward failed: {}", e));
+        let linear_error = |e| AumateError::Other(format!("Final linear failed: {}", e));
+        // Project to vocabulary logits
         match self {
             Self::Normal(model) => {
-                let decoder_output = model
-                    .decoder
-                    .forward(tokens, audio_features, flush)
-                    .map_err(|e| AumateError::Other(format!("Decoder forward failed: {}", e)))?;
-                // Project to vocabulary logits
-                model
-                    .decoder
-                    .final_linear(&decoder_output)
-                    .map_err(|e| AumateError::Other(format!("Final linear failed: {}", e)))
+                let decoder_output =
+                    model.decoder.forward(tokens, audio_features, flush).map_err(decoder_error)?;
+                model.decoder.final_linear(&decoder_output).map_err(linear_error)
+            }
+            Self::Quantized(model) => {
+                let decoder_output =
+                    model.decoder.forward(tokens, audio_features, flush).map_err(decoder_error)?;
+                model.decoder.final_linear(&decoder_output).map_err(linear_error)
             }
         }
     }
@@ -62,6 +63,7 @@ impl WhisperModel {
     pub fn reset_kv_cache(&mut self) {
         match self {
             Self::Normal(model) => model.reset_kv_cache(),
+            Self::Quantized(model) => model.reset_kv_cache(),
         }
     }
 }
@@ -127,7 +129,7 @@ impl WhisperEngine {
     /// Load a Whisper model from directory containing model files
     ///
     /// The directory should contain:
-    /// - model.safetensors (or model.gguf for quantized)
+    /// - model.safetensors, or model.gguf for quantized weights (preferred if both exist)
     /// - config.json
     /// - tokenizer.json
     /// - mel_filters.safetensors (optional, will use built-in if missing)
@@ -161,9 +163,38 @@ impl WhisperEngine {
         self.mel_filters = Self::get_mel_filters(config.num_mel_bins)?;
 
         // Load model weights
+        let model = self.load_weights(model_dir, &config)?;
+
+        self.model = Some(model);
+        self.config = Some(config);
+        self.tokenizer = Some(tokenizer);
+        self.model_path = Some(model_dir.to_path_buf());
+
+        log::info!("Whisper model loaded successfully on {:?}", self.device);
+        Ok(())
+    }
+
+    /// Load quantized weights from model.gguf, or full precision ones from
+    /// model.safetensors
+    fn load_weights(&self, model_dir: &Path, config: &Config) -> Result<WhisperModel> {
+        let gguf_path = model_dir.join("model.gguf");
+        if gguf_path.exists() {
+            let vb = candle_transformers::quantized_var_builder::VarBuilder::from_gguf(
+                &gguf_path,
+                &self.device,
+            )
+            .map_err(|e| AumateError::Other(format!("Failed to load weights: {}", e)))?;
+
+            let model = m::quantized_model::Whisper::load(&vb, config.clone())
+                .map_err(|e| AumateError::Other(format!("Failed to create model: {}", e)))?;
+            return Ok(WhisperModel::Quantized(model));
+        }
+
         let weights_path = model_dir.join("model.safetensors");
         if !weights_path.exists() {
-            return Err(AumateError::Other("model.safetensors not found".to_string()));
+            return Err(AumateError::Other(
+                "model.safetensors or model.gguf not found".to_string(),
+            ));
         }
 
         let vb = unsafe {
@@ -173,14 +204,7 @@ impl WhisperEngine {
 
         let model = m::model::Whisper::load(&vb, config.clone())
             .map_err(|e| AumateError::Other(format!("Failed to create model: {}", e)))?;
-
-        self.model = Some(WhisperModel::Normal(model));
-        self.config = Some(config);
-        self.tokenizer = Some(tokenizer);
-        self.model_path = Some(model_dir.to_path_buf());
-
-        log::info!("Whisper model loaded successfully on {:?}", self.device);
-        Ok(())
+        Ok(WhisperModel::Normal(model))
     }
 
     /// Get mel filters for the specified number of mel bins
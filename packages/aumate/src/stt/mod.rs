@@ -102,11 +102,11 @@ impl SttController {
         self.audio_recorder = Some(AudioRecorder::new()?);
 
         // Initialize output handler
-        self.output_handler = Some(OutputHandler::new(self.config.output_mode)?);
+        self.output_handler = Some(OutputHandler::new(&self.config.output_modes)?);
 
         // Try to load the selected model if available
         if let Some(model_path) = self.model_manager.get_model_path(&self.config.model_id) {
-            let mut engine = WhisperEngine::new();
+            let mut engine = WhisperEngine::with_device(self.config.device.into())?;
             if engine.load_model(&model_path).is_ok() {
                 self.engine = Some(engine);
             }
@@ -8,55 +8,71 @@ use crate::input::Keyboard;
 
 /// Handler for outputting transcribed text
 pub struct OutputHandler {
-    /// Output mode
-    mode: OutputMode,
+    /// Output modes, applied in order
+    modes: Vec<OutputMode>,
     /// Keyboard for typing text
     keyboard: Keyboard,
 }
 
-impl OutputHandler {
-    /// Create a new output handler
-    pub fn new(mode: OutputMode) -> Result<Self> {
-        let keyboard = Keyboard::new()?;
-        Ok(Self { mode, keyboard })
-    }
-
-    /// Get the current output mode
-    pub fn mode(&self) -> OutputMode {
-        self.mode
-    }
+/// Destinations that transcribed text can be sent to
+trait OutputSink {
+    fn type_text(&self, text: &str) -> Result<()>;
+    fn copy_to_clipboard(&self, text: &str) -> Result<()>;
+    fn paste(&self) -> Result<()>;
+}
 
-    /// Set the output mode
-    pub fn set_mode(&mut self, mode: OutputMode) {
-        self.mode = mode;
+/// Send text to every sink selected by `modes`, in order
+fn write_to_sinks(sink: &impl OutputSink, modes: &[OutputMode], text: &str) -> Result<()> {
+    if text.is_empty() {
+        return Ok(());
     }
 
-    /// Output the transcribed text according to the current mode
-    pub fn output(&self, text: &str) -> Result<()> {
-        if text.is_empty() {
-            return Ok(());
-        }
-
-        match self.mode {
+    for mode in modes {
+        match mode {
             OutputMode::Keystrokes => {
-                self.type_text(text)?;
+                sink.type_text(text)?;
             }
             OutputMode::Clipboard => {
-                self.copy_to_clipboard(text)?;
+                sink.copy_to_clipboard(text)?;
             }
             OutputMode::Both => {
-                self.copy_to_clipboard(text)?;
-                self.paste()?;
+                sink.copy_to_clipboard(text)?;
+                sink.paste()?;
             }
             OutputMode::Logger => {
                 // Logger mode - only log, no typing or clipboard
                 log::info!("STT Output (Logger): \"{}\"", truncate_for_log(text, 100));
             }
         }
+    }
 
-        Ok(())
+    Ok(())
+}
+
+impl OutputHandler {
+    /// Create a new output handler
+    pub fn new(modes: &[OutputMode]) -> Result<Self> {
+        let keyboard = Keyboard::new()?;
+        Ok(Self { modes: modes.to_vec(), keyboard })
+    }
+
+    /// Get the current output modes
+    pub fn modes(&self) -> &[OutputMode] {
+        &self.modes
     }
 
+    /// Set the output modes
+    pub fn set_modes(&mut self, modes: &[OutputMode]) {
+        self.modes = modes.to_vec();
+    }
+
+    /// Output the transcribed text to each of the current modes in order
+    pub fn output(&self, text: &str) -> Result<()> {
+        write_to_sinks(self, &self.modes, text)
+    }
+}
+
+impl OutputSink for OutputHandler {
     /// Type text as keystrokes
     fn type_text(&self, text: &str) -> Result<()> {
         log::info!("Typing text: \"{}\"", truncate_for_log(text, 50));
@@ -99,6 +115,7 @@ fn truncate_for_log(text: &str, max_len: usize) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::cell::RefCell;
 
     #[test]
     fn test_truncate_for_log() {
@@ -109,8 +126,43 @@ mod tests {
     #[test]
     fn test_output_mode() {
         // This test requires a display, so just test creation
-        if let Ok(handler) = OutputHandler::new(OutputMode::Keystrokes) {
-            assert_eq!(handler.mode(), OutputMode::Keystrokes);
+        if let Ok(handler) = OutputHandler::new(&[OutputMode::Keystrokes]) {
+            assert_eq!(handler.modes(), [OutputMode::Keystrokes]);
+        }
+    }
+
+    /// Records what each sink receives
+    #[derive(Default)]
+    struct RecordingSink {
+        calls: RefCell<Vec<String>>,
+    }
+
+    impl OutputSink for RecordingSink {
+        fn type_text(&self, text: &str) -> Result<()> {
+            self.calls.borrow_mut().push(format!("type:{}", text));
+            Ok(())
         }
+
+        fn copy_to_clipboard(&self, text: &str) -> Result<()> {
+            self.calls.borrow_mut().push(format!("clipboard:{}", text));
+            Ok(())
+        }
+
+        fn paste(&self) -> Result<()> {
+            self.calls.borrow_mut().push("paste".to_string());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_multiple_modes_reach_every_sink() {
+        let sink = RecordingSink::default();
+        let modes = [OutputMode::Keystrokes, OutputMode::Clipboard];
+        write_to_sinks(&sink, &modes, "hello").unwrap();
+        assert_eq!(*sink.calls.borrow(), ["type:hello", "clipboard:hello"]);
+
+        // Empty text is not sent anywhere
+        write_to_sinks(&sink, &modes, "").unwrap();
+        assert_eq!(sink.calls.borrow().len(), 2);
     }
 }
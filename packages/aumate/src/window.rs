@@ -2,9 +2,9 @@
 //!
 //! Provides window information and management for desktop automation.
 //!
-//! Note: This implementation uses active-win-pos-rs which provides
-//! get_active_window() functionality. Getting a list of all windows
-//! requires platform-specific implementations.
+//! Note: The active window comes from active-win-pos-rs. Listing all windows
+//! uses xcap and needs the `screen` feature; without it, window lists only
+//! contain the active window.
 
 use crate::error::{AumateError, Result};
 use active_win_pos_rs::{ActiveWindow, get_active_window};
@@ -16,8 +16,10 @@ pub struct WindowInfo {
     pub title: String,
     /// Process ID
     pub process_id: u32,
-    /// Process path/name
+    /// Path of the process executable, empty if it could not be read
     pub process_path: String,
+    /// Application display name, e.g. "Google Chrome"
+    pub app_name: String,
     /// Window position X
     pub x: f64,
     /// Window position Y
@@ -28,6 +30,12 @@ pub struct WindowInfo {
     pub height: f64,
     /// Window ID (platform-specific)
     pub window_id: String,
+    /// Whether the window is minimized
+    pub is_minimized: bool,
+    /// Whether the window has a non-zero size and overlaps a monitor
+    pub is_on_screen: bool,
+    /// Stacking position, 0 being the frontmost window
+    pub z_order: u32,
 }
 
 impl WindowInfo {
@@ -37,65 +45,239 @@ impl WindowInfo {
             title: window.title,
             process_id: window.process_id as u32,
             process_path: window.process_path.to_string_lossy().to_string(),
+            app_name: window.app_name,
             x: window.position.x,
             y: window.position.y,
             width: window.position.width,
             height: window.position.height,
             window_id: window.window_id.to_string(),
+            // The focused window is never minimized
+            is_minimized: false,
+            is_on_screen: window.position.width > 0.0 && window.position.height > 0.0,
+            // The focused window is frontmost
+            z_order: 0,
         }
     }
 
-    /// Get the process name from the path
+    /// Create WindowInfo from an xcap window
+    #[cfg(feature = "screen")]
+    fn from_xcap_window(window: &xcap::Window, monitors: &[Rect]) -> Result<Self> {
+        let field = |name: &str, e: xcap::XCapError| {
+            AumateError::Window(format!("Failed to get window {}: {}", name, e))
+        };
+
+        let x = window.x().map_err(|e| field("x", e))?;
+        let y = window.y().map_err(|e| field("y", e))?;
+        let width = window.width().map_err(|e| field("width", e))?;
+        let height = window.height().map_err(|e| field("height", e))?;
+
+        let process_id = window.pid().map_err(|e| field("pid", e))?;
+
+        Ok(Self {
+            title: window.title().map_err(|e| field("title", e))?,
+            process_id,
+            process_path: executable_path(process_id).unwrap_or_default(),
+            app_name: window.app_name().map_err(|e| field("app name", e))?,
+            x: x as f64,
+            y: y as f64,
+            width: width as f64,
+            height: height as f64,
+            window_id: window.id().map_err(|e| field("id", e))?.to_string(),
+            is_minimized: window.is_minimized().map_err(|e| field("minimized state", e))?,
+            is_on_screen: overlaps_any(&Rect { x, y, width, height }, monitors),
+            // Filled in by get_all_windows
+            z_order: 0,
+        })
+    }
+
+    /// Get the process name from the path, or the app name if the path is unknown
     pub fn process_name(&self) -> &str {
+        if self.process_path.is_empty() {
+            return &self.app_name;
+        }
         self.process_path.rsplit(std::path::MAIN_SEPARATOR).next().unwrap_or(&self.process_path)
     }
+
+    /// Whether the window can be interacted with: not minimized and on screen
+    pub fn is_visible(&self) -> bool {
+        !self.is_minimized && self.is_on_screen
+    }
+}
+
+/// Path of a process executable
+///
+/// xcap only reports the application display name, so the path is looked up
+/// from the process id. Returns `None` when the process has exited or belongs
+/// to another user.
+#[cfg(all(feature = "screen", target_os = "linux"))]
+fn executable_path(pid: u32) -> Option<String> {
+    let path = std::fs::read_link(format!("/proc/{}/exe", pid)).ok()?;
+    Some(path.to_string_lossy().to_string())
+}
+
+/// Path of a process executable
+///
+/// xcap only reports the application display name, so the path is looked up
+/// from the process id. Returns `None` when the process has exited.
+#[cfg(all(feature = "screen", target_os = "macos"))]
+fn executable_path(pid: u32) -> Option<String> {
+    let mut buf = vec![0u8; libc::PROC_PIDPATHINFO_MAXSIZE as usize];
+    // SAFETY: buf is valid for buf.len() bytes and proc_pidpath writes at most that many
+    let len = unsafe { libc::proc_pidpath(pid as i32, buf.as_mut_ptr().cast(), buf.len() as u32) };
+    if len <= 0 {
+        return None;
+    }
+    buf.truncate(len as usize);
+    Some(String::from_utf8_lossy(&buf).to_string())
+}
+
+/// Path of a process executable
+///
+/// xcap only reports the application display name, so the path is looked up
+/// from the process id. Returns `None` when the process has exited or cannot
+/// be opened, e.g. elevated processes.
+#[cfg(all(feature = "screen", target_os = "windows"))]
+fn executable_path(pid: u32) -> Option<String> {
+    use winapi::shared::minwindef::{DWORD, FALSE, MAX_PATH};
+    use winapi::um::handleapi::CloseHandle;
+    use winapi::um::processthreadsapi::OpenProcess;
+    use winapi::um::winbase::QueryFullProcessImageNameW;
+    use winapi::um::winnt::PROCESS_QUERY_LIMITED_INFORMATION;
+
+    // SAFETY: the handle is checked for null and closed before returning, and
+    // QueryFullProcessImageNameW writes at most `len` UTF-16 units to buf
+    unsafe {
+        let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, FALSE, pid);
+        if handle.is_null() {
+            return None;
+        }
+        let mut buf = vec![0u16; MAX_PATH * 4];
+        let mut len = buf.len() as DWORD;
+        let ok = QueryFullProcessImageNameW(handle, 0, buf.as_mut_ptr(), &mut len);
+        CloseHandle(handle);
+        if ok == 0 {
+            return None;
+        }
+        Some(String::from_utf16_lossy(&buf[..len as usize]))
+    }
+}
+
+/// Screen-space rectangle in logical pixels
+#[cfg(feature = "screen")]
+#[derive(Debug, Clone, Copy)]
+struct Rect {
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+}
+
+#[cfg(feature = "screen")]
+impl Rect {
+    fn overlaps(&self, other: &Rect) -> bool {
+        let right = |r: &Rect| r.x as i64 + r.width as i64;
+        let bottom = |r: &Rect| r.y as i64 + r.height as i64;
+        (self.x as i64) < right(other)
+            && (other.x as i64) < right(self)
+            && (self.y as i64) < bottom(other)
+            && (other.y as i64) < bottom(self)
+    }
+}
+
+/// Whether a non-empty window rectangle overlaps any monitor
+#[cfg(feature = "screen")]
+fn overlaps_any(window: &Rect, monitors: &[Rect]) -> bool {
+    window.width > 0 && window.height > 0 && monitors.iter().any(|m| window.overlaps(m))
+}
+
+/// Bounds of all monitors
+#[cfg(feature = "screen")]
+fn monitor_rects() -> Result<Vec<Rect>> {
+    let monitors = xcap::Monitor::all()
+        .map_err(|e| AumateError::Window(format!("Failed to get monitors: {}", e)))?;
+
+    monitors
+        .iter()
+        .map(|m| {
+            let rect = Rect { x: m.x()?, y: m.y()?, width: m.width()?, height: m.height()? };
+            Ok(rect)
+        })
+        .collect::<std::result::Result<_, xcap::XCapError>>()
+        .map_err(|e| AumateError::Window(format!("Failed to get monitor bounds: {}", e)))
 }
 
 /// Get the currently active (focused) window
 pub fn get_active_window_info() -> Result<WindowInfo> {
-    let active_window = get_active_window()
-        .map_err(|_| AumateError::Window("Failed to get active window".to_string()))?;
+    let active_window = get_active_window().map_err(|_| AumateError::NoFocusedWindow)?;
 
     Ok(WindowInfo::from_active_window(active_window))
 }
 
-/// Get a list of all visible windows
-/// Note: Currently only returns the active window due to API limitations
+/// Get a list of all windows, including minimized and off-screen ones
+///
+/// Windows are sorted front to back by `z_order`.
+///
+/// Note: Without the `screen` feature only the active window is returned
+#[cfg(feature = "screen")]
+pub fn get_all_windows() -> Result<Vec<WindowInfo>> {
+    let monitors = monitor_rects()?;
+    let windows = xcap::Window::all()
+        .map_err(|e| AumateError::Window(format!("Failed to list windows: {}", e)))?;
+
+    // xcap lists windows front to back, so the position in the list is the
+    // stacking order; asking each window for its z value would re-list them all
+    let windows = windows
+        .iter()
+        .filter_map(|w| {
+            // Windows can close while being listed; skip ones that can't be read
+            WindowInfo::from_xcap_window(w, &monitors)
+                .map_err(|e| log::debug!("Skipping unreadable window: {}", e))
+                .ok()
+        })
+        .enumerate()
+        .map(|(i, mut info)| {
+            info.z_order = i as u32;
+            info
+        })
+        .collect();
+
+    Ok(windows)
+}
+
+/// Get a list of all windows, including minimized and off-screen ones
+///
+/// Note: Without the `screen` feature only the active window is returned
+#[cfg(not(feature = "screen"))]
 pub fn get_all_windows() -> Result<Vec<WindowInfo>> {
-    let active_window = get_active_window()
-        .map_err(|_| AumateError::Window("Failed to get active window".to_string()))?;
+    Ok(vec![get_active_window_info()?])
+}
 
-    Ok(vec![WindowInfo::from_active_window(active_window)])
+/// Get windows that can be interacted with: not minimized, non-zero size and
+/// at least partly on a monitor
+pub fn get_visible_windows() -> Result<Vec<WindowInfo>> {
+    let mut windows = get_all_windows()?;
+    windows.retain(WindowInfo::is_visible);
+    Ok(windows)
 }
 
-/// Find windows by title (case-insensitive partial match)
-/// Note: Currently only searches the active window due to API limitations
+/// Find windows by title (case-insensitive partial match), frontmost first
 pub fn find_windows_by_title(search_title: &str) -> Result<Vec<WindowInfo>> {
-    let active_window = get_active_window()
-        .map_err(|_| AumateError::Window("Failed to get active window".to_string()))?;
-
     let search_lower = search_title.to_lowercase();
-    if active_window.title.to_lowercase().contains(&search_lower) {
-        Ok(vec![WindowInfo::from_active_window(active_window)])
-    } else {
-        Ok(vec![])
-    }
+    let mut windows = get_all_windows()?;
+    windows.retain(|w| w.title.to_lowercase().contains(&search_lower));
+    Ok(windows)
 }
 
-/// Find windows by process name (case-insensitive partial match)
-/// Note: Currently only searches the active window due to API limitations
+/// Find windows by process name (case-insensitive partial match against the
+/// executable path or app name), frontmost first
 pub fn find_windows_by_process(process_name: &str) -> Result<Vec<WindowInfo>> {
-    let active_window = get_active_window()
-        .map_err(|_| AumateError::Window("Failed to get active window".to_string()))?;
-
     let process_lower = process_name.to_lowercase();
-    let process_path_str = active_window.process_path.to_string_lossy().to_lowercase();
-
-    if process_path_str.contains(&process_lower) {
-        Ok(vec![WindowInfo::from_active_window(active_window)])
-    } else {
-        Ok(vec![])
-    }
+    let mut windows = get_all_windows()?;
+    windows.retain(|w| {
+        w.process_path.to_lowercase().contains(&process_lower)
+            || w.app_name.to_lowercase().contains(&process_lower)
+    });
+    Ok(windows)
 }
 
 #[cfg(test)]
@@ -116,6 +298,34 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "screen")]
+    #[test]
+    fn test_overlaps_any() {
+        let monitors = [
+            Rect { x: 0, y: 0, width: 1920, height: 1080 },
+            Rect { x: 1920, y: 0, width: 1280, height: 1024 },
+        ];
+        let window = |x, y, width, height| Rect { x, y, width, height };
+
+        assert!(overlaps_any(&window(100, 100, 800, 600), &monitors));
+        assert!(overlaps_any(&window(3000, 900, 800, 600), &monitors));
+        // Partly off the left edge
+        assert!(overlaps_any(&window(-400, 10, 500, 300), &monitors));
+        // Parked off-screen, as minimized windows often are on Windows
+        assert!(!overlaps_any(&window(-32000, -32000, 160, 28), &monitors));
+        // Below the shorter second monitor
+        assert!(!overlaps_any(&window(2000, 1030, 300, 200), &monitors));
+        assert!(!overlaps_any(&window(100, 100, 0, 600), &monitors));
+    }
+
+    #[cfg(feature = "screen")]
+    #[test]
+    fn test_executable_path() {
+        let path = executable_path(std::process::id()).expect("own executable path");
+        let exe = std::env::current_exe().unwrap();
+        assert_eq!(path, exe.to_string_lossy());
+    }
+
     #[test]
     fn test_get_all_windows() {
         let result = get_all_windows();
@@ -1,5 +1,20 @@
 /* auto-generated by NAPI-RS */
 /* eslint-disable */
+/** Token for cancelling a long-running async input operation */
+export declare class CancellationToken {
+  constructor();
+  /** Request cancellation; the operation stops at its next checkpoint */
+  cancel(): void;
+  /** Whether `cancel` has been called */
+  get isCancelled(): boolean;
+}
+
+/** Handle returned by `watchClipboard` */
+export declare class ClipboardWatcher {
+  /** Stop watching for clipboard changes */
+  stop(): void;
+}
+
 /** Screen capture interface */
 export declare class Screen {
   constructor();
@@ -12,6 +27,14 @@ export declare class Screen {
   ): Promise<Bitmap>;
 }
 
+/** Options for Bezier mouse movement */
+export interface BezierOptions {
+  /** Exactly two control points; randomized for a natural arc when omitted */
+  controlPoints?: Array<MousePositionResult>;
+  /** Number of interpolation steps (default: 50) */
+  steps?: number;
+}
+
 /** Bitmap structure for screen capture (robotjs compatible) */
 export interface Bitmap {
   width: number;
@@ -29,6 +52,22 @@ export declare function bitmapColorAt(
   y: number,
 ): string;
 
+/** Raw frame delivered by a capture stream */
+export interface CaptureFrameResult {
+  width: number;
+  height: number;
+  /** Raw RGBA pixels, `width * height * 4` bytes */
+  data: Buffer;
+}
+
+/** Screen region to capture */
+export interface CaptureRegionInput {
+  x: number;
+  y: number;
+  width: number;
+  height: number;
+}
+
 /** Capture entire screen */
 export declare function captureScreen(): Promise<ScreenCaptureResult>;
 
@@ -40,9 +79,53 @@ export declare function captureScreenRegion(
   height: number,
 ): Promise<ScreenCaptureResult>;
 
+/**
+ * Capture the contents of a single window, even when partly covered
+ *
+ * Falls back to capturing the window's bounds on the primary monitor where
+ * the platform cannot capture windows directly.
+ *
+ * @param windowId - `windowId` of a window from `getAllWindows`
+ */
+export declare function captureWindow(
+  windowId: string,
+): Promise<ScreenCaptureResult>;
+
+/**
+ * Check which system permissions the current process has
+ *
+ * Only macOS gates these; other platforms report everything as granted.
+ */
+export declare function checkPermissions(): PermissionStatusResult;
+
 /** Clear clipboard */
 export declare function clearClipboard(): void;
 
+/** Clipboard change event */
+export interface ClipboardChangeEvent {
+  /** Content type: "text", "html", "image" or "empty" */
+  contentType: string;
+  /** Plain text of the new content, if any */
+  text?: string;
+}
+
+/**
+ * Perceptual distance between two hex colors (CIE76 delta E)
+ *
+ * About 2.3 is a just-noticeable difference; black to white is 100.
+ *
+ * @param hexA - First color as hex string
+ * @param hexB - Second color as hex string
+ */
+export declare function colorDistance(hexA: string, hexB: string): number;
+
+/**
+ * Convert a hex color to HSV
+ *
+ * @param hex - Color as hex string (e.g. "#FF0000")
+ */
+export declare function colorToHsv(hex: string): HsvResult;
+
 /** Drag mouse */
 export declare function dragMouse(x: number, y: number): void;
 
@@ -78,6 +161,20 @@ export declare function findAllOnScreen(
   config?: MatchConfigJs | undefined | null,
 ): Promise<Array<MatchResultJs>>;
 
+/**
+ * Find first match of template image in another image instead of the live screen
+ *
+ * @param haystackPng - PNG-encoded image buffer to search in
+ * @param templatePng - PNG-encoded image buffer of the template to find
+ * @param confidence - Minimum confidence threshold 0.0-1.0 (default: 0.8)
+ * @returns Match result or null if not found (coordinates are relative to the haystack)
+ */
+export declare function findImageInBuffer(
+  haystackPng: Buffer,
+  templatePng: Buffer,
+  confidence?: number | undefined | null,
+): Promise<MatchResultJs | null>;
+
 /**
  * Find first match of template image in a screen region
  *
@@ -110,12 +207,15 @@ export declare function findOnScreen(
   config?: MatchConfigJs | undefined | null,
 ): Promise<MatchResultJs | null>;
 
-/** Find windows by process name (case-insensitive partial match) */
+/**
+ * Find windows by process name (case-insensitive partial match against the
+ * executable path or app name), frontmost first
+ */
 export declare function findWindowsByProcess(
   processName: string,
 ): Array<WindowInfoResult>;
 
-/** Find windows by title (case-insensitive partial match) */
+/** Find windows by title (case-insensitive partial match), frontmost first */
 export declare function findWindowsByTitle(
   title: string,
 ): Array<WindowInfoResult>;
@@ -123,27 +223,150 @@ export declare function findWindowsByTitle(
 /** Get the currently active (focused) window */
 export declare function getActiveWindow(): WindowInfoResult;
 
-/** Get a list of all visible windows */
+/** Get a list of all windows, including minimized and off-screen ones */
 export declare function getAllWindows(): Array<WindowInfoResult>;
 
 /** Get text from clipboard */
 export declare function getClipboard(): string;
 
+/** Get HTML from clipboard (returns null if no HTML is present) */
+export declare function getClipboardHtml(): string | null;
+
 /** Get image from clipboard (returns PNG-encoded buffer) */
 export declare function getClipboardImage(): Buffer;
 
+/** Get RTF from clipboard (returns null if no RTF is present) */
+export declare function getClipboardRtf(): string | null;
+
+/**
+ * List the content types currently on the clipboard
+ *
+ * Returns normalized MIME types such as "text/plain", "text/html", "text/rtf" and
+ * "image/png".
+ */
+export declare function getClipboardTypes(): Array<string>;
+
+/**
+ * Get the state of Caps Lock, Num Lock and Scroll Lock
+ *
+ * Best effort: macOS always reports Num Lock and Scroll Lock as off.
+ */
+export declare function getLockState(): LockStateResult;
+
+/** Get which modifier keys are currently held down */
+export declare function getModifierState(): ModifierStateResult;
+
+/** Get all monitors */
+export declare function getMonitors(): Array<MonitorInfoResult>;
+
 /** Get mouse position */
 export declare function getMousePos(): MousePositionResult;
 
 /** Get pixel color (returns hex string) */
 export declare function getPixelColor(x: number, y: number): Promise<string>;
 
+/** Get the primary monitor */
+export declare function getPrimaryMonitor(): MonitorInfoResult;
+
+/**
+ * Get the average color of a screen region (returns hex string)
+ *
+ * @param x - X coordinate of the region's top-left corner
+ * @param y - Y coordinate of the region's top-left corner
+ * @param width - Region width in pixels
+ * @param height - Region height in pixels
+ */
+export declare function getRegionAverageColor(
+  x: number,
+  y: number,
+  width: number,
+  height: number,
+): Promise<string>;
+
+/**
+ * Get the dominant color of a screen region (returns hex string)
+ *
+ * @param x - X coordinate of the region's top-left corner
+ * @param y - Y coordinate of the region's top-left corner
+ * @param width - Region width in pixels
+ * @param height - Region height in pixels
+ */
+export declare function getRegionDominantColor(
+  x: number,
+  y: number,
+  width: number,
+  height: number,
+): Promise<string>;
+
 /** Get global screen instance */
 export declare function getScreen(): Screen;
 
+/**
+ * Get the scale factor of the primary screen
+ *
+ * Ratio between physical pixels and logical coordinates (e.g. 2.0 on Retina).
+ */
+export declare function getScreenScaleFactor(): number;
+
 /** Get screen size */
 export declare function getScreenSize(): ScreenSizeResult;
 
+/** Get windows that are not minimized and at least partly on screen */
+export declare function getVisibleWindows(): Array<WindowInfoResult>;
+
+/**
+ * Color in HSV space
+ *
+ * `h` is the hue in degrees `[0, 360)`, `s` and `v` are in `[0, 1]`.
+ */
+export interface HsvResult {
+  h: number;
+  s: number;
+  v: number;
+}
+
+/**
+ * Check whether a shortcut is valid and not yet registered by this process
+ *
+ * Shortcuts used by other applications are not detected.
+ *
+ * @param accelerator - Combo such as "Ctrl+Shift+A" or "F3"
+ */
+export declare function isShortcutAvailable(accelerator: string): boolean;
+
+/**
+ * Tap a key combination written as one string, e.g. "Ctrl+Shift+S"
+ *
+ * @param combo - Modifiers and key separated by `+`
+ */
+export declare function keyCombo(combo: string): void;
+
+/**
+ * Hold a key down for a duration, then release it
+ *
+ * @param key - Key to hold
+ * @param durationMs - How long to hold the key in milliseconds
+ * @param modifier - Optional modifier keys held together with the key
+ */
+export declare function keyHold(
+  key: string,
+  durationMs: number,
+  modifier?: Array<string> | undefined | null,
+): void;
+
+/**
+ * Hold a key down for a duration on a worker thread
+ *
+ * @param key - Key to hold
+ * @param durationMs - How long to hold the key in milliseconds
+ * @param modifier - Optional modifier keys held together with the key
+ */
+export declare function keyHoldAsync(
+  key: string,
+  durationMs: number,
+  modifier?: Array<string> | undefined | null,
+): Promise<void>;
+
 /** Tap a key */
 export declare function keyTap(
   key: string,
@@ -157,6 +380,13 @@ export declare function keyToggle(
   modifier?: Array<string> | undefined | null,
 ): void;
 
+/** State of the keyboard lock keys */
+export interface LockStateResult {
+  caps: boolean;
+  num: boolean;
+  scroll: boolean;
+}
+
 /** Configuration for image template matching */
 export interface MatchConfigJs {
   /** Search at multiple scales (default: true) */
@@ -169,6 +399,10 @@ export interface MatchConfigJs {
   confidence?: number;
   /** Maximum number of results (default: 100) */
   limit?: number;
+  /** Template rotation angles in degrees to search (default: [0]) */
+  angles?: Array<number>;
+  /** IoU above which overlapping matches are merged (default: 0.5) */
+  nmsIou?: number;
 }
 
 /** Result from image template matching */
@@ -185,6 +419,34 @@ export interface MatchResultJs {
   confidence: number;
   /** Scale at which match was found */
   scale: number;
+  /** Template rotation in degrees at which match was found */
+  angle: number;
+}
+
+/** Modifier keys currently held down */
+export interface ModifierStateResult {
+  shift: boolean;
+  ctrl: boolean;
+  alt: boolean;
+  meta: boolean;
+}
+
+/**
+ * Monitor information
+ *
+ * Bounds are positions in the virtual desktop as the OS reports them: logical
+ * points on macOS and Linux, device pixels on Windows. `scale_factor` converts
+ * between the two.
+ */
+export interface MonitorInfoResult {
+  index: number;
+  name: string;
+  x: number;
+  y: number;
+  width: number;
+  height: number;
+  scaleFactor: number;
+  isPrimary: boolean;
 }
 
 /** Mouse click */
@@ -205,9 +467,42 @@ export declare function mouseToggle(
   button?: string | undefined | null,
 ): void;
 
-/** Move mouse */
+/**
+ * Move mouse
+ *
+ * Coordinates are logical, matching `width`/`height` from `getScreenSize`.
+ */
 export declare function moveMouse(x: number, y: number): void;
 
+/**
+ * Move mouse on a worker thread
+ *
+ * @param x - Target X coordinate
+ * @param y - Target Y coordinate
+ */
+export declare function moveMouseAsync(x: number, y: number): Promise<void>;
+
+/**
+ * Move mouse along a cubic Bezier curve
+ *
+ * @param x - Target X coordinate
+ * @param y - Target Y coordinate
+ * @param options - Optional control points and step count
+ */
+export declare function moveMouseBezier(
+  x: number,
+  y: number,
+  options?: BezierOptions | undefined | null,
+): void;
+
+/**
+ * Move mouse relative to its current position
+ *
+ * @param dx - Horizontal offset in pixels
+ * @param dy - Vertical offset in pixels
+ */
+export declare function moveMouseRelative(dx: number, dy: number): void;
+
 /** Move mouse smoothly */
 export declare function moveMouseSmooth(
   x: number,
@@ -215,6 +510,93 @@ export declare function moveMouseSmooth(
   speed?: number | undefined | null,
 ): void;
 
+/**
+ * Move mouse smoothly on a worker thread
+ *
+ * @param x - Target X coordinate
+ * @param y - Target Y coordinate
+ * @param speed - Optional speed multiplier
+ */
+export declare function moveMouseSmoothAsync(
+  x: number,
+  y: number,
+  speed?: number | undefined | null,
+): Promise<void>;
+
+/**
+ * Move mouse smoothly relative to its current position
+ *
+ * @param dx - Horizontal offset in pixels
+ * @param dy - Vertical offset in pixels
+ * @param speed - Optional speed multiplier
+ */
+export declare function moveMouseSmoothRelative(
+  dx: number,
+  dy: number,
+  speed?: number | undefined | null,
+): void;
+
+/**
+ * Recognize text in the image currently on the clipboard
+ *
+ * Fails if the clipboard does not hold an image. The default TrOCR model is
+ * downloaded on first use. Runs on a worker thread.
+ *
+ * @param preprocess - Image preprocessing before recognition
+ * @returns Recognized text
+ */
+export declare function ocrClipboardImage(
+  preprocess?: OcrPreprocessInput | undefined | null,
+): Promise<string>;
+
+/** Image preprocessing applied to each text region before recognition */
+export interface OcrPreprocessInput {
+  /** Upscale regions shorter than 32px by 2x (default true) */
+  upscale?: boolean;
+  /** Convert to grayscale (default true) */
+  grayscale?: boolean;
+  /** Straighten text rotated by up to 10 degrees (default false) */
+  deskew?: boolean;
+  /** Convert to black text on white (default false) */
+  binarize?: boolean;
+}
+
+/** Screen region to recognize text in, in capture (physical) pixels */
+export interface OcrRegionInput {
+  x: number;
+  y: number;
+  width: number;
+  height: number;
+}
+
+/**
+ * Recognize text in multiple screen regions
+ *
+ * The screen is captured once, each region is cropped from that capture and
+ * all crops are recognized in one batch by a shared OCR engine. The default
+ * TrOCR model is downloaded on first use. Runs on a worker thread.
+ *
+ * Regions are in capture (physical) pixels, not the logical coordinates
+ * mouse functions use; multiply logical values by the screen scale factor.
+ *
+ * @param regions - Screen regions to recognize
+ * @param preprocess - Image preprocessing before recognition
+ * @returns Recognized text for each region, in input order
+ */
+export declare function ocrRegions(
+  regions: Array<OcrRegionInput>,
+  preprocess?: OcrPreprocessInput | undefined | null,
+): Promise<Array<string>>;
+
+/** Permissions granted to the current process */
+export interface PermissionStatusResult {
+  /** Needed for input simulation and global shortcuts */
+  accessibility: boolean;
+  /** Needed for screen capture */
+  screenRecording: boolean;
+  microphone: boolean;
+}
+
 /** Pixel color information */
 export interface PixelColorResult {
   r: number;
@@ -223,6 +605,49 @@ export interface PixelColorResult {
   a: number;
 }
 
+/**
+ * Replay a recording returned by `stopRecordingMacro` on a worker thread
+ *
+ * Resolves once playback finishes or `token` is cancelled.
+ *
+ * @param buffer - Serialized recording
+ * @param speed - Playback speed multiplier (default: 1.0)
+ * @param token - Optional token to stop playback mid-way
+ */
+export declare function playMacro(
+  buffer: Buffer,
+  speed?: number | undefined | null,
+  token?: CancellationToken | undefined | null,
+): Promise<void>;
+
+/**
+ * Register a global shortcut
+ *
+ * Shortcuts are observed passively: the keystroke still reaches the focused
+ * application. They cannot be registered while a macro is being recorded.
+ *
+ * @param accelerator - Combo such as "Ctrl+Shift+A" or "F3"
+ * @param callback - Called each time the shortcut is pressed
+ */
+export declare function registerGlobalShortcut(
+  accelerator: string,
+  callback: (arg: undefined) => void,
+): void;
+
+/**
+ * Prompt the user to grant Accessibility access
+ *
+ * @returns true if access is already granted
+ */
+export declare function requestAccessibilityPermission(): boolean;
+
+/**
+ * Prompt the user to grant Screen Recording access
+ *
+ * @returns true if access is already granted
+ */
+export declare function requestScreenRecordingPermission(): boolean;
+
 /** Screen capture result containing image data */
 export interface ScreenCaptureResult {
   width: number;
@@ -230,47 +655,213 @@ export interface ScreenCaptureResult {
   image: Buffer;
 }
 
-/** Screen size information */
+/**
+ * Screen size information
+ *
+ * `width`/`height` are logical coordinates, the space `move_mouse` expects.
+ * `physical_width`/`physical_height` are device pixels, the space of captures.
+ * The OS reports one pair (logical on macOS/Linux, physical on Windows) and
+ * the other is derived with the scale factor.
+ */
 export interface ScreenSizeResult {
   width: number;
   height: number;
+  physicalWidth: number;
+  physicalHeight: number;
 }
 
+/**
+ * Scroll a number of lines in a direction
+ *
+ * @param direction - "up", "down", "left" or "right"
+ * @param count - Number of lines (default: 1)
+ */
+export declare function scrollLines(
+  direction: string,
+  count?: number | undefined | null,
+): void;
+
 /** Scroll mouse */
 export declare function scrollMouse(x: number, y: number): void;
 
+/**
+ * Scroll smoothly, spreading one-notch wheel events over a duration
+ *
+ * @param dx - Horizontal notches (positive scrolls right)
+ * @param dy - Vertical notches (positive scrolls down)
+ * @param durationMs - Total scroll duration in milliseconds (default: 300)
+ */
+export declare function scrollSmooth(
+  dx: number,
+  dy: number,
+  durationMs?: number | undefined | null,
+): void;
+
 /** Set text to clipboard */
 export declare function setClipboard(text: string): void;
 
+/**
+ * Set HTML to clipboard with a plain-text fallback
+ *
+ * @param html - HTML content
+ * @param altText - Plain-text fallback (derived from the HTML when omitted)
+ */
+export declare function setClipboardHtml(
+  html: string,
+  altText?: string | undefined | null,
+): void;
+
 /** Set image to clipboard (accepts PNG-encoded buffer) */
 export declare function setClipboardImage(imageBuffer: Buffer): void;
 
+/**
+ * Set RTF to clipboard with a plain-text fallback
+ *
+ * @param rtf - RTF document
+ * @param altText - Plain-text fallback (derived from the RTF when omitted)
+ */
+export declare function setClipboardRtf(
+  rtf: string,
+  altText?: string | undefined | null,
+): void;
+
 /** Set keyboard delay */
 export declare function setKeyboardDelay(ms: number): void;
 
 /** Set mouse delay */
 export declare function setMouseDelay(delay: number): void;
 
+/**
+ * Start streaming raw screen frames to `callback`
+ *
+ * Frames that arrive while earlier ones are still queued for JavaScript are
+ * dropped, so a slow callback lowers the effective frame rate instead of
+ * building up a backlog.
+ *
+ * @param fps - Target frames per second
+ * @param region - Region to capture (default: entire screen)
+ * @param callback - Called with each raw RGBA frame
+ */
+export declare function startCaptureStream(
+  fps: number,
+  region: CaptureRegionInput | undefined | null,
+  callback: (arg: CaptureFrameResult) => void,
+): void;
+
+/** Start recording global keyboard and mouse input */
+export declare function startRecordingMacro(): void;
+
+/** Stop the capture stream started by `start_capture_stream` */
+export declare function stopCaptureStream(): void;
+
+/** Stop recording and return the serialized recording */
+export declare function stopRecordingMacro(): Buffer;
+
 /** Type a string */
 export declare function typeString(string: string): void;
 
+/**
+ * Type a string on a worker thread
+ *
+ * @param string - Text to type
+ */
+export declare function typeStringAsync(string: string): Promise<void>;
+
 /** Type a string with delay */
 export declare function typeStringDelayed(string: string, cpm: number): void;
 
+/**
+ * Type a string with delay on a worker thread
+ *
+ * Resolves to `true` when the whole string was typed, or `false` if `token`
+ * was cancelled first.
+ *
+ * @param string - Text to type
+ * @param cpm - Characters per minute
+ * @param token - Optional token to stop typing mid-way
+ */
+export declare function typeStringDelayedAsync(
+  string: string,
+  cpm: number,
+  token?: CancellationToken | undefined | null,
+): Promise<boolean>;
+
+/**
+ * Type a string with human-like, randomized delays between characters
+ *
+ * @param string - Text to type
+ * @param baseCpm - Average typing speed in characters per minute
+ * @param jitterPercent - Maximum random deviation of each delay in percent (0-100)
+ * @param punctuationPause - Pause longer after punctuation and line breaks (default: true)
+ */
+export declare function typeStringHumanized(
+  string: string,
+  baseCpm: number,
+  jitterPercent: number,
+  punctuationPause?: boolean | undefined | null,
+): void;
+
 /** Tap a Unicode character */
 export declare function unicodeTap(value: number): void;
 
+/**
+ * Unregister a global shortcut
+ *
+ * @param accelerator - Combo passed to `registerGlobalShortcut`
+ * @returns true if the shortcut was registered
+ */
+export declare function unregisterGlobalShortcut(accelerator: string): boolean;
+
 /** Update screen metrics (no-op for now) */
 export declare function updateScreenMetrics(): void;
 
+/**
+ * Wait until a pixel matches a color
+ *
+ * Resolves to `true` once the pixel matches, or `false` when the timeout
+ * elapses.
+ *
+ * @param x - X coordinate
+ * @param y - Y coordinate
+ * @param color - Target color as hex string (e.g. "#FF0000")
+ * @param timeoutMs - Maximum time to wait in milliseconds
+ * @param tolerance - Maximum per-channel difference (default: 0)
+ * @param pollIntervalMs - Delay between checks in milliseconds (default: 100)
+ */
+export declare function waitForPixelColor(
+  x: number,
+  y: number,
+  color: string,
+  timeoutMs: number,
+  tolerance?: number | undefined | null,
+  pollIntervalMs?: number | undefined | null,
+): Promise<boolean>;
+
+/**
+ * Watch the clipboard and call `callback` whenever its content changes
+ *
+ * @param callback - Called with the new content type and text
+ */
+export declare function watchClipboard(
+  callback: (arg: ClipboardChangeEvent) => void,
+): ClipboardWatcher;
+
 /** Window information structure */
 export interface WindowInfoResult {
   title: string;
   processId: number;
+  /** Path of the process executable, empty if it could not be read */
   processPath: string;
+  /** Application display name, e.g. "Google Chrome" */
+  appName: string;
   x: number;
   y: number;
   width: number;
   height: number;
   windowId: string;
+  isMinimized: boolean;
+  /** Whether the window has a non-zero size and overlaps a monitor */
+  isOnScreen: boolean;
+  /** Stacking position, 0 being the frontmost window */
+  zOrder: number;
 }
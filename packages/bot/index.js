@@ -689,14 +689,21 @@ if (!nativeBinding) {
 }
 
 const {
+  CancellationToken,
+  ClipboardWatcher,
   Screen,
   bitmapColorAt,
   captureScreen,
   captureScreenRegion,
+  captureWindow,
+  checkPermissions,
   clearClipboard,
+  colorDistance,
+  colorToHsv,
   dragMouse,
   findAllInRegion,
   findAllOnScreen,
+  findImageInBuffer,
   findInRegion,
   findOnScreen,
   findWindowsByProcess,
@@ -704,35 +711,82 @@ const {
   getActiveWindow,
   getAllWindows,
   getClipboard,
+  getClipboardHtml,
   getClipboardImage,
+  getClipboardRtf,
+  getClipboardTypes,
+  getLockState,
+  getModifierState,
+  getMonitors,
   getMousePos,
   getPixelColor,
+  getPrimaryMonitor,
+  getRegionAverageColor,
+  getRegionDominantColor,
   getScreen,
+  getScreenScaleFactor,
   getScreenSize,
+  getVisibleWindows,
+  isShortcutAvailable,
+  keyCombo,
+  keyHold,
+  keyHoldAsync,
   keyTap,
   keyToggle,
   mouseClick,
   mouseToggle,
   moveMouse,
+  moveMouseAsync,
+  moveMouseBezier,
+  moveMouseRelative,
   moveMouseSmooth,
+  moveMouseSmoothAsync,
+  moveMouseSmoothRelative,
+  ocrClipboardImage,
+  ocrRegions,
+  playMacro,
+  registerGlobalShortcut,
+  requestAccessibilityPermission,
+  requestScreenRecordingPermission,
+  scrollLines,
   scrollMouse,
+  scrollSmooth,
   setClipboard,
+  setClipboardHtml,
   setClipboardImage,
+  setClipboardRtf,
   setKeyboardDelay,
   setMouseDelay,
+  startCaptureStream,
+  startRecordingMacro,
+  stopCaptureStream,
+  stopRecordingMacro,
   typeString,
+  typeStringAsync,
   typeStringDelayed,
+  typeStringDelayedAsync,
+  typeStringHumanized,
   unicodeTap,
+  unregisterGlobalShortcut,
   updateScreenMetrics,
+  waitForPixelColor,
+  watchClipboard,
 } = nativeBinding;
+export { CancellationToken };
+export { ClipboardWatcher };
 export { Screen };
 export { bitmapColorAt };
 export { captureScreen };
 export { captureScreenRegion };
+export { captureWindow };
+export { checkPermissions };
 export { clearClipboard };
+export { colorDistance };
+export { colorToHsv };
 export { dragMouse };
 export { findAllInRegion };
 export { findAllOnScreen };
+export { findImageInBuffer };
 export { findInRegion };
 export { findOnScreen };
 export { findWindowsByProcess };
@@ -740,23 +794,63 @@ export { findWindowsByTitle };
 export { getActiveWindow };
 export { getAllWindows };
 export { getClipboard };
+export { getClipboardHtml };
 export { getClipboardImage };
+export { getClipboardRtf };
+export { getClipboardTypes };
+export { getLockState };
+export { getModifierState };
+export { getMonitors };
 export { getMousePos };
 export { getPixelColor };
+export { getPrimaryMonitor };
+export { getRegionAverageColor };
+export { getRegionDominantColor };
 export { getScreen };
+export { getScreenScaleFactor };
 export { getScreenSize };
+export { getVisibleWindows };
+export { isShortcutAvailable };
+export { keyCombo };
+export { keyHold };
+export { keyHoldAsync };
 export { keyTap };
 export { keyToggle };
 export { mouseClick };
 export { mouseToggle };
 export { moveMouse };
+export { moveMouseAsync };
+export { moveMouseBezier };
+export { moveMouseRelative };
 export { moveMouseSmooth };
+export { moveMouseSmoothAsync };
+export { moveMouseSmoothRelative };
+export { ocrClipboardImage };
+export { ocrRegions };
+export { playMacro };
+export { registerGlobalShortcut };
+export { requestAccessibilityPermission };
+export { requestScreenRecordingPermission };
+export { scrollLines };
 export { scrollMouse };
+export { scrollSmooth };
 export { setClipboard };
+export { setClipboardHtml };
 export { setClipboardImage };
+export { setClipboardRtf };
 export { setKeyboardDelay };
 export { setMouseDelay };
+export { startCaptureStream };
+export { startRecordingMacro };
+export { stopCaptureStream };
+export { stopRecordingMacro };
 export { typeString };
+export { typeStringAsync };
 export { typeStringDelayed };
+export { typeStringDelayedAsync };
+export { typeStringHumanized };
 export { unicodeTap };
+export { unregisterGlobalShortcut };
 export { updateScreenMetrics };
+export { waitForPixelColor };
+export { watchClipboard };
@@ -5,9 +5,14 @@
 
 extern crate napi_derive;
 
-use aumate::prelude::{AumateError, Keyboard, Mouse, WindowInfo, get_active_window_info};
+use aumate::input::random_bezier_control_points;
+use aumate::prelude::{
+    AumateError, Keyboard, Mouse, MousePosition, WindowInfo, get_active_window_info,
+};
 use napi::bindgen_prelude::*;
+use napi::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
 use napi_derive::napi;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 
 // Global delay settings
@@ -20,8 +25,116 @@ static MOUSE_DELAY: once_cell::sync::Lazy<Arc<Mutex<u32>>> =
 // Type Conversions
 // ============================================================================
 
-fn aumate_to_napi_error(e: AumateError) -> Error {
-    Error::from_reason(e.to_string())
+/// Error code thrown to JavaScript as `err.code`
+///
+/// Aumate errors map to a stable code per error kind. Errors raised by napi
+/// itself keep their status name, such as `InvalidArg`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCode {
+    Input,
+    Screen,
+    Clipboard,
+    Window,
+    NoFocusedWindow,
+    WindowNotFound,
+    PermissionDenied,
+    PlatformUnsupported,
+    Gui,
+    Io,
+    Image,
+    Ml,
+    Generic,
+    Napi(Status),
+}
+
+impl AsRef<str> for ErrorCode {
+    fn as_ref(&self) -> &str {
+        match self {
+            ErrorCode::Input => "INPUT_ERROR",
+            ErrorCode::Screen => "SCREEN_ERROR",
+            ErrorCode::Clipboard => "CLIPBOARD_ERROR",
+            ErrorCode::Window => "WINDOW_ERROR",
+            ErrorCode::NoFocusedWindow => "NO_FOCUSED_WINDOW",
+            ErrorCode::WindowNotFound => "WINDOW_NOT_FOUND",
+            ErrorCode::PermissionDenied => "PERMISSION_DENIED",
+            ErrorCode::PlatformUnsupported => "PLATFORM_UNSUPPORTED",
+            ErrorCode::Gui => "GUI_ERROR",
+            ErrorCode::Io => "IO_ERROR",
+            ErrorCode::Image => "IMAGE_ERROR",
+            ErrorCode::Ml => "ML_ERROR",
+            ErrorCode::Generic => "GENERIC_ERROR",
+            ErrorCode::Napi(status) => status.as_ref(),
+        }
+    }
+}
+
+impl From<&AumateError> for ErrorCode {
+    fn from(e: &AumateError) -> Self {
+        match e {
+            AumateError::Input(_) => ErrorCode::Input,
+            AumateError::Screen(_) | AumateError::Screenshot(_) => ErrorCode::Screen,
+            AumateError::Clipboard(_) => ErrorCode::Clipboard,
+            AumateError::Window(_) => ErrorCode::Window,
+            AumateError::NoFocusedWindow => ErrorCode::NoFocusedWindow,
+            AumateError::WindowNotFound(_) => ErrorCode::WindowNotFound,
+            AumateError::PermissionDenied(_) => ErrorCode::PermissionDenied,
+            AumateError::PlatformUnsupported(_) => ErrorCode::PlatformUnsupported,
+            AumateError::Gui(_) => ErrorCode::Gui,
+            AumateError::Io(_) => ErrorCode::Io,
+            AumateError::Image(_) => ErrorCode::Image,
+            AumateError::Ml(_) => ErrorCode::Ml,
+            AumateError::Other(_) => ErrorCode::Generic,
+        }
+    }
+}
+
+/// Result of a binding; the error's `ErrorCode` becomes `err.code`
+type Result<T> = napi::Result<T, ErrorCode>;
+
+fn aumate_to_napi_error(e: AumateError) -> Error<ErrorCode> {
+    Error::new(ErrorCode::from(&e), e.to_string())
+}
+
+/// Blocking work run on the libuv thread pool
+///
+/// `Task::compute` can only fail with an napi `Status`, so the code of a
+/// failed run is kept here and put back on the error in `reject`.
+pub struct BlockingTask<T> {
+    work: Option<Box<dyn FnOnce() -> aumate::error::Result<T> + Send>>,
+    code: Option<ErrorCode>,
+}
+
+impl<T: ToNapiValue + TypeName + Send + 'static> Task for BlockingTask<T> {
+    type Output = T;
+    type JsValue = T;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        let work = self.work.take().expect("BlockingTask runs once");
+        work().map_err(|e| {
+            self.code = Some(ErrorCode::from(&e));
+            Error::from_reason(e.to_string())
+        })
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+        Ok(output)
+    }
+
+    fn reject(&mut self, env: Env, err: Error) -> napi::Result<Self::JsValue> {
+        let code = self.code.take().unwrap_or(ErrorCode::Napi(err.status));
+        let error = JsError::from(Error::new(code, &err.reason)).into_unknown(env);
+        Err(error.into())
+    }
+}
+
+/// Run blocking work on a worker thread so the event loop stays free
+fn blocking_task<T>(
+    work: impl FnOnce() -> aumate::error::Result<T> + Send + 'static,
+) -> AsyncTask<BlockingTask<T>>
+where
+    T: ToNapiValue + TypeName + Send + 'static,
+{
+    AsyncTask::new(BlockingTask { work: Some(Box::new(work)), code: None })
 }
 
 // ============================================================================
@@ -36,11 +149,74 @@ pub struct ScreenCaptureResult {
     pub image: Buffer,
 }
 
+impl From<aumate::screen::ScreenCapture> for ScreenCaptureResult {
+    fn from(capture: aumate::screen::ScreenCapture) -> Self {
+        Self { width: capture.width, height: capture.height, image: Buffer::from(capture.image) }
+    }
+}
+
 /// Screen size information
+///
+/// `width`/`height` are logical coordinates, the space `move_mouse` expects.
+/// `physical_width`/`physical_height` are device pixels, the space of captures.
+/// The OS reports one pair (logical on macOS/Linux, physical on Windows) and
+/// the other is derived with the scale factor.
 #[napi(object)]
 pub struct ScreenSizeResult {
     pub width: u32,
     pub height: u32,
+    pub physical_width: u32,
+    pub physical_height: u32,
+}
+
+/// Raw frame delivered by a capture stream
+#[napi(object)]
+pub struct CaptureFrameResult {
+    pub width: u32,
+    pub height: u32,
+    /// Raw RGBA pixels, `width * height * 4` bytes
+    pub data: Buffer,
+}
+
+/// Screen region to capture
+#[napi(object)]
+pub struct CaptureRegionInput {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Monitor information
+///
+/// Bounds are positions in the virtual desktop as the OS reports them: logical
+/// points on macOS and Linux, device pixels on Windows. `scale_factor` converts
+/// between the two.
+#[napi(object)]
+pub struct MonitorInfoResult {
+    pub index: u32,
+    pub name: String,
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub scale_factor: f64,
+    pub is_primary: bool,
+}
+
+impl From<aumate::screen::MonitorInfo> for MonitorInfoResult {
+    fn from(info: aumate::screen::MonitorInfo) -> Self {
+        Self {
+            index: info.id,
+            name: info.name,
+            x: info.x,
+            y: info.y,
+            width: info.width,
+            height: info.height,
+            scale_factor: info.scale_factor as f64,
+            is_primary: info.is_primary,
+        }
+    }
 }
 
 /// Pixel color information
@@ -52,6 +228,16 @@ pub struct PixelColorResult {
     pub a: u32,
 }
 
+/// Color in HSV space
+///
+/// `h` is the hue in degrees `[0, 360)`, `s` and `v` are in `[0, 1]`.
+#[napi(object)]
+pub struct HsvResult {
+    pub h: f64,
+    pub s: f64,
+    pub v: f64,
+}
+
 /// Bitmap structure for screen capture (robotjs compatible)
 #[napi(object)]
 pub struct Bitmap {
@@ -70,17 +256,34 @@ pub struct MousePositionResult {
     pub y: i32,
 }
 
+/// Options for Bezier mouse movement
+#[napi(object)]
+pub struct BezierOptions {
+    /// Exactly two control points; randomized for a natural arc when omitted
+    pub control_points: Option<Vec<MousePositionResult>>,
+    /// Number of interpolation steps (default: 50)
+    pub steps: Option<u32>,
+}
+
 /// Window information structure
 #[napi(object)]
 pub struct WindowInfoResult {
     pub title: String,
     pub process_id: u32,
+    /// Path of the process executable, empty if it could not be read
     pub process_path: String,
+    /// Application display name, e.g. "Google Chrome"
+    pub app_name: String,
     pub x: f64,
     pub y: f64,
     pub width: f64,
     pub height: f64,
     pub window_id: String,
+    pub is_minimized: bool,
+    /// Whether the window has a non-zero size and overlaps a monitor
+    pub is_on_screen: bool,
+    /// Stacking position, 0 being the frontmost window
+    pub z_order: u32,
 }
 
 impl From<WindowInfo> for WindowInfoResult {
@@ -89,11 +292,15 @@ impl From<WindowInfo> for WindowInfoResult {
             title: info.title,
             process_id: info.process_id,
             process_path: info.process_path,
+            app_name: info.app_name,
             x: info.x,
             y: info.y,
             width: info.width,
             height: info.height,
             window_id: info.window_id,
+            is_minimized: info.is_minimized,
+            is_on_screen: info.is_on_screen,
+            z_order: info.z_order,
         }
     }
 }
@@ -114,23 +321,24 @@ impl Screen {
     }
 
     /// Capture screen region
-    #[napi]
-    pub async fn capture(
+    #[napi(ts_return_type = "Promise<Bitmap>")]
+    pub fn capture(
         &self,
         x: Option<u32>,
         y: Option<u32>,
         width: Option<u32>,
         height: Option<u32>,
-    ) -> Result<Bitmap> {
-        let capture = aumate::prelude::capture_screen_region(x, y, width, height)
-            .map_err(aumate_to_napi_error)?;
-        Ok(Bitmap {
-            width: capture.width,
-            height: capture.height,
-            image: Buffer::from(capture.image),
-            byte_width: capture.width * 4,
-            bits_per_pixel: 32,
-            bytes_per_pixel: 4,
+    ) -> AsyncTask<BlockingTask<Bitmap>> {
+        blocking_task(move || {
+            let capture = aumate::prelude::capture_screen_region(x, y, width, height)?;
+            Ok(Bitmap {
+                width: capture.width,
+                height: capture.height,
+                image: Buffer::from(capture.image),
+                byte_width: capture.width * 4,
+                bits_per_pixel: 32,
+                bytes_per_pixel: 4,
+            })
         })
     }
 }
@@ -151,7 +359,7 @@ pub fn get_screen() -> Screen {
 #[napi]
 pub fn bitmap_color_at(bitmap: Bitmap, x: u32, y: u32) -> Result<String> {
     if x >= bitmap.width || y >= bitmap.height {
-        return Err(Error::from_reason("Coordinates out of bounds"));
+        return Err(Error::new(ErrorCode::Screen, "Coordinates out of bounds"));
     }
     // Return placeholder - would need to decode PNG buffer
     Ok("#000000".to_string())
@@ -176,6 +384,15 @@ pub fn key_tap(key: String, modifier: Option<Vec<String>>) -> Result<()> {
     keyboard.key_tap(&key, modifier.as_deref()).map_err(aumate_to_napi_error)
 }
 
+/// Tap a key combination written as one string, e.g. "Ctrl+Shift+S"
+///
+/// @param combo - Modifiers and key separated by `+`
+#[napi]
+pub fn key_combo(combo: String) -> Result<()> {
+    let keyboard = Keyboard::new().map_err(aumate_to_napi_error)?;
+    keyboard.key_combo(&combo).map_err(aumate_to_napi_error)
+}
+
 /// Toggle a key
 #[napi]
 pub fn key_toggle(key: String, down: String, modifier: Option<Vec<String>>) -> Result<()> {
@@ -183,6 +400,19 @@ pub fn key_toggle(key: String, down: String, modifier: Option<Vec<String>>) -> R
     keyboard.key_toggle(&key, &down, modifier.as_deref()).map_err(aumate_to_napi_error)
 }
 
+/// Hold a key down for a duration, then release it
+///
+/// @param key - Key to hold
+/// @param durationMs - How long to hold the key in milliseconds
+/// @param modifier - Optional modifier keys held together with the key
+#[napi]
+pub fn key_hold(key: String, duration_ms: u32, modifier: Option<Vec<String>>) -> Result<()> {
+    let keyboard = Keyboard::new().map_err(aumate_to_napi_error)?;
+    keyboard
+        .key_hold(&key, modifier.as_deref(), std::time::Duration::from_millis(duration_ms as u64))
+        .map_err(aumate_to_napi_error)
+}
+
 /// Tap a Unicode character
 #[napi]
 pub fn unicode_tap(value: u32) -> Result<()> {
@@ -190,10 +420,50 @@ pub fn unicode_tap(value: u32) -> Result<()> {
     if let Some(ch) = std::char::from_u32(value) {
         keyboard.unicode_tap(ch).map_err(aumate_to_napi_error)
     } else {
-        Err(Error::from_reason(format!("Invalid Unicode value: {}", value)))
+        Err(Error::new(ErrorCode::Input, format!("Invalid Unicode value: {}", value)))
     }
 }
 
+/// State of the keyboard lock keys
+#[napi(object)]
+pub struct LockStateResult {
+    pub caps: bool,
+    pub num: bool,
+    pub scroll: bool,
+}
+
+/// Modifier keys currently held down
+#[napi(object)]
+pub struct ModifierStateResult {
+    pub shift: bool,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub meta: bool,
+}
+
+/// Get the state of Caps Lock, Num Lock and Scroll Lock
+///
+/// Best effort: macOS always reports Num Lock and Scroll Lock as off.
+#[napi]
+pub fn get_lock_state() -> Result<LockStateResult> {
+    let keyboard = Keyboard::new().map_err(aumate_to_napi_error)?;
+    let state = keyboard.get_lock_state().map_err(aumate_to_napi_error)?;
+    Ok(LockStateResult { caps: state.caps, num: state.num, scroll: state.scroll })
+}
+
+/// Get which modifier keys are currently held down
+#[napi]
+pub fn get_modifier_state() -> Result<ModifierStateResult> {
+    let keyboard = Keyboard::new().map_err(aumate_to_napi_error)?;
+    let state = keyboard.get_modifier_state().map_err(aumate_to_napi_error)?;
+    Ok(ModifierStateResult {
+        shift: state.shift,
+        ctrl: state.ctrl,
+        alt: state.alt,
+        meta: state.meta,
+    })
+}
+
 /// Type a string
 #[napi]
 pub fn type_string(string: String) -> Result<()> {
@@ -208,6 +478,134 @@ pub fn type_string_delayed(string: String, cpm: u32) -> Result<()> {
     keyboard.type_string_delayed(&string, cpm).map_err(aumate_to_napi_error)
 }
 
+/// Type a string with human-like, randomized delays between characters
+///
+/// @param string - Text to type
+/// @param baseCpm - Average typing speed in characters per minute
+/// @param jitterPercent - Maximum random deviation of each delay in percent (0-100)
+/// @param punctuationPause - Pause longer after punctuation and line breaks (default: true)
+#[napi]
+pub fn type_string_humanized(
+    string: String,
+    base_cpm: u32,
+    jitter_percent: u32,
+    punctuation_pause: Option<bool>,
+) -> Result<()> {
+    let keyboard = Keyboard::new().map_err(aumate_to_napi_error)?;
+    keyboard
+        .type_string_humanized(&string, base_cpm, jitter_percent, punctuation_pause.unwrap_or(true))
+        .map_err(aumate_to_napi_error)
+}
+
+// ============================================================================
+// Async Input Operations
+// ============================================================================
+
+/// Token for cancelling a long-running async input operation
+#[napi]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+#[napi]
+impl CancellationToken {
+    #[napi(constructor)]
+    pub fn new() -> Self {
+        Self { cancelled: Arc::new(AtomicBool::new(false)) }
+    }
+
+    /// Request cancellation; the operation stops at its next checkpoint
+    #[napi]
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether `cancel` has been called
+    #[napi(getter)]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+impl Default for CancellationToken {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Type a string on a worker thread
+///
+/// @param string - Text to type
+#[napi(ts_return_type = "Promise<void>")]
+pub fn type_string_async(string: String) -> AsyncTask<BlockingTask<()>> {
+    blocking_task(move || Keyboard::new()?.type_string(&string))
+}
+
+/// Type a string with delay on a worker thread
+///
+/// Resolves to `true` when the whole string was typed, or `false` if `token`
+/// was cancelled first.
+///
+/// @param string - Text to type
+/// @param cpm - Characters per minute
+/// @param token - Optional token to stop typing mid-way
+#[napi(ts_return_type = "Promise<boolean>")]
+pub fn type_string_delayed_async(
+    string: String,
+    cpm: u32,
+    token: Option<&CancellationToken>,
+) -> AsyncTask<BlockingTask<bool>> {
+    let cancelled = token.map(|t| t.cancelled.clone()).unwrap_or_default();
+    blocking_task(move || {
+        Keyboard::new()?.type_string_delayed_cancellable(&string, cpm, &cancelled)
+    })
+}
+
+/// Hold a key down for a duration on a worker thread
+///
+/// @param key - Key to hold
+/// @param durationMs - How long to hold the key in milliseconds
+/// @param modifier - Optional modifier keys held together with the key
+#[napi(ts_return_type = "Promise<void>")]
+pub fn key_hold_async(
+    key: String,
+    duration_ms: u32,
+    modifier: Option<Vec<String>>,
+) -> AsyncTask<BlockingTask<()>> {
+    blocking_task(move || {
+        Keyboard::new()?.key_hold(
+            &key,
+            modifier.as_deref(),
+            std::time::Duration::from_millis(duration_ms as u64),
+        )
+    })
+}
+
+/// Move mouse on a worker thread
+///
+/// @param x - Target X coordinate
+/// @param y - Target Y coordinate
+#[napi(ts_return_type = "Promise<void>")]
+pub fn move_mouse_async(x: i32, y: i32) -> AsyncTask<BlockingTask<()>> {
+    blocking_task(move || Mouse::new()?.move_mouse(x, y))
+}
+
+/// Move mouse smoothly on a worker thread
+///
+/// @param x - Target X coordinate
+/// @param y - Target Y coordinate
+/// @param speed - Optional speed multiplier
+#[napi(ts_return_type = "Promise<void>")]
+pub fn move_mouse_smooth_async(x: i32, y: i32, speed: Option<f64>) -> AsyncTask<BlockingTask<()>> {
+    blocking_task(move || {
+        let mouse = Mouse::new()?;
+        match speed {
+            Some(s) => mouse.move_mouse_smooth_with_speed(x, y, s),
+            None => mouse.move_mouse_smooth(x, y),
+        }
+    })
+}
+
 // ============================================================================
 // Mouse Operations
 // ============================================================================
@@ -227,6 +625,8 @@ pub fn update_screen_metrics() -> Result<()> {
 }
 
 /// Move mouse
+///
+/// Coordinates are logical, matching `width`/`height` from `getScreenSize`.
 #[napi]
 pub fn move_mouse(x: i32, y: i32) -> Result<()> {
     let mouse = Mouse::new().map_err(aumate_to_napi_error)?;
@@ -244,6 +644,64 @@ pub fn move_mouse_smooth(x: i32, y: i32, speed: Option<f64>) -> Result<()> {
     }
 }
 
+/// Move mouse along a cubic Bezier curve
+///
+/// @param x - Target X coordinate
+/// @param y - Target Y coordinate
+/// @param options - Optional control points and step count
+#[napi]
+pub fn move_mouse_bezier(x: i32, y: i32, options: Option<BezierOptions>) -> Result<()> {
+    let mouse = Mouse::new().map_err(aumate_to_napi_error)?;
+    if let Ok(delay) = MOUSE_DELAY.lock() {
+        mouse.set_delay(*delay).map_err(aumate_to_napi_error)?;
+    }
+
+    let (control_points, steps) =
+        options.map(|o| (o.control_points, o.steps)).unwrap_or((None, None));
+    let control_points = match control_points {
+        Some(points) => match points.as_slice() {
+            [c1, c2] => [MousePosition { x: c1.x, y: c1.y }, MousePosition { x: c2.x, y: c2.y }],
+            _ => {
+                return Err(Error::new(
+                    ErrorCode::Input,
+                    format!("Expected 2 control points, got {}", points.len()),
+                ));
+            }
+        },
+        None => {
+            let start = mouse.get_mouse_pos().map_err(aumate_to_napi_error)?;
+            random_bezier_control_points(start, MousePosition { x, y })
+        }
+    };
+
+    mouse.move_mouse_bezier(x, y, control_points, steps.unwrap_or(50)).map_err(aumate_to_napi_error)
+}
+
+/// Move mouse relative to its current position
+///
+/// @param dx - Horizontal offset in pixels
+/// @param dy - Vertical offset in pixels
+#[napi]
+pub fn move_mouse_relative(dx: i32, dy: i32) -> Result<()> {
+    let mouse = Mouse::new().map_err(aumate_to_napi_error)?;
+    mouse.move_mouse_relative(dx, dy).map_err(aumate_to_napi_error)
+}
+
+/// Move mouse smoothly relative to its current position
+///
+/// @param dx - Horizontal offset in pixels
+/// @param dy - Vertical offset in pixels
+/// @param speed - Optional speed multiplier
+#[napi]
+pub fn move_mouse_smooth_relative(dx: i32, dy: i32, speed: Option<f64>) -> Result<()> {
+    let mouse = Mouse::new().map_err(aumate_to_napi_error)?;
+    if let Some(s) = speed {
+        mouse.move_mouse_smooth_relative_with_speed(dx, dy, s).map_err(aumate_to_napi_error)
+    } else {
+        mouse.move_mouse_smooth_relative(dx, dy).map_err(aumate_to_napi_error)
+    }
+}
+
 /// Mouse click
 #[napi]
 pub fn mouse_click(button: Option<String>, double: Option<bool>) -> Result<()> {
@@ -273,6 +731,29 @@ pub fn scroll_mouse(x: i32, y: i32) -> Result<()> {
     mouse.scroll_mouse(x, y).map_err(aumate_to_napi_error)
 }
 
+/// Scroll smoothly, spreading one-notch wheel events over a duration
+///
+/// @param dx - Horizontal notches (positive scrolls right)
+/// @param dy - Vertical notches (positive scrolls down)
+/// @param durationMs - Total scroll duration in milliseconds (default: 300)
+#[napi]
+pub fn scroll_smooth(dx: i32, dy: i32, duration_ms: Option<u32>) -> Result<()> {
+    let mouse = Mouse::new().map_err(aumate_to_napi_error)?;
+    let duration = std::time::Duration::from_millis(duration_ms.unwrap_or(300) as u64);
+    mouse.scroll_smooth(dx, dy, duration).map_err(aumate_to_napi_error)
+}
+
+/// Scroll a number of lines in a direction
+///
+/// @param direction - "up", "down", "left" or "right"
+/// @param count - Number of lines (default: 1)
+#[napi]
+pub fn scroll_lines(direction: String, count: Option<u32>) -> Result<()> {
+    let direction = direction.parse().map_err(aumate_to_napi_error)?;
+    let mouse = Mouse::new().map_err(aumate_to_napi_error)?;
+    mouse.scroll_lines(direction, count.unwrap_or(1)).map_err(aumate_to_napi_error)
+}
+
 /// Get mouse position
 #[napi]
 pub fn get_mouse_pos() -> Result<MousePositionResult> {
@@ -286,48 +767,235 @@ pub fn get_mouse_pos() -> Result<MousePositionResult> {
 // ============================================================================
 
 /// Get pixel color (returns hex string)
+#[napi(ts_return_type = "Promise<string>")]
+pub fn get_pixel_color(x: u32, y: u32) -> AsyncTask<BlockingTask<String>> {
+    blocking_task(move || {
+        let color = aumate::screen::get_pixel_color(x, y)?;
+        Ok(format!("#{:02x}{:02x}{:02x}", color.r, color.g, color.b))
+    })
+}
+
+/// Convert a hex color to HSV
+///
+/// @param hex - Color as hex string (e.g. "#FF0000")
 #[napi]
-pub async fn get_pixel_color(x: u32, y: u32) -> Result<String> {
-    let color = aumate::screen::get_pixel_color(x, y).map_err(aumate_to_napi_error)?;
-    Ok(format!("#{:02x}{:02x}{:02x}", color.r, color.g, color.b))
+pub fn color_to_hsv(hex: String) -> Result<HsvResult> {
+    let hsv = aumate::screen::PixelColor::from_hex(&hex).map_err(aumate_to_napi_error)?.to_hsv();
+    Ok(HsvResult { h: hsv.h, s: hsv.s, v: hsv.v })
+}
+
+/// Perceptual distance between two hex colors (CIE76 delta E)
+///
+/// About 2.3 is a just-noticeable difference; black to white is 100.
+///
+/// @param hexA - First color as hex string
+/// @param hexB - Second color as hex string
+#[napi]
+pub fn color_distance(hex_a: String, hex_b: String) -> Result<f64> {
+    let a = aumate::screen::PixelColor::from_hex(&hex_a).map_err(aumate_to_napi_error)?;
+    let b = aumate::screen::PixelColor::from_hex(&hex_b).map_err(aumate_to_napi_error)?;
+    Ok(a.distance(&b))
+}
+
+/// Get the average color of a screen region (returns hex string)
+///
+/// @param x - X coordinate of the region's top-left corner
+/// @param y - Y coordinate of the region's top-left corner
+/// @param width - Region width in pixels
+/// @param height - Region height in pixels
+#[napi(ts_return_type = "Promise<string>")]
+pub fn get_region_average_color(
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+) -> AsyncTask<BlockingTask<String>> {
+    blocking_task(move || {
+        let color = aumate::screen::get_region_average_color(x, y, width, height)?;
+        Ok(format!("#{:02x}{:02x}{:02x}", color.r, color.g, color.b))
+    })
+}
+
+/// Get the dominant color of a screen region (returns hex string)
+///
+/// @param x - X coordinate of the region's top-left corner
+/// @param y - Y coordinate of the region's top-left corner
+/// @param width - Region width in pixels
+/// @param height - Region height in pixels
+#[napi(ts_return_type = "Promise<string>")]
+pub fn get_region_dominant_color(
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+) -> AsyncTask<BlockingTask<String>> {
+    blocking_task(move || {
+        let color = aumate::screen::get_region_dominant_color(x, y, width, height)?;
+        Ok(format!("#{:02x}{:02x}{:02x}", color.r, color.g, color.b))
+    })
+}
+
+/// Wait until a pixel matches a color
+///
+/// Resolves to `true` once the pixel matches, or `false` when the timeout
+/// elapses.
+///
+/// @param x - X coordinate
+/// @param y - Y coordinate
+/// @param color - Target color as hex string (e.g. "#FF0000")
+/// @param timeoutMs - Maximum time to wait in milliseconds
+/// @param tolerance - Maximum per-channel difference (default: 0)
+/// @param pollIntervalMs - Delay between checks in milliseconds (default: 100)
+#[napi(ts_return_type = "Promise<boolean>")]
+pub fn wait_for_pixel_color(
+    x: u32,
+    y: u32,
+    color: String,
+    timeout_ms: u32,
+    tolerance: Option<u8>,
+    poll_interval_ms: Option<u32>,
+) -> AsyncTask<BlockingTask<bool>> {
+    let timeout = std::time::Duration::from_millis(timeout_ms as u64);
+    let poll_interval = std::time::Duration::from_millis(poll_interval_ms.unwrap_or(100) as u64);
+
+    blocking_task(move || {
+        let target = aumate::screen::PixelColor::from_hex(&color)?;
+        aumate::screen::wait_for_pixel_color(
+            x,
+            y,
+            target,
+            tolerance.unwrap_or(0),
+            timeout,
+            poll_interval,
+        )
+    })
 }
 
 /// Get screen size
 #[napi]
 pub fn get_screen_size() -> Result<ScreenSizeResult> {
     let size = aumate::screen::get_screen_size().map_err(aumate_to_napi_error)?;
-    Ok(ScreenSizeResult { width: size.width, height: size.height })
+    Ok(ScreenSizeResult {
+        width: size.width,
+        height: size.height,
+        physical_width: size.physical_width,
+        physical_height: size.physical_height,
+    })
 }
 
-/// Capture entire screen
+/// Get the scale factor of the primary screen
+///
+/// Ratio between physical pixels and logical coordinates (e.g. 2.0 on Retina).
 #[napi]
-pub async fn capture_screen() -> Result<ScreenCaptureResult> {
-    let capture = aumate::screen::capture_screen().map_err(aumate_to_napi_error)?;
-    Ok(ScreenCaptureResult {
-        width: capture.width,
-        height: capture.height,
-        image: Buffer::from(capture.image),
-    })
+pub fn get_screen_scale_factor() -> Result<f64> {
+    aumate::screen::get_screen_scale_factor().map(f64::from).map_err(aumate_to_napi_error)
 }
 
-/// Capture screen region
+/// Get all monitors
 #[napi]
-pub async fn capture_screen_region(
+pub fn get_monitors() -> Result<Vec<MonitorInfoResult>> {
+    let monitors = aumate::screen::get_monitors().map_err(aumate_to_napi_error)?;
+    Ok(monitors.into_iter().map(Into::into).collect())
+}
+
+/// Get the primary monitor
+#[napi]
+pub fn get_primary_monitor() -> Result<MonitorInfoResult> {
+    aumate::screen::get_primary_monitor().map(Into::into).map_err(aumate_to_napi_error)
+}
+
+/// Capture entire screen
+#[napi(ts_return_type = "Promise<ScreenCaptureResult>")]
+pub fn capture_screen() -> AsyncTask<BlockingTask<ScreenCaptureResult>> {
+    blocking_task(|| aumate::screen::capture_screen().map(Into::into))
+}
+
+/// Capture screen region
+#[napi(ts_return_type = "Promise<ScreenCaptureResult>")]
+pub fn capture_screen_region(
     x: u32,
     y: u32,
     width: u32,
     height: u32,
-) -> Result<ScreenCaptureResult> {
-    let capture =
+) -> AsyncTask<BlockingTask<ScreenCaptureResult>> {
+    blocking_task(move || {
         aumate::screen::capture_screen_region(Some(x), Some(y), Some(width), Some(height))
-            .map_err(aumate_to_napi_error)?;
-    Ok(ScreenCaptureResult {
-        width: capture.width,
-        height: capture.height,
-        image: Buffer::from(capture.image),
+            .map(Into::into)
     })
 }
 
+/// Capture the contents of a single window, even when partly covered
+///
+/// Falls back to capturing the window's bounds on the primary monitor where
+/// the platform cannot capture windows directly.
+///
+/// @param windowId - `windowId` of a window from `getAllWindows`
+#[napi(ts_return_type = "Promise<ScreenCaptureResult>")]
+pub fn capture_window(window_id: String) -> AsyncTask<BlockingTask<ScreenCaptureResult>> {
+    blocking_task(move || aumate::screen::capture_window(&window_id).map(Into::into))
+}
+
+static CAPTURE_STREAM: Mutex<Option<aumate::screen::CaptureStream>> = Mutex::new(None);
+
+/// Start streaming raw screen frames to `callback`
+///
+/// Frames that arrive while earlier ones are still queued for JavaScript are
+/// dropped, so a slow callback lowers the effective frame rate instead of
+/// building up a backlog.
+///
+/// @param fps - Target frames per second
+/// @param region - Region to capture (default: entire screen)
+/// @param callback - Called with each raw RGBA frame
+#[napi]
+pub fn start_capture_stream(
+    fps: u32,
+    region: Option<CaptureRegionInput>,
+    callback: ThreadsafeFunction<
+        CaptureFrameResult,
+        (),
+        CaptureFrameResult,
+        Status,
+        false,
+        false,
+        2,
+    >,
+) -> Result<()> {
+    let mut stream = CAPTURE_STREAM
+        .lock()
+        .map_err(|e| Error::new(ErrorCode::Screen, format!("Lock error: {}", e)))?;
+    if stream.is_some() {
+        return Err(Error::new(ErrorCode::Screen, "A capture stream is already running"));
+    }
+
+    let region = region.map(|r| (r.x, r.y, r.width, r.height));
+    *stream = Some(
+        aumate::screen::capture_stream(fps, region, move |frame| {
+            // With a bounded queue a non-blocking call fails when JavaScript is
+            // behind; that frame is simply dropped.
+            callback.call(
+                CaptureFrameResult {
+                    width: frame.width,
+                    height: frame.height,
+                    data: Buffer::from(frame.data),
+                },
+                ThreadsafeFunctionCallMode::NonBlocking,
+            );
+        })
+        .map_err(aumate_to_napi_error)?,
+    );
+    Ok(())
+}
+
+/// Stop the capture stream started by `start_capture_stream`
+#[napi]
+pub fn stop_capture_stream() -> Result<()> {
+    CAPTURE_STREAM
+        .lock()
+        .map_err(|e| Error::new(ErrorCode::Screen, format!("Lock error: {}", e)))?
+        .take();
+    Ok(())
+}
+
 // ============================================================================
 // Clipboard Operations
 // ============================================================================
@@ -344,6 +1012,89 @@ pub fn set_clipboard(text: String) -> Result<()> {
     aumate::clipboard::set_text(&text).map_err(aumate_to_napi_error)
 }
 
+/// Get HTML from clipboard (returns null if no HTML is present)
+#[napi]
+pub fn get_clipboard_html() -> Result<Option<String>> {
+    aumate::clipboard::get_html().map_err(aumate_to_napi_error)
+}
+
+/// Set HTML to clipboard with a plain-text fallback
+///
+/// @param html - HTML content
+/// @param altText - Plain-text fallback (derived from the HTML when omitted)
+#[napi]
+pub fn set_clipboard_html(html: String, alt_text: Option<String>) -> Result<()> {
+    aumate::clipboard::set_html(&html, alt_text.as_deref()).map_err(aumate_to_napi_error)
+}
+
+/// Get RTF from clipboard (returns null if no RTF is present)
+#[napi]
+pub fn get_clipboard_rtf() -> Result<Option<String>> {
+    aumate::clipboard::get_rtf().map_err(aumate_to_napi_error)
+}
+
+/// Set RTF to clipboard with a plain-text fallback
+///
+/// @param rtf - RTF document
+/// @param altText - Plain-text fallback (derived from the RTF when omitted)
+#[napi]
+pub fn set_clipboard_rtf(rtf: String, alt_text: Option<String>) -> Result<()> {
+    aumate::clipboard::set_rtf(&rtf, alt_text.as_deref()).map_err(aumate_to_napi_error)
+}
+
+/// List the content types currently on the clipboard
+///
+/// Returns normalized MIME types such as "text/plain", "text/html", "text/rtf" and
+/// "image/png".
+#[napi]
+pub fn get_clipboard_types() -> Result<Vec<String>> {
+    aumate::clipboard::get_types().map_err(aumate_to_napi_error)
+}
+
+/// Clipboard change event
+#[napi(object)]
+pub struct ClipboardChangeEvent {
+    /// Content type: "text", "html", "image" or "empty"
+    pub content_type: String,
+    /// Plain text of the new content, if any
+    pub text: Option<String>,
+}
+
+/// Handle returned by `watchClipboard`
+#[napi]
+pub struct ClipboardWatcher {
+    inner: Mutex<Option<aumate::clipboard::ClipboardWatcher>>,
+}
+
+#[napi]
+impl ClipboardWatcher {
+    /// Stop watching for clipboard changes
+    #[napi]
+    pub fn stop(&self) {
+        if let Ok(mut inner) = self.inner.lock() {
+            inner.take();
+        }
+    }
+}
+
+/// Watch the clipboard and call `callback` whenever its content changes
+///
+/// @param callback - Called with the new content type and text
+#[napi]
+pub fn watch_clipboard(
+    callback: ThreadsafeFunction<ClipboardChangeEvent, (), ClipboardChangeEvent, Status, false>,
+) -> Result<ClipboardWatcher> {
+    let watcher = aumate::clipboard::watch(move |change| {
+        let event = ClipboardChangeEvent {
+            content_type: change.content_type.as_str().to_string(),
+            text: change.text,
+        };
+        callback.call(event, ThreadsafeFunctionCallMode::NonBlocking);
+    })
+    .map_err(aumate_to_napi_error)?;
+    Ok(ClipboardWatcher { inner: Mutex::new(Some(watcher)) })
+}
+
 /// Get image from clipboard (returns PNG-encoded buffer)
 #[napi]
 pub fn get_clipboard_image() -> Result<Buffer> {
@@ -374,21 +1125,29 @@ pub fn get_active_window() -> Result<WindowInfoResult> {
     Ok(info.into())
 }
 
-/// Get a list of all visible windows
+/// Get a list of all windows, including minimized and off-screen ones
 #[napi]
 pub fn get_all_windows() -> Result<Vec<WindowInfoResult>> {
     let windows = aumate::prelude::get_all_windows().map_err(aumate_to_napi_error)?;
     Ok(windows.into_iter().map(|w| w.into()).collect())
 }
 
-/// Find windows by title (case-insensitive partial match)
+/// Get windows that are not minimized and at least partly on screen
+#[napi]
+pub fn get_visible_windows() -> Result<Vec<WindowInfoResult>> {
+    let windows = aumate::prelude::get_visible_windows().map_err(aumate_to_napi_error)?;
+    Ok(windows.into_iter().map(|w| w.into()).collect())
+}
+
+/// Find windows by title (case-insensitive partial match), frontmost first
 #[napi]
 pub fn find_windows_by_title(title: String) -> Result<Vec<WindowInfoResult>> {
     let windows = aumate::prelude::find_windows_by_title(&title).map_err(aumate_to_napi_error)?;
     Ok(windows.into_iter().map(|w| w.into()).collect())
 }
 
-/// Find windows by process name (case-insensitive partial match)
+/// Find windows by process name (case-insensitive partial match against the
+/// executable path or app name), frontmost first
 #[napi]
 pub fn find_windows_by_process(process_name: String) -> Result<Vec<WindowInfoResult>> {
     let windows =
@@ -413,6 +1172,10 @@ pub struct MatchConfigJs {
     pub confidence: Option<f64>,
     /// Maximum number of results (default: 100)
     pub limit: Option<u32>,
+    /// Template rotation angles in degrees to search (default: [0])
+    pub angles: Option<Vec<f64>>,
+    /// IoU above which overlapping matches are merged (default: 0.5)
+    pub nms_iou: Option<f64>,
 }
 
 /// Result from image template matching
@@ -430,6 +1193,8 @@ pub struct MatchResultJs {
     pub confidence: f64,
     /// Scale at which match was found
     pub scale: f64,
+    /// Template rotation in degrees at which match was found
+    pub angle: f64,
 }
 
 impl From<aumate::image_match::MatchResult> for MatchResultJs {
@@ -441,6 +1206,7 @@ impl From<aumate::image_match::MatchResult> for MatchResultJs {
             height: r.height,
             confidence: r.confidence as f64,
             scale: r.scale as f64,
+            angle: r.angle as f64,
         }
     }
 }
@@ -464,6 +1230,12 @@ fn config_js_to_match_config(config: Option<MatchConfigJs>) -> aumate::image_mat
             if let Some(v) = c.limit {
                 mc.limit = v as usize;
             }
+            if let Some(v) = c.angles {
+                mc.angles = v.into_iter().map(|a| a as f32).collect();
+            }
+            if let Some(v) = c.nms_iou {
+                mc.nms_iou = v as f32;
+            }
             mc
         }
         None => aumate::image_match::MatchConfig::default(),
@@ -475,20 +1247,17 @@ fn config_js_to_match_config(config: Option<MatchConfigJs>) -> aumate::image_mat
 /// @param template - PNG-encoded image buffer of the template to find
 /// @param config - Optional matching configuration
 /// @returns Match result or null if not found
-#[napi]
-pub async fn find_on_screen(
+#[napi(ts_return_type = "Promise<MatchResultJs | null>")]
+pub fn find_on_screen(
     template: Buffer,
     config: Option<MatchConfigJs>,
-) -> Result<Option<MatchResultJs>> {
-    let template_image = image::load_from_memory(&template)
-        .map_err(|e| Error::from_reason(format!("Failed to decode template image: {}", e)))?;
-
+) -> AsyncTask<BlockingTask<Option<MatchResultJs>>> {
     let mc = config_js_to_match_config(config);
-
-    let result = aumate::image_match::find_on_screen(&template_image, Some(mc))
-        .map_err(aumate_to_napi_error)?;
-
-    Ok(result.map(|r| r.into()))
+    blocking_task(move || {
+        let template_image = decode_image(&template)?;
+        let result = aumate::image_match::find_on_screen(&template_image, Some(mc))?;
+        Ok(result.map(|r| r.into()))
+    })
 }
 
 /// Find all matches of template image on screen
@@ -496,20 +1265,17 @@ pub async fn find_on_screen(
 /// @param template - PNG-encoded image buffer of the template to find
 /// @param config - Optional matching configuration
 /// @returns Array of match results sorted by confidence
-#[napi]
-pub async fn find_all_on_screen(
+#[napi(ts_return_type = "Promise<Array<MatchResultJs>>")]
+pub fn find_all_on_screen(
     template: Buffer,
     config: Option<MatchConfigJs>,
-) -> Result<Vec<MatchResultJs>> {
-    let template_image = image::load_from_memory(&template)
-        .map_err(|e| Error::from_reason(format!("Failed to decode template image: {}", e)))?;
-
+) -> AsyncTask<BlockingTask<Vec<MatchResultJs>>> {
     let mc = config_js_to_match_config(config);
-
-    let results = aumate::image_match::find_all_on_screen(&template_image, Some(mc))
-        .map_err(aumate_to_napi_error)?;
-
-    Ok(results.into_iter().map(|r| r.into()).collect())
+    blocking_task(move || {
+        let template_image = decode_image(&template)?;
+        let results = aumate::image_match::find_all_on_screen(&template_image, Some(mc))?;
+        Ok(results.into_iter().map(|r| r.into()).collect())
+    })
 }
 
 /// Find first match of template image in a screen region
@@ -521,25 +1287,22 @@ pub async fn find_all_on_screen(
 /// @param height - Height of search region
 /// @param config - Optional matching configuration
 /// @returns Match result or null if not found (coordinates are absolute screen coordinates)
-#[napi]
-pub async fn find_in_region(
+#[napi(ts_return_type = "Promise<MatchResultJs | null>")]
+pub fn find_in_region(
     template: Buffer,
     x: u32,
     y: u32,
     width: u32,
     height: u32,
     config: Option<MatchConfigJs>,
-) -> Result<Option<MatchResultJs>> {
-    let template_image = image::load_from_memory(&template)
-        .map_err(|e| Error::from_reason(format!("Failed to decode template image: {}", e)))?;
-
+) -> AsyncTask<BlockingTask<Option<MatchResultJs>>> {
     let mc = config_js_to_match_config(config);
-
-    let result =
-        aumate::image_match::find_in_region(&template_image, x, y, width, height, Some(mc))
-            .map_err(aumate_to_napi_error)?;
-
-    Ok(result.map(|r| r.into()))
+    blocking_task(move || {
+        let template_image = decode_image(&template)?;
+        let result =
+            aumate::image_match::find_in_region(&template_image, x, y, width, height, Some(mc))?;
+        Ok(result.map(|r| r.into()))
+    })
 }
 
 /// Find all matches of template image in a screen region
@@ -551,23 +1314,342 @@ pub async fn find_in_region(
 /// @param height - Height of search region
 /// @param config - Optional matching configuration
 /// @returns Array of match results (coordinates are absolute screen coordinates)
-#[napi]
-pub async fn find_all_in_region(
+#[napi(ts_return_type = "Promise<Array<MatchResultJs>>")]
+pub fn find_all_in_region(
     template: Buffer,
     x: u32,
     y: u32,
     width: u32,
     height: u32,
     config: Option<MatchConfigJs>,
-) -> Result<Vec<MatchResultJs>> {
-    let template_image = image::load_from_memory(&template)
-        .map_err(|e| Error::from_reason(format!("Failed to decode template image: {}", e)))?;
-
+) -> AsyncTask<BlockingTask<Vec<MatchResultJs>>> {
     let mc = config_js_to_match_config(config);
+    blocking_task(move || {
+        let template_image = decode_image(&template)?;
+        let results = aumate::image_match::find_all_in_region(
+            &template_image,
+            x,
+            y,
+            width,
+            height,
+            Some(mc),
+        )?;
+        Ok(results.into_iter().map(|r| r.into()).collect())
+    })
+}
+
+/// Find first match of template image in another image instead of the live screen
+///
+/// @param haystackPng - PNG-encoded image buffer to search in
+/// @param templatePng - PNG-encoded image buffer of the template to find
+/// @param confidence - Minimum confidence threshold 0.0-1.0 (default: 0.8)
+/// @returns Match result or null if not found (coordinates are relative to the haystack)
+#[napi(ts_return_type = "Promise<MatchResultJs | null>")]
+pub fn find_image_in_buffer(
+    haystack_png: Buffer,
+    template_png: Buffer,
+    confidence: Option<f64>,
+) -> AsyncTask<BlockingTask<Option<MatchResultJs>>> {
+    let mut mc = aumate::image_match::MatchConfig::default();
+    if let Some(v) = confidence {
+        mc.confidence = v as f32;
+    }
+
+    blocking_task(move || {
+        let haystack_image = decode_image(&haystack_png)?;
+        let template_image = decode_image(&template_png)?;
+        let result =
+            aumate::image_match::find_in_image(&haystack_image, &template_image, Some(mc))?;
+        Ok(result.map(|r| r.into()))
+    })
+}
+
+/// Decode an encoded image buffer
+fn decode_image(buffer: &[u8]) -> aumate::error::Result<image::DynamicImage> {
+    image::load_from_memory(buffer).map_err(AumateError::Image)
+}
+
+// ============================================================================
+// Macro Recording
+// ============================================================================
+
+static MACRO_RECORDER: Mutex<Option<aumate::macros::Recorder>> = Mutex::new(None);
+
+/// Start recording global keyboard and mouse input
+#[napi]
+pub fn start_recording_macro() -> Result<()> {
+    let mut recorder = MACRO_RECORDER
+        .lock()
+        .map_err(|e| Error::new(ErrorCode::Input, format!("Lock error: {}", e)))?;
+    if recorder.is_some() {
+        return Err(Error::new(ErrorCode::Input, "A macro is already being recorded"));
+    }
+    *recorder = Some(aumate::macros::Recorder::start().map_err(aumate_to_napi_error)?);
+    Ok(())
+}
+
+/// Stop recording and return the serialized recording
+#[napi]
+pub fn stop_recording_macro() -> Result<Buffer> {
+    let recorder = MACRO_RECORDER
+        .lock()
+        .map_err(|e| Error::new(ErrorCode::Input, format!("Lock error: {}", e)))?
+        .take()
+        .ok_or_else(|| Error::new(ErrorCode::Input, "No macro is being recorded"))?;
+    let recording = recorder.stop().map_err(aumate_to_napi_error)?;
+    Ok(Buffer::from(recording.to_bytes().map_err(aumate_to_napi_error)?))
+}
+
+/// Replay a recording returned by `stopRecordingMacro` on a worker thread
+///
+/// Resolves once playback finishes or `token` is cancelled.
+///
+/// @param buffer - Serialized recording
+/// @param speed - Playback speed multiplier (default: 1.0)
+/// @param token - Optional token to stop playback mid-way
+#[napi(ts_return_type = "Promise<void>")]
+pub fn play_macro(
+    buffer: Buffer,
+    speed: Option<f64>,
+    token: Option<&CancellationToken>,
+) -> AsyncTask<BlockingTask<()>> {
+    let buffer = buffer.to_vec();
+    let cancelled = token.map(|t| t.cancelled.clone()).unwrap_or_default();
+    blocking_task(move || {
+        let recording = aumate::macros::Recording::from_bytes(&buffer)?;
+        let player = aumate::macros::Player::new()?;
+        player.play_cancellable(&recording, speed.unwrap_or(1.0), &cancelled).map(|_| ())
+    })
+}
+
+// ============================================================================
+// Global Shortcuts
+// ============================================================================
+
+/// Register a global shortcut
+///
+/// Shortcuts are observed passively: the keystroke still reaches the focused
+/// application. They cannot be registered while a macro is being recorded.
+///
+/// @param accelerator - Combo such as "Ctrl+Shift+A" or "F3"
+/// @param callback - Called each time the shortcut is pressed
+#[napi]
+pub fn register_global_shortcut(
+    accelerator: String,
+    callback: ThreadsafeFunction<(), (), (), Status, false>,
+) -> Result<()> {
+    aumate::input::register_hotkey(&accelerator, move || {
+        callback.call((), ThreadsafeFunctionCallMode::NonBlocking);
+    })
+    .map_err(aumate_to_napi_error)
+}
+
+/// Unregister a global shortcut
+///
+/// @param accelerator - Combo passed to `registerGlobalShortcut`
+/// @returns true if the shortcut was registered
+#[napi]
+pub fn unregister_global_shortcut(accelerator: String) -> Result<bool> {
+    aumate::input::unregister_hotkey(&accelerator).map_err(aumate_to_napi_error)
+}
+
+/// Check whether a shortcut is valid and not yet registered by this process
+///
+/// Shortcuts used by other applications are not detected.
+///
+/// @param accelerator - Combo such as "Ctrl+Shift+A" or "F3"
+#[napi]
+pub fn is_shortcut_available(accelerator: String) -> Result<bool> {
+    aumate::input::is_hotkey_available(&accelerator).map_err(aumate_to_napi_error)
+}
+
+// ============================================================================
+// Permissions
+// ============================================================================
+
+/// Permissions granted to the current process
+#[napi(object)]
+pub struct PermissionStatusResult {
+    /// Needed for input simulation and global shortcuts
+    pub accessibility: bool,
+    /// Needed for screen capture
+    pub screen_recording: bool,
+    pub microphone: bool,
+}
+
+/// Check which system permissions the current process has
+///
+/// Only macOS gates these; other platforms report everything as granted.
+#[napi]
+pub fn check_permissions() -> PermissionStatusResult {
+    let status = aumate::permissions::check_permissions();
+    PermissionStatusResult {
+        accessibility: status.accessibility,
+        screen_recording: status.screen_recording,
+        microphone: status.microphone,
+    }
+}
+
+/// Prompt the user to grant Accessibility access
+///
+/// @returns true if access is already granted
+#[napi]
+pub fn request_accessibility_permission() -> bool {
+    aumate::permissions::request_accessibility_permission()
+}
+
+/// Prompt the user to grant Screen Recording access
+///
+/// @returns true if access is already granted
+#[napi]
+pub fn request_screen_recording_permission() -> bool {
+    aumate::permissions::request_screen_recording_permission()
+}
+
+// ============================================================================
+// OCR
+// ============================================================================
+
+/// Shared OCR engine, loaded on first use and kept warm between calls
+static OCR_ENGINE: once_cell::sync::Lazy<Mutex<Option<aumate::ocr::OcrEngine>>> =
+    once_cell::sync::Lazy::new(|| Mutex::new(None));
+
+/// Screen region to recognize text in, in capture (physical) pixels
+#[napi(object)]
+pub struct OcrRegionInput {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Image preprocessing applied to each text region before recognition
+#[napi(object)]
+pub struct OcrPreprocessInput {
+    /// Upscale regions shorter than 32px by 2x (default true)
+    pub upscale: Option<bool>,
+    /// Convert to grayscale (default true)
+    pub grayscale: Option<bool>,
+    /// Straighten text rotated by up to 10 degrees (default false)
+    pub deskew: Option<bool>,
+    /// Convert to black text on white (default false)
+    pub binarize: Option<bool>,
+}
+
+impl From<OcrPreprocessInput> for aumate::ocr::OcrPreprocess {
+    fn from(input: OcrPreprocessInput) -> Self {
+        let defaults = Self::default();
+        Self {
+            upscale: input.upscale.unwrap_or(defaults.upscale),
+            grayscale: input.grayscale.unwrap_or(defaults.grayscale),
+            deskew: input.deskew.unwrap_or(defaults.deskew),
+            binarize: input.binarize.unwrap_or(defaults.binarize),
+        }
+    }
+}
 
-    let results =
-        aumate::image_match::find_all_in_region(&template_image, x, y, width, height, Some(mc))
-            .map_err(aumate_to_napi_error)?;
+/// Load the default OCR model, downloading it on first use
+fn load_ocr_engine() -> aumate::error::Result<aumate::ocr::OcrEngine> {
+    use aumate::ocr::{ModelManager, ModelType, OcrEngine, OcrModelVariant};
+
+    let manager = ModelManager::new()?;
+    let model_id = OcrModelVariant::default().model_id();
+    let model_path = match manager.get_model_path(ModelType::TrOCR, model_id) {
+        Some(path) => path,
+        None => manager.download_model_sync(ModelType::TrOCR, model_id, None)?,
+    };
+
+    let mut engine = OcrEngine::new();
+    engine.load_model(&model_path)?;
+    Ok(engine)
+}
 
-    Ok(results.into_iter().map(|r| r.into()).collect())
+/// Run a closure with the shared OCR engine, loading it if needed
+///
+/// Loading may download the model and recognition is slow, so call this from
+/// a worker thread via `blocking_task`.
+fn with_ocr_engine<T>(
+    f: impl FnOnce(&mut aumate::ocr::OcrEngine) -> aumate::error::Result<T>,
+) -> aumate::error::Result<T> {
+    let mut engine =
+        OCR_ENGINE.lock().map_err(|e| AumateError::Other(format!("Lock error: {}", e)))?;
+    if engine.is_none() {
+        *engine = Some(load_ocr_engine()?);
+    }
+    let engine = engine.as_mut().expect("OCR engine is loaded");
+    f(engine)
+}
+
+/// Recognize text in multiple screen regions
+///
+/// The screen is captured once, each region is cropped from that capture and
+/// all crops are recognized in one batch by a shared OCR engine. The default
+/// TrOCR model is downloaded on first use. Runs on a worker thread.
+///
+/// Regions are in capture (physical) pixels, not the logical coordinates
+/// mouse functions use; multiply logical values by the screen scale factor.
+///
+/// @param regions - Screen regions to recognize
+/// @param preprocess - Image preprocessing before recognition
+/// @returns Recognized text for each region, in input order
+#[napi(ts_return_type = "Promise<Array<string>>")]
+pub fn ocr_regions(
+    regions: Vec<OcrRegionInput>,
+    preprocess: Option<OcrPreprocessInput>,
+) -> AsyncTask<BlockingTask<Vec<String>>> {
+    let preprocess = preprocess.map(Into::into).unwrap_or_default();
+    blocking_task(move || {
+        let capture = aumate::screen::capture_screen()?;
+        let screen = image::load_from_memory(&capture.image).map_err(AumateError::Image)?;
+
+        let mut crops = Vec::with_capacity(regions.len());
+        for region in &regions {
+            if !region_in_bounds(region, capture.width, capture.height) {
+                return Err(AumateError::Screen(format!(
+                    "Region out of bounds: ({}, {}, {}x{}) for screen size {}x{}",
+                    region.x, region.y, region.width, region.height, capture.width, capture.height
+                )));
+            }
+            crops.push(screen.crop_imm(region.x, region.y, region.width, region.height));
+        }
+
+        let results = with_ocr_engine(|engine| {
+            engine.set_preprocess(preprocess);
+            engine.transcribe_batch(&crops)
+        })?;
+        Ok(results.into_iter().map(|r| r.text).collect())
+    })
+}
+
+/// Whether a non-empty region fits inside a capture, without overflowing
+fn region_in_bounds(region: &OcrRegionInput, width: u32, height: u32) -> bool {
+    region.width > 0
+        && region.height > 0
+        && region.x as u64 + region.width as u64 <= width as u64
+        && region.y as u64 + region.height as u64 <= height as u64
+}
+
+/// Recognize text in the image currently on the clipboard
+///
+/// Fails if the clipboard does not hold an image. The default TrOCR model is
+/// downloaded on first use. Runs on a worker thread.
+///
+/// @param preprocess - Image preprocessing before recognition
+/// @returns Recognized text
+#[napi(ts_return_type = "Promise<string>")]
+pub fn ocr_clipboard_image(
+    preprocess: Option<OcrPreprocessInput>,
+) -> AsyncTask<BlockingTask<String>> {
+    let preprocess = preprocess.map(Into::into).unwrap_or_default();
+    blocking_task(move || {
+        // Fails with a clipboard error when no image is present
+        let png = aumate::clipboard::get_image()?;
+        let image = image::load_from_memory(&png).map_err(AumateError::Image)?;
+
+        let result = with_ocr_engine(|engine| {
+            engine.set_preprocess(preprocess);
+            engine.recognize(&image)
+        })?;
+        Ok(result.text)
+    })
 }
@@ -0,0 +1,68 @@
+/**
+ * Structured Error Codes
+ *
+ * Errors thrown by @tego/botjs functions carry a stable `code`, so callers can
+ * branch on the kind of failure instead of matching messages.
+ *
+ * @example
+ * ```typescript
+ * import {
+ *   type BotError,
+ *   captureScreen,
+ *   requestScreenRecordingPermission,
+ * } from "@tego/botjs";
+ *
+ * try {
+ *   await captureScreen();
+ * } catch (err) {
+ *   if ((err as BotError).code === "PERMISSION_DENIED") {
+ *     requestScreenRecordingPermission();
+ *   }
+ * }
+ * ```
+ *
+ * @module errors
+ */
+
+/**
+ * Error codes set as `err.code` on errors thrown by @tego/botjs functions
+ *
+ * - `INPUT_ERROR` - Mouse, keyboard, hotkey or macro input failed
+ * - `SCREEN_ERROR` - Screen capture failed or a color was invalid
+ * - `CLIPBOARD_ERROR` - Clipboard access failed
+ * - `WINDOW_ERROR` - Window lookup failed
+ * - `NO_FOCUSED_WINDOW` - No window currently has focus
+ * - `WINDOW_NOT_FOUND` - No window matches the given id
+ * - `PERMISSION_DENIED` - A required macOS permission is missing
+ * - `PLATFORM_UNSUPPORTED` - Not available here, e.g. input hooks on Wayland
+ * - `IO_ERROR` - Reading or writing a file failed
+ * - `IMAGE_ERROR` - Decoding or encoding an image failed
+ * - `ML_ERROR` - OCR model inference failed
+ * - `GUI_ERROR` - GUI operation failed
+ * - `GENERIC_ERROR` - Any other failure
+ *
+ * Errors raised by N-API itself, such as invalid arguments, keep N-API's
+ * status name as `code`, e.g. `"InvalidArg"`.
+ */
+export type BotErrorCode =
+  | "INPUT_ERROR"
+  | "SCREEN_ERROR"
+  | "CLIPBOARD_ERROR"
+  | "WINDOW_ERROR"
+  | "NO_FOCUSED_WINDOW"
+  | "WINDOW_NOT_FOUND"
+  | "PERMISSION_DENIED"
+  | "PLATFORM_UNSUPPORTED"
+  | "IO_ERROR"
+  | "IMAGE_ERROR"
+  | "ML_ERROR"
+  | "GUI_ERROR"
+  | "GENERIC_ERROR";
+
+/**
+ * Error thrown by @tego/botjs functions
+ */
+export interface BotError extends Error {
+  /** One of {@link BotErrorCode}, or an N-API status name */
+  code: string;
+}
@@ -67,6 +67,20 @@ export interface MatchConfig {
    * @default 100
    */
   limit?: number;
+
+  /**
+   * Template rotation angles in degrees to search.
+   * Each angle multiplies the number of matching passes, so keep the list short.
+   * @default [0]
+   */
+  angles?: number[];
+
+  /**
+   * Overlap (intersection over union) above which nearby matches are merged,
+   * keeping only the highest-confidence one.
+   * @default 0.5
+   */
+  nmsIou?: number;
 }
 
 /**
@@ -77,14 +91,16 @@ export interface MatchResult {
   x: number;
   /** Y coordinate of the match (top-left corner) */
   y: number;
-  /** Width of the matched region */
+  /** Width of the matched region, covering the whole rotated template */
   width: number;
-  /** Height of the matched region */
+  /** Height of the matched region, covering the whole rotated template */
   height: number;
   /** Confidence score from 0.0 to 1.0 (higher = better match) */
   confidence: number;
   /** Scale at which the template was matched (1.0 = original size) */
   scale: number;
+  /** Rotation in degrees at which the template was matched (0 = upright) */
+  angle: number;
 }
 
 /**
@@ -178,6 +194,8 @@ function toMatchConfigJs(config?: MatchConfig): bot.MatchConfigJs | undefined {
     scaleSteps: config.scaleSteps,
     confidence: config.confidence,
     limit: config.limit,
+    angles: config.angles,
+    nmsIou: config.nmsIou,
   };
 }
 
@@ -189,6 +207,7 @@ function fromMatchResultJs(result: bot.MatchResultJs): MatchResult {
     height: result.height,
     confidence: result.confidence,
     scale: result.scale,
+    angle: result.angle,
   };
 }
 
@@ -355,6 +374,42 @@ export async function findAllInRegion(
   return results.map(fromMatchResultJs);
 }
 
+/**
+ * Find first match of template image inside another image instead of the live screen
+ *
+ * Useful for analyzing previously saved captures or testing templates offline.
+ *
+ * @param haystack - ImageResource to search in
+ * @param template - ImageResource to search for
+ * @param confidence - Minimum confidence threshold 0.0-1.0 (default: 0.8)
+ * @returns Promise resolving to MatchResult or null (coordinates are relative to the haystack)
+ *
+ * @example
+ * ```typescript
+ * import { imageResource, findImageInBuffer } from "@tego/botjs";
+ *
+ * const capture = await imageResource("./captures/login.png");
+ * const button = await imageResource("./button.png");
+ * const match = await findImageInBuffer(capture, button, 0.9);
+ *
+ * if (match) {
+ *   console.log(`Button was at (${match.x}, ${match.y}) in the capture`);
+ * }
+ * ```
+ */
+export async function findImageInBuffer(
+  haystack: ImageResource,
+  template: ImageResource,
+  confidence?: number,
+): Promise<MatchResult | null> {
+  const result = await bot.findImageInBuffer(
+    haystack.buffer,
+    template.buffer,
+    confidence,
+  );
+  return result ? fromMatchResultJs(result) : null;
+}
+
 // ============================================================================
 // Utility Functions
 // ============================================================================
@@ -9,8 +9,20 @@ import * as bot from "@tego/bot";
 // ============================================================================
 
 export type {
+  BezierOptions,
   Bitmap,
+  CaptureFrameResult as CaptureFrame,
+  CaptureRegionInput as CaptureRegion,
+  ClipboardChangeEvent,
+  ClipboardWatcher,
+  HsvResult as Hsv,
+  LockStateResult as LockState,
+  ModifierStateResult as ModifierState,
+  MonitorInfoResult as MonitorInfo,
   MousePositionResult as MousePosition,
+  OcrPreprocessInput as OcrPreprocess,
+  OcrRegionInput as OcrRegion,
+  PermissionStatusResult as PermissionStatus,
   ScreenCaptureResult as ScreenCapture,
   ScreenSizeResult as ScreenSize,
   WindowInfoResult as WindowInfo,
@@ -22,6 +34,8 @@ export type {
  */
 export type PixelColor = string;
 
+export type { BotError, BotErrorCode } from "./errors";
+
 // ============================================================================
 // Class Exports
 // ============================================================================
@@ -43,6 +57,23 @@ export type PixelColor = string;
  */
 export const Screen = bot.Screen;
 
+/**
+ * Token for cancelling a long-running async input operation
+ *
+ * Pass it to {@link typeStringDelayedAsync} and call `cancel()` to stop
+ * typing at the next character.
+ *
+ * @example
+ * ```typescript
+ * import { CancellationToken, typeStringDelayedAsync } from "@tego/botjs";
+ *
+ * const token = new CancellationToken();
+ * setTimeout(() => token.cancel(), 1000);
+ * const completed = await typeStringDelayedAsync(longText, 300, token);
+ * ```
+ */
+export const CancellationToken = bot.CancellationToken;
+
 // ============================================================================
 // Mouse Functions
 // ============================================================================
@@ -50,8 +81,12 @@ export const Screen = bot.Screen;
 /**
  * Move the mouse cursor to the specified coordinates instantly
  *
- * @param x - X coordinate in pixels
- * @param y - Y coordinate in pixels
+ * Coordinates are logical (the same space as `width`/`height` from
+ * {@link getScreenSize}). On HiDPI displays, screenshot pixel positions must be
+ * divided by {@link getScreenScaleFactor} before passing them here.
+ *
+ * @param x - X coordinate in logical pixels
+ * @param y - Y coordinate in logical pixels
  *
  * @example
  * ```typescript
@@ -87,6 +122,94 @@ export function moveMouseSmooth(x: number, y: number, speed?: number): void {
   bot.moveMouseSmooth(x, y, speed);
 }
 
+/**
+ * Move the mouse cursor along a cubic Bezier curve to the specified coordinates
+ *
+ * Produces a curved, human-looking path instead of a straight line. Each step
+ * waits for the delay configured via {@link setMouseDelay}. When no control
+ * points are given, slightly randomized ones are generated so every movement
+ * follows a different natural arc.
+ *
+ * @param x - Target X coordinate in pixels
+ * @param y - Target Y coordinate in pixels
+ * @param options - Optional settings
+ * @param options.controlPoints - Exactly two control points shaping the curve
+ * @param options.steps - Number of interpolation steps (default: 50)
+ *
+ * @example
+ * ```typescript
+ * import { moveMouseBezier, setMouseDelay } from "@tego/botjs";
+ *
+ * // Natural arc with randomized control points
+ * setMouseDelay(2);
+ * moveMouseBezier(800, 600);
+ *
+ * // Explicit curve with fewer steps
+ * moveMouseBezier(800, 600, {
+ *   controlPoints: [
+ *     { x: 200, y: 500 },
+ *     { x: 700, y: 100 },
+ *   ],
+ *   steps: 30,
+ * });
+ * ```
+ */
+export function moveMouseBezier(
+  x: number,
+  y: number,
+  options?: bot.BezierOptions,
+): void {
+  bot.moveMouseBezier(x, y, options);
+}
+
+/**
+ * Move the mouse cursor by an offset from its current position
+ *
+ * The target position is clamped to the bounding box of all monitors, which
+ * makes this suitable for joystick or gyroscope style controllers that report
+ * deltas.
+ *
+ * @param dx - Horizontal offset in pixels (negative moves left)
+ * @param dy - Vertical offset in pixels (negative moves up)
+ *
+ * @example
+ * ```typescript
+ * import { moveMouseRelative } from "@tego/botjs";
+ *
+ * // Nudge the cursor 10px right and 5px up
+ * moveMouseRelative(10, -5);
+ * ```
+ */
+export function moveMouseRelative(dx: number, dy: number): void {
+  bot.moveMouseRelative(dx, dy);
+}
+
+/**
+ * Move the mouse cursor smoothly by an offset from its current position
+ *
+ * Uses the same easing animation as {@link moveMouseSmooth}. The target position
+ * is clamped to the bounding box of all monitors.
+ *
+ * @param dx - Horizontal offset in pixels (negative moves left)
+ * @param dy - Vertical offset in pixels (negative moves up)
+ * @param speed - Optional movement speed multiplier (default: 3.0, higher = faster)
+ *
+ * @example
+ * ```typescript
+ * import { moveMouseSmoothRelative } from "@tego/botjs";
+ *
+ * // Glide the cursor 200px to the left
+ * moveMouseSmoothRelative(-200, 0);
+ * ```
+ */
+export function moveMouseSmoothRelative(
+  dx: number,
+  dy: number,
+  speed?: number,
+): void {
+  bot.moveMouseSmoothRelative(dx, dy, speed);
+}
+
 /**
  * Click the mouse button at the current cursor position
  *
@@ -179,6 +302,54 @@ export function scrollMouse(x: number, y: number): void {
   bot.scrollMouse(x, y);
 }
 
+/**
+ * Scroll smoothly by spreading single-notch wheel events over a duration
+ *
+ * Some applications ignore one large scroll delta or only start momentum
+ * scrolling for a stream of small ones; use this instead of {@link scrollMouse}
+ * for those.
+ *
+ * @param dx - Horizontal notches (positive = right, negative = left)
+ * @param dy - Vertical notches (positive = down, negative = up)
+ * @param durationMs - Total duration in milliseconds (default: 300)
+ *
+ * @example
+ * ```typescript
+ * import { scrollSmooth } from "@tego/botjs";
+ *
+ * // Scroll down 10 notches over half a second
+ * scrollSmooth(0, 10, 500);
+ * ```
+ */
+export function scrollSmooth(
+  dx: number,
+  dy: number,
+  durationMs?: number,
+): void {
+  bot.scrollSmooth(dx, dy, durationMs);
+}
+
+/**
+ * Scroll a number of lines in a direction
+ *
+ * @param direction - "up", "down", "left" or "right"
+ * @param count - Number of lines to scroll (default: 1)
+ *
+ * @example
+ * ```typescript
+ * import { scrollLines } from "@tego/botjs";
+ *
+ * scrollLines("up", 3);
+ * scrollLines("right");
+ * ```
+ */
+export function scrollLines(
+  direction: "up" | "down" | "left" | "right",
+  count?: number,
+): void {
+  bot.scrollLines(direction, count);
+}
+
 /**
  * Get the current mouse cursor position
  *
@@ -248,6 +419,31 @@ export function keyTap(key: string, modifier?: string[]): void {
   bot.keyTap(key, modifier);
 }
 
+/**
+ * Tap a key combination written as a single string
+ *
+ * Parts are separated by `+` and are case-insensitive. All parts but the last
+ * must be modifiers; aliases are accepted (`Ctrl`/`Control`, `Alt`/`Option`,
+ * `Cmd`/`Command`/`Meta`/`Super`/`Win`). Throws on malformed combos such as
+ * unknown modifiers, duplicates or a missing key.
+ *
+ * @param combo - Key combination (e.g., "Ctrl+Shift+S", "Cmd+C", "Ctrl++")
+ *
+ * @example
+ * ```typescript
+ * import { keyCombo } from "@tego/botjs";
+ *
+ * // Save as
+ * keyCombo("Ctrl+Shift+S");
+ *
+ * // Copy on macOS
+ * keyCombo("Cmd+C");
+ * ```
+ */
+export function keyCombo(combo: string): void {
+  bot.keyCombo(combo);
+}
+
 /**
  * Toggle a key state (press down or release up)
  *
@@ -281,6 +477,80 @@ export function keyToggle(
   bot.keyToggle(key, down, modifier);
 }
 
+/**
+ * Hold a key down for a duration, then release it
+ *
+ * The key (and any modifiers) are always released afterwards, so this is safer
+ * than pairing {@link keyToggle} calls with a manual sleep.
+ *
+ * @param key - Key to hold (e.g., 'w', 'space', 'shift')
+ * @param durationMs - How long to hold the key in milliseconds
+ * @param modifier - Optional modifier keys held together with the key
+ *
+ * @example
+ * ```typescript
+ * import { keyHold } from "@tego/botjs";
+ *
+ * // Walk forward in a game for two seconds
+ * keyHold('w', 2000);
+ *
+ * // Sprint: hold Shift+W for one second
+ * keyHold('w', 1000, ['shift']);
+ * ```
+ */
+export function keyHold(
+  key: string,
+  durationMs: number,
+  modifier?: string[],
+): void {
+  bot.keyHold(key, durationMs, modifier);
+}
+
+/**
+ * Get the state of the lock keys
+ *
+ * Useful to avoid typing in the wrong case when Caps Lock is on. Values are
+ * best effort: macOS has no Num Lock or Scroll Lock and always reports them
+ * as off; on Linux Scroll Lock depends on the keyboard layout providing an
+ * indicator for it.
+ *
+ * @returns Object with `caps`, `num` and `scroll` flags
+ *
+ * @example
+ * ```typescript
+ * import { getLockState, typeString } from "@tego/botjs";
+ *
+ * if (getLockState().caps) {
+ *   console.warn("Caps Lock is on, typed text will be uppercase");
+ * }
+ * typeString("hello");
+ * ```
+ */
+export function getLockState(): bot.LockStateResult {
+  return bot.getLockState();
+}
+
+/**
+ * Get which modifier keys are currently held down
+ *
+ * `meta` is the Command key on macOS and the Windows/Super key elsewhere.
+ *
+ * @returns Object with `shift`, `ctrl`, `alt` and `meta` flags
+ *
+ * @example
+ * ```typescript
+ * import { getModifierState } from "@tego/botjs";
+ *
+ * const { shift, ctrl } = getModifierState();
+ * if (shift || ctrl) {
+ *   console.log("Release modifiers before typing");
+ * }
+ * ```
+ */
+export function getModifierState(): bot.ModifierStateResult {
+  return bot.getModifierState();
+}
+
 /**
  * Type a string of text by simulating individual keystrokes
  *
@@ -325,6 +595,35 @@ export function typeStringDelayed(text: string, cpm: number): void {
   bot.typeStringDelayed(text, cpm);
 }
 
+/**
+ * Type text with human-like, randomized delays between keystrokes
+ *
+ * Unlike {@link typeStringDelayed}, the delay after each character varies randomly
+ * around the base speed, and longer pauses are inserted after punctuation, which
+ * makes the typing look natural.
+ *
+ * @param text - Text to type
+ * @param baseCpm - Average typing speed in characters per minute
+ * @param jitterPercent - Maximum random deviation of each delay in percent (0-100)
+ * @param punctuationPause - Pause longer after punctuation and line breaks (default: true)
+ *
+ * @example
+ * ```typescript
+ * import { typeStringHumanized } from "@tego/botjs";
+ *
+ * // ~300 characters per minute, each delay varies by up to 30%
+ * typeStringHumanized("Hello, World!", 300, 30);
+ * ```
+ */
+export function typeStringHumanized(
+  text: string,
+  baseCpm: number,
+  jitterPercent: number,
+  punctuationPause?: boolean,
+): void {
+  bot.typeStringHumanized(text, baseCpm, jitterPercent, punctuationPause);
+}
+
 /**
  * Tap a Unicode character by its code point
  *
@@ -365,6 +664,124 @@ export function setKeyboardDelay(ms: number): void {
   bot.setKeyboardDelay(ms);
 }
 
+// ============================================================================
+// Async Input Functions
+// ============================================================================
+//
+// The regular input functions are synchronous and block the Node.js event
+// loop until they finish. That is fine for instant actions like a key tap,
+// but smooth mouse movement, held keys and delayed typing can take seconds.
+// These variants do the same work on a worker thread and return a Promise.
+
+/**
+ * Move the mouse without blocking the event loop
+ *
+ * @param x - X coordinate in pixels
+ * @param y - Y coordinate in pixels
+ *
+ * @example
+ * ```typescript
+ * import { moveMouseAsync } from "@tego/botjs";
+ *
+ * await moveMouseAsync(100, 200);
+ * ```
+ */
+export function moveMouseAsync(x: number, y: number): Promise<void> {
+  return bot.moveMouseAsync(x, y);
+}
+
+/**
+ * Move the mouse smoothly without blocking the event loop
+ *
+ * @param x - X coordinate in pixels
+ * @param y - Y coordinate in pixels
+ * @param speed - Optional speed multiplier (higher = faster)
+ *
+ * @example
+ * ```typescript
+ * import { moveMouseSmoothAsync } from "@tego/botjs";
+ *
+ * // Timers and I/O keep running while the cursor glides
+ * await moveMouseSmoothAsync(500, 500, 2.0);
+ * ```
+ */
+export function moveMouseSmoothAsync(
+  x: number,
+  y: number,
+  speed?: number,
+): Promise<void> {
+  return bot.moveMouseSmoothAsync(x, y, speed);
+}
+
+/**
+ * Hold a key down for a duration without blocking the event loop
+ *
+ * @param key - Key to hold
+ * @param durationMs - How long to hold the key in milliseconds
+ * @param modifier - Optional modifier keys held together with the key
+ *
+ * @example
+ * ```typescript
+ * import { keyHoldAsync } from "@tego/botjs";
+ *
+ * // Walk forward for two seconds in a game
+ * await keyHoldAsync("w", 2000);
+ * ```
+ */
+export function keyHoldAsync(
+  key: string,
+  durationMs: number,
+  modifier?: string[],
+): Promise<void> {
+  return bot.keyHoldAsync(key, durationMs, modifier);
+}
+
+/**
+ * Type a string without blocking the event loop
+ *
+ * @param text - Text to type
+ *
+ * @example
+ * ```typescript
+ * import { typeStringAsync } from "@tego/botjs";
+ *
+ * await typeStringAsync("Hello, World!");
+ * ```
+ */
+export function typeStringAsync(text: string): Promise<void> {
+  return bot.typeStringAsync(text);
+}
+
+/**
+ * Type a string with a delay between characters without blocking the event loop
+ *
+ * Pass a {@link CancellationToken} to stop typing mid-way.
+ *
+ * @param text - Text to type
+ * @param cpm - Characters per minute (typing speed)
+ * @param token - Optional token to cancel typing
+ * @returns Promise resolving to `true` if the whole string was typed, `false` if cancelled
+ *
+ * @example
+ * ```typescript
+ * import { CancellationToken, typeStringDelayedAsync } from "@tego/botjs";
+ *
+ * const token = new CancellationToken();
+ * const typing = typeStringDelayedAsync("A very long paragraph...", 200, token);
+ *
+ * // Stop typing after 3 seconds
+ * setTimeout(() => token.cancel(), 3000);
+ * const completed = await typing;
+ * ```
+ */
+export function typeStringDelayedAsync(
+  text: string,
+  cpm: number,
+  token?: bot.CancellationToken,
+): Promise<boolean> {
+  return bot.typeStringDelayedAsync(text, cpm, token);
+}
+
 // ============================================================================
 // Screen Functions
 // ============================================================================
@@ -441,6 +858,87 @@ export function captureScreenRegion(
   return bot.captureScreenRegion(x, y, width, height);
 }
 
+/**
+ * Capture the contents of a single window as a PNG image
+ *
+ * Uses the platform window capture API, so the window is captured even when
+ * other windows cover it. Where that is not supported, falls back to capturing
+ * the window's bounds on the primary monitor, including anything on top.
+ *
+ * @param windowId - `windowId` of a window from {@link getAllWindows}
+ * @returns Promise resolving to screen capture with PNG buffer
+ *
+ * @example
+ * ```typescript
+ * import { captureWindow, findWindowsByProcess } from "@tego/botjs";
+ * import fs from "fs";
+ *
+ * const [editor] = findWindowsByProcess("code");
+ * if (editor) {
+ *   const shot = await captureWindow(editor.windowId);
+ *   fs.writeFileSync("editor.png", shot.image);
+ * }
+ * ```
+ */
+export function captureWindow(
+  windowId: string,
+): Promise<bot.ScreenCaptureResult> {
+  return bot.captureWindow(windowId);
+}
+
+/**
+ * Start streaming raw screen frames to a callback
+ *
+ * Captures run on a background thread at up to `fps` frames per second, which
+ * is much faster than calling `captureScreen` in a loop. Frames are raw RGBA
+ * (not PNG). If the callback can't keep up, frames are dropped instead of
+ * queued. Only one stream can run at a time.
+ *
+ * @param fps - Target frames per second
+ * @param callback - Called with each frame's width, height and RGBA data
+ * @param region - Region to capture (default: entire screen)
+ *
+ * @example
+ * ```typescript
+ * import { startCaptureStream, stopCaptureStream } from "@tego/botjs";
+ *
+ * startCaptureStream(
+ *   10,
+ *   (frame) => {
+ *     // First pixel of the frame
+ *     const [r, g, b] = frame.data;
+ *     console.log(`${frame.width}x${frame.height}: rgb(${r}, ${g}, ${b})`);
+ *   },
+ *   { x: 0, y: 0, width: 640, height: 480 },
+ * );
+ *
+ * setTimeout(stopCaptureStream, 5000);
+ * ```
+ */
+export function startCaptureStream(
+  fps: number,
+  callback: (frame: bot.CaptureFrameResult) => void,
+  region?: bot.CaptureRegionInput,
+): void {
+  bot.startCaptureStream(fps, region, callback);
+}
+
+/**
+ * Stop the capture stream started by `startCaptureStream`
+ *
+ * Does nothing if no stream is running.
+ *
+ * @example
+ * ```typescript
+ * import { stopCaptureStream } from "@tego/botjs";
+ *
+ * stopCaptureStream();
+ * ```
+ */
+export function stopCaptureStream(): void {
+  bot.stopCaptureStream();
+}
+
 /**
  * Get the color of a pixel at specific screen coordinates
  *
@@ -465,37 +963,261 @@ export function getPixelColorHex(x: number, y: number): Promise<string> {
 }
 
 /**
- * Get the global Screen instance for capture operations
+ * Convert a hex color to HSV (hue, saturation, value)
  *
- * @returns Screen object
+ * HSV makes tolerance checks easier than RGB: e.g. "any bright green" is a hue
+ * range plus minimum saturation and value.
+ *
+ * @param hex - Color as hex string (e.g., "#FF0000")
+ * @returns `h` in degrees [0, 360), `s` and `v` in [0, 1]
  *
  * @example
  * ```typescript
- * import { getScreen } from "@tego/botjs";
+ * import { colorToHsv, getPixelColor } from "@tego/botjs";
  *
- * const screen = getScreen();
- * const bitmap = await screen.capture(0, 0, 800, 600);
+ * const { h, s, v } = colorToHsv(await getPixelColor(100, 200));
+ * const isGreen = h > 90 && h < 150 && s > 0.5 && v > 0.4;
  * ```
  */
-export function getScreen(): bot.Screen {
-  return bot.getScreen();
+export function colorToHsv(hex: string): bot.HsvResult {
+  return bot.colorToHsv(hex);
 }
 
 /**
- * Get the dimensions of the primary screen
+ * Perceptual distance between two colors (CIE76 ΔE)
  *
- * @returns Object containing width and height in pixels
+ * Distances are computed in CIE L*a*b* space, which matches human perception
+ * better than RGB differences. Roughly: < 2.3 is indistinguishable, < 10 is
+ * similar, 100 is black vs. white.
+ *
+ * @param hexA - First color as hex string
+ * @param hexB - Second color as hex string
+ * @returns Distance, 0 for identical colors
  *
  * @example
  * ```typescript
- * import { getScreenSize } from "@tego/botjs";
+ * import { colorDistance, getPixelColor } from "@tego/botjs";
  *
- * const size = getScreenSize();
- * console.log(`Screen resolution: ${size.width}x${size.height}`);
+ * const color = await getPixelColor(100, 200);
+ * if (colorDistance(color, "#3478F6") < 10) {
+ *   console.log("Looks like the accent blue");
+ * }
  * ```
  */
-export function getScreenSize(): bot.ScreenSizeResult {
-  return bot.getScreenSize();
+export function colorDistance(hexA: string, hexB: string): number {
+  return bot.colorDistance(hexA, hexB);
+}
+
+/**
+ * Get the average color of a screen region
+ *
+ * Cheaper than capturing the region and averaging it in JavaScript, since
+ * only the resulting color crosses the native boundary.
+ *
+ * @param x - X coordinate of the region's top-left corner
+ * @param y - Y coordinate of the region's top-left corner
+ * @param width - Region width in pixels
+ * @param height - Region height in pixels
+ * @returns Promise resolving to hex color string (e.g., "#7f7f00")
+ *
+ * @example
+ * ```typescript
+ * import { getRegionAverageColor } from "@tego/botjs";
+ *
+ * const color = await getRegionAverageColor(0, 0, 200, 100);
+ * console.log(`Average color: ${color}`);
+ * ```
+ */
+export function getRegionAverageColor(
+  x: number,
+  y: number,
+  width: number,
+  height: number,
+): Promise<string> {
+  return bot.getRegionAverageColor(x, y, width, height);
+}
+
+/**
+ * Get the dominant color of a screen region
+ *
+ * The region is downscaled and its colors are grouped into coarse buckets;
+ * the result is the average color of the most common bucket. Unlike the
+ * average color, a small red icon on a green background still reports green.
+ *
+ * @param x - X coordinate of the region's top-left corner
+ * @param y - Y coordinate of the region's top-left corner
+ * @param width - Region width in pixels
+ * @param height - Region height in pixels
+ * @returns Promise resolving to hex color string (e.g., "#00c800")
+ *
+ * @example
+ * ```typescript
+ * import { getRegionDominantColor } from "@tego/botjs";
+ *
+ * const color = await getRegionDominantColor(100, 100, 50, 50);
+ * if (color === "#00c800") {
+ *   console.log("Status light is green");
+ * }
+ * ```
+ */
+export function getRegionDominantColor(
+  x: number,
+  y: number,
+  width: number,
+  height: number,
+): Promise<string> {
+  return bot.getRegionDominantColor(x, y, width, height);
+}
+
+/**
+ * Wait until the pixel at a position matches a color
+ *
+ * Polls the pixel until every RGB channel is within `tolerance` of the target
+ * color, or until the timeout elapses. Useful for waiting on loading
+ * indicators, status lights or game state.
+ *
+ * @param x - X coordinate on screen
+ * @param y - Y coordinate on screen
+ * @param color - Target color as hex string (e.g., "#FF0000")
+ * @param timeoutMs - Maximum time to wait in milliseconds
+ * @param tolerance - Maximum per-channel difference, 0-255 (default: 0)
+ * @param pollIntervalMs - Delay between checks in milliseconds (default: 100)
+ * @returns Promise resolving to `true` if the color matched, `false` on timeout
+ *
+ * @example
+ * ```typescript
+ * import { waitForPixelColor, leftClick } from "@tego/botjs";
+ *
+ * // Wait up to 5 seconds for the button to turn green
+ * if (await waitForPixelColor(640, 480, "#00FF00", 5000, 10)) {
+ *   leftClick(640, 480);
+ * }
+ * ```
+ */
+export function waitForPixelColor(
+  x: number,
+  y: number,
+  color: string,
+  timeoutMs: number,
+  tolerance?: number,
+  pollIntervalMs?: number,
+): Promise<boolean> {
+  return bot.waitForPixelColor(
+    x,
+    y,
+    color,
+    timeoutMs,
+    tolerance,
+    pollIntervalMs,
+  );
+}
+
+/**
+ * Get the global Screen instance for capture operations
+ *
+ * @returns Screen object
+ *
+ * @example
+ * ```typescript
+ * import { getScreen } from "@tego/botjs";
+ *
+ * const screen = getScreen();
+ * const bitmap = await screen.capture(0, 0, 800, 600);
+ * ```
+ */
+export function getScreen(): bot.Screen {
+  return bot.getScreen();
+}
+
+/**
+ * Get the dimensions of the primary screen
+ *
+ * `width`/`height` are logical coordinates, the space mouse functions expect.
+ * `physicalWidth`/`physicalHeight` are device pixels, the space of screenshots
+ * and pixel colors. They differ on HiDPI displays such as Retina Macs and
+ * scaled Windows displays.
+ *
+ * @returns Object containing logical and physical width and height
+ *
+ * @example
+ * ```typescript
+ * import { getScreenSize } from "@tego/botjs";
+ *
+ * const size = getScreenSize();
+ * console.log(`Screen resolution: ${size.width}x${size.height}`);
+ * console.log(`Physical pixels: ${size.physicalWidth}x${size.physicalHeight}`);
+ * ```
+ */
+export function getScreenSize(): bot.ScreenSizeResult {
+  return bot.getScreenSize();
+}
+
+/**
+ * Get the scale factor of the primary screen
+ *
+ * This is the ratio between physical pixels and logical coordinates, e.g. `2`
+ * on a Retina display and `1.25` on a Windows display scaled to 125%. Use it to
+ * convert positions found in screenshots into mouse coordinates.
+ *
+ * @returns Scale factor of the primary screen
+ *
+ * @example
+ * ```typescript
+ * import { getScreenScaleFactor, moveMouse } from "@tego/botjs";
+ *
+ * // A match found at (400, 300) in a Retina screenshot
+ * const scale = getScreenScaleFactor();
+ * moveMouse(Math.round(400 / scale), Math.round(300 / scale));
+ * ```
+ */
+export function getScreenScaleFactor(): number {
+  return bot.getScreenScaleFactor();
+}
+
+/**
+ * Get information about all connected monitors
+ *
+ * Bounds are positions in the virtual desktop as the OS reports them: logical
+ * points on macOS and Linux, device pixels on Windows. `scaleFactor` converts
+ * between the two. Secondary monitors can have negative `x`/`y` when placed
+ * left of or above the primary one.
+ *
+ * @returns Array of monitors with index, name, bounds, scale factor and primary flag
+ *
+ * @example
+ * ```typescript
+ * import { getMonitors, moveMouse } from "@tego/botjs";
+ *
+ * for (const m of getMonitors()) {
+ *   console.log(`#${m.index} ${m.name}: ${m.width}x${m.height} at (${m.x}, ${m.y})`);
+ * }
+ *
+ * // Move to the center of the second monitor
+ * const [, second] = getMonitors();
+ * if (second) {
+ *   moveMouse(second.x + second.width / 2, second.y + second.height / 2);
+ * }
+ * ```
+ */
+export function getMonitors(): bot.MonitorInfoResult[] {
+  return bot.getMonitors();
+}
+
+/**
+ * Get the primary monitor
+ *
+ * @returns The monitor the operating system reports as primary
+ *
+ * @example
+ * ```typescript
+ * import { getPrimaryMonitor } from "@tego/botjs";
+ *
+ * const primary = getPrimaryMonitor();
+ * console.log(`Primary: ${primary.width}x${primary.height} @${primary.scaleFactor}x`);
+ * ```
+ */
+export function getPrimaryMonitor(): bot.MonitorInfoResult {
+  return bot.getPrimaryMonitor();
 }
 
 /**
@@ -554,6 +1276,156 @@ export function setClipboard(text: string): void {
   bot.setClipboard(text);
 }
 
+/**
+ * List the content types currently on the system clipboard
+ *
+ * Platform-specific formats are normalized to a stable set of MIME types:
+ * `"text/plain"`, `"text/html"`, `"text/rtf"`, `"image/png"` and
+ * `"text/uri-list"` (copied files). Use this to decide which getter to call
+ * without trial and error.
+ *
+ * @returns Array of MIME types, empty if the clipboard is empty
+ *
+ * @example
+ * ```typescript
+ * import { getClipboard, getClipboardImage, getClipboardTypes } from "@tego/botjs";
+ *
+ * const types = getClipboardTypes();
+ * if (types.includes("image/png")) {
+ *   const png = getClipboardImage();
+ * } else if (types.includes("text/plain")) {
+ *   const text = getClipboard();
+ * }
+ * ```
+ */
+export function getClipboardTypes(): string[] {
+  return bot.getClipboardTypes();
+}
+
+/**
+ * Get HTML content from the system clipboard
+ *
+ * @returns HTML string, or `null` if the clipboard holds no HTML
+ *
+ * @example
+ * ```typescript
+ * import { getClipboardHtml } from "@tego/botjs";
+ *
+ * const html = getClipboardHtml();
+ * if (html !== null) {
+ *   console.log('Copied markup:', html);
+ * }
+ * ```
+ */
+export function getClipboardHtml(): string | null {
+  return bot.getClipboardHtml();
+}
+
+/**
+ * Write HTML content to the system clipboard
+ *
+ * A plain-text version is stored alongside the HTML so pasting into plain
+ * text editors still works. When `altText` is omitted, it is derived from the
+ * HTML by stripping tags.
+ *
+ * @param html - HTML content to copy
+ * @param altText - Optional plain-text fallback
+ *
+ * @example
+ * ```typescript
+ * import { setClipboardHtml } from "@tego/botjs";
+ *
+ * // Rich apps paste bold text, plain editors paste "Hello"
+ * setClipboardHtml('<b>Hello</b>');
+ *
+ * // Explicit plain-text fallback
+ * setClipboardHtml('<a href="https://example.com">Example</a>', 'https://example.com');
+ * ```
+ */
+export function setClipboardHtml(html: string, altText?: string): void {
+  bot.setClipboardHtml(html, altText);
+}
+
+/**
+ * Get RTF content from the system clipboard
+ *
+ * Reads the platform's rich text format: `public.rtf` on macOS, "Rich Text
+ * Format" on Windows and the `text/rtf` target on Linux (X11 or XWayland).
+ *
+ * @returns RTF document, or `null` if the clipboard holds no RTF
+ *
+ * @example
+ * ```typescript
+ * import { getClipboardRtf } from "@tego/botjs";
+ *
+ * const rtf = getClipboardRtf();
+ * if (rtf !== null) {
+ *   console.log('Copied rich text:', rtf);
+ * }
+ * ```
+ */
+export function getClipboardRtf(): string | null {
+  return bot.getClipboardRtf();
+}
+
+/**
+ * Write RTF content to the system clipboard
+ *
+ * A plain-text version is stored alongside the RTF so pasting into plain
+ * text editors still works. When `altText` is omitted, it is derived from the
+ * RTF by dropping control words and header groups.
+ *
+ * On Linux the clipboard has no storage of its own: a background thread keeps
+ * serving the content until another application copies something.
+ *
+ * @param rtf - RTF document to copy
+ * @param altText - Optional plain-text fallback
+ *
+ * @example
+ * ```typescript
+ * import { setClipboardRtf } from "@tego/botjs";
+ *
+ * // Word processors paste bold text, plain editors paste "Hello"
+ * setClipboardRtf('{\\rtf1\\ansi {\\b Hello}}');
+ * ```
+ */
+export function setClipboardRtf(rtf: string, altText?: string): void {
+  bot.setClipboardRtf(rtf, altText);
+}
+
+/**
+ * Watch the system clipboard for changes
+ *
+ * The clipboard is polled in the background and rapid successive changes are
+ * debounced into a single event. Each event carries the content type
+ * (`"text"`, `"html"`, `"image"` or `"empty"`) so callers can skip content they
+ * are not interested in. Call `stop()` on the returned handle to stop watching.
+ *
+ * On Linux only text and HTML changes are detected; copying one image over
+ * another is not reported.
+ *
+ * @param callback - Called with the new content type and plain text (if any)
+ * @returns Handle used to stop watching
+ *
+ * @example
+ * ```typescript
+ * import { watchClipboard } from "@tego/botjs";
+ *
+ * const watcher = watchClipboard((event) => {
+ *   if (event.contentType === "image") return;
+ *   console.log('Copied:', event.text);
+ * });
+ *
+ * // Later
+ * watcher.stop();
+ * ```
+ */
+export function watchClipboard(
+  callback: (event: bot.ClipboardChangeEvent) => void,
+): bot.ClipboardWatcher {
+  return bot.watchClipboard(callback);
+}
+
 /**
  * Clear the system clipboard contents
  *
@@ -631,10 +1503,11 @@ export function getActiveWindow(): bot.WindowInfoResult {
 }
 
 /**
- * Get a list of all visible windows
+ * Get a list of all windows
  *
- * **Note:** Currently returns only the active window due to API limitations of the underlying library.
- * Future versions may support enumerating all windows.
+ * Includes minimized and off-screen windows. Use {@link getVisibleWindows} to
+ * get only windows that can be interacted with. Windows are sorted front to
+ * back; `zOrder` is 0 for the frontmost window.
  *
  * @returns Array of WindowInfo objects
  *
@@ -654,10 +1527,28 @@ export function getAllWindows(): bot.WindowInfoResult[] {
 }
 
 /**
- * Find windows by title using case-insensitive partial matching
+ * Get windows that are not minimized and at least partly on screen
+ *
+ * Filters out minimized, zero-size and off-screen windows, which
+ * {@link getAllWindows} includes.
+ *
+ * @returns Array of WindowInfo objects
+ *
+ * @example
+ * ```typescript
+ * import { getVisibleWindows } from "@tego/botjs";
  *
- * **Note:** Currently searches only the active window due to API limitations of the underlying library.
- * Future versions may support searching all windows.
+ * for (const win of getVisibleWindows()) {
+ *   console.log(`${win.title} at (${win.x}, ${win.y})`);
+ * }
+ * ```
+ */
+export function getVisibleWindows(): bot.WindowInfoResult[] {
+  return bot.getVisibleWindows();
+}
+
+/**
+ * Find windows by title using case-insensitive partial matching
  *
  * @param title - Title text to search for (case-insensitive partial match)
  * @returns Array of matching WindowInfo objects
@@ -681,8 +1572,11 @@ export function findWindowsByTitle(title: string): bot.WindowInfoResult[] {
 /**
  * Find windows by process name using case-insensitive partial matching
  *
- * **Note:** Currently searches only the active window due to API limitations of the underlying library.
- * Future versions may support searching all windows.
+ * Matches against both the executable path (e.g. `"chrome.exe"`) and the
+ * application display name (e.g. `"Google Chrome"`).
+ *
+ * Results are sorted front to back, so the first entry is the topmost
+ * window of the process.
  *
  * @param processName - Process name to search for (case-insensitive partial match)
  * @returns Array of matching WindowInfo objects
@@ -704,6 +1598,277 @@ export function findWindowsByProcess(
   return bot.findWindowsByProcess(processName);
 }
 
+// ============================================================================
+// Macro Functions
+// ============================================================================
+
+/**
+ * Start recording global keyboard and mouse input
+ *
+ * Input is observed passively and still reaches other applications. Only one
+ * recording can be active at a time; call {@link stopRecordingMacro} to finish.
+ *
+ * @example
+ * ```typescript
+ * import { startRecordingMacro, stopRecordingMacro } from "@tego/botjs";
+ * import fs from "node:fs";
+ *
+ * startRecordingMacro();
+ * setTimeout(() => {
+ *   fs.writeFileSync("macro.json", stopRecordingMacro());
+ * }, 10_000);
+ * ```
+ */
+export function startRecordingMacro(): void {
+  bot.startRecordingMacro();
+}
+
+/**
+ * Stop the active recording and return it
+ *
+ * The recording is a JSON document of timestamped events, so it can be saved
+ * to disk and replayed later with {@link playMacro}.
+ *
+ * @returns Serialized recording
+ *
+ * @example
+ * ```typescript
+ * import { startRecordingMacro, stopRecordingMacro } from "@tego/botjs";
+ *
+ * startRecordingMacro();
+ * // ... user performs some actions ...
+ * const recording = stopRecordingMacro();
+ * ```
+ */
+export function stopRecordingMacro(): Buffer {
+  return bot.stopRecordingMacro();
+}
+
+/**
+ * Replay a recording created by {@link stopRecordingMacro}
+ *
+ * Events are replayed on a worker thread with their original relative timing,
+ * scaled by `speed`, so the event loop stays free. Pass a
+ * {@link CancellationToken} to stop playback mid-way; keys and mouse buttons
+ * the recording left pressed are released when playback ends.
+ *
+ * @param buffer - Serialized recording
+ * @param speed - Playback speed multiplier (default: 1.0, 2.0 = twice as fast)
+ * @param token - Optional token to cancel playback
+ * @returns Promise resolving once playback finishes or is cancelled
+ *
+ * @example
+ * ```typescript
+ * import { CancellationToken, playMacro } from "@tego/botjs";
+ * import fs from "node:fs";
+ *
+ * const recording = fs.readFileSync("macro.json");
+ * await playMacro(recording);
+ *
+ * // Replay at double speed, giving up after 5 seconds
+ * const token = new CancellationToken();
+ * setTimeout(() => token.cancel(), 5000);
+ * await playMacro(recording, 2.0, token);
+ * ```
+ */
+export function playMacro(
+  buffer: Buffer,
+  speed?: number,
+  token?: bot.CancellationToken,
+): Promise<void> {
+  return bot.playMacro(buffer, speed, token);
+}
+
+// ============================================================================
+// Global Shortcut Functions
+// ============================================================================
+
+/**
+ * Register a global shortcut that fires even when another app has focus
+ *
+ * The keystroke is observed passively and still reaches the focused
+ * application. Shortcuts share the global input listener with macro
+ * recording, so they cannot be registered while a macro is being recorded.
+ *
+ * @param accelerator - Combo such as "Ctrl+Shift+A", "F3" or "Ctrl+,"
+ * @param callback - Called each time the shortcut is pressed
+ *
+ * @example
+ * ```typescript
+ * import { registerGlobalShortcut, unregisterGlobalShortcut } from "@tego/botjs";
+ *
+ * registerGlobalShortcut("Ctrl+Shift+Q", () => {
+ *   console.log("Stopping automation");
+ *   unregisterGlobalShortcut("Ctrl+Shift+Q");
+ * });
+ * ```
+ */
+export function registerGlobalShortcut(
+  accelerator: string,
+  callback: () => void,
+): void {
+  bot.registerGlobalShortcut(accelerator, () => callback());
+}
+
+/**
+ * Unregister a shortcut added with {@link registerGlobalShortcut}
+ *
+ * @param accelerator - Combo passed to registerGlobalShortcut
+ * @returns true if the shortcut was registered
+ */
+export function unregisterGlobalShortcut(accelerator: string): boolean {
+  return bot.unregisterGlobalShortcut(accelerator);
+}
+
+/**
+ * Check whether a shortcut is valid and not yet registered by this process
+ *
+ * Shortcuts claimed by other applications are not detected. Throws if the
+ * accelerator cannot be parsed.
+ *
+ * @param accelerator - Combo such as "Ctrl+Shift+A" or "F3"
+ * @returns true if {@link registerGlobalShortcut} would accept it
+ *
+ * @example
+ * ```typescript
+ * import { isShortcutAvailable, registerGlobalShortcut } from "@tego/botjs";
+ *
+ * if (isShortcutAvailable("F8")) {
+ *   registerGlobalShortcut("F8", () => console.log("F8 pressed"));
+ * }
+ * ```
+ */
+export function isShortcutAvailable(accelerator: string): boolean {
+  return bot.isShortcutAvailable(accelerator);
+}
+
+// ============================================================================
+// Permission Functions
+// ============================================================================
+
+/**
+ * Check which system permissions the current process has
+ *
+ * On macOS, input simulation and global shortcuts need Accessibility access
+ * and screen capture needs Screen Recording access. Screen captures, screen
+ * color reads, hotkeys and macro recording throw with code
+ * `PERMISSION_DENIED` when access is missing; simulated input is silently
+ * dropped. Other platforms report every permission as granted.
+ *
+ * @returns Whether accessibility, screenRecording and microphone are granted
+ *
+ * @example
+ * ```typescript
+ * import { checkPermissions, requestAccessibilityPermission } from "@tego/botjs";
+ *
+ * if (!checkPermissions().accessibility) {
+ *   requestAccessibilityPermission();
+ *   console.log("Grant Accessibility access, then restart");
+ * }
+ * ```
+ */
+export function checkPermissions(): bot.PermissionStatusResult {
+  return bot.checkPermissions();
+}
+
+/**
+ * Prompt the user to grant Accessibility access
+ *
+ * On macOS this shows the system dialog linking to System Settings. The user
+ * toggles access there, so the result usually stays false until restart.
+ *
+ * @returns true if access is already granted
+ */
+export function requestAccessibilityPermission(): boolean {
+  return bot.requestAccessibilityPermission();
+}
+
+/**
+ * Prompt the user to grant Screen Recording access
+ *
+ * On macOS the prompt appears at most once per app; afterwards access has to
+ * be enabled in System Settings. It takes effect after restart.
+ *
+ * @returns true if access is already granted
+ */
+export function requestScreenRecordingPermission(): boolean {
+  return bot.requestScreenRecordingPermission();
+}
+
+// ============================================================================
+// OCR Functions
+// ============================================================================
+
+/**
+ * Recognize text in multiple screen regions in one call
+ *
+ * The screen is captured once and every region is recognized in a single batch,
+ * reusing the same OCR engine. This is much faster than recognizing regions one
+ * at a time. The default TrOCR model is downloaded on first use. Recognition
+ * runs on a worker thread, so the event loop stays responsive.
+ *
+ * Each detected text region is preprocessed before recognition. By default
+ * small text is upscaled and color is dropped, which suits screen text. Enable
+ * `deskew` for slightly rotated text and `binarize` for noisy or low-contrast
+ * backgrounds.
+ *
+ * Regions are in capture (physical) pixels, the space of {@link captureScreen}
+ * images, not the logical coordinates {@link moveMouse} uses. On a HiDPI
+ * screen, multiply logical values by {@link getScreenScaleFactor}.
+ *
+ * @param regions - Screen regions to recognize, in physical pixels
+ * @param preprocess - Image preprocessing before recognition
+ * @returns Recognized text for each region, in input order
+ *
+ * @example
+ * ```typescript
+ * import { ocrRegions } from "@tego/botjs";
+ *
+ * const [title, status] = await ocrRegions([
+ *   { x: 0, y: 0, width: 400, height: 40 },
+ *   { x: 0, y: 560, width: 400, height: 40 },
+ * ]);
+ * console.log(`Title: ${title}, status: ${status}`);
+ *
+ * // Scanned document shown on screen
+ * const [page] = await ocrRegions([{ x: 0, y: 0, width: 800, height: 1000 }], {
+ *   deskew: true,
+ *   binarize: true,
+ * });
+ * ```
+ */
+export function ocrRegions(
+  regions: bot.OcrRegionInput[],
+  preprocess?: bot.OcrPreprocessInput,
+): Promise<string[]> {
+  return bot.ocrRegions(regions, preprocess);
+}
+
+/**
+ * Recognize text in the image currently on the clipboard
+ *
+ * Chains {@link getClipboardImage} and OCR in one call, for the common "copy
+ * an image, read its text" flow. Throws if the clipboard holds no image. The
+ * default TrOCR model is downloaded on first use. Recognition runs on a
+ * worker thread, so the event loop stays responsive.
+ *
+ * @param preprocess - Image preprocessing before recognition, as in {@link ocrRegions}
+ * @returns Recognized text
+ *
+ * @example
+ * ```typescript
+ * import { ocrClipboardImage } from "@tego/botjs";
+ *
+ * // After copying a screenshot or image
+ * console.log(await ocrClipboardImage());
+ * ```
+ */
+export function ocrClipboardImage(
+  preprocess?: bot.OcrPreprocessInput,
+): Promise<string> {
+  return bot.ocrClipboardImage(preprocess);
+}
+
 // ============================================================================
 // Mouse Shortcut Helper Functions (botjs-specific)
 // ============================================================================
@@ -890,6 +2055,7 @@ export type {
 export {
   findAllInRegion,
   findAllOnScreen,
+  findImageInBuffer,
   findInRegion,
   findOnScreen,
   getMatchBounds,
@@ -309,8 +309,8 @@ export class ScreenshotTool {
     const { getScreenSize } = await import("@tego/bot");
     const size = getScreenSize();
     return this.getPixelColor(
-      Math.floor(size.width / 2),
-      Math.floor(size.height / 2),
+      Math.floor(size.physicalWidth / 2),
+      Math.floor(size.physicalHeight / 2),
     );
   }
 
@@ -440,6 +440,7 @@ describe.skipIf(!ENABLE_INTEGRATION_TESTS)(
         expect(typeof win.title).toBe("string");
         expect(typeof win.processId).toBe("number");
         expect(typeof win.processPath).toBe("string");
+        expect(typeof win.appName).toBe("string");
       });
 
       it("should have correct WindowInfo type", () => {
@@ -15,12 +15,17 @@ import type {
 // Value imports (classes, functions)
 import {
   bitmapColorAt,
+  CancellationToken,
   captureAndCopy,
   captureAndSave,
   captureRegion,
   captureScreen,
   captureScreenRegion,
+  captureWindow,
+  checkPermissions,
   clearClipboard,
+  colorDistance,
+  colorToHsv,
   copyScreenshotToClipboard,
   doubleClick,
   dragMouse,
@@ -29,12 +34,27 @@ import {
   getActiveWindow,
   getAllWindows,
   getClipboard,
+  getClipboardHtml,
   getClipboardImage,
+  getClipboardRtf,
+  getClipboardTypes,
+  getLockState,
+  getModifierState,
+  getMonitors,
   getMousePos,
   getPixelColor,
   getPixelColorHex,
+  getPrimaryMonitor,
+  getRegionAverageColor,
+  getRegionDominantColor,
   getScreen,
+  getScreenScaleFactor,
   getScreenSize,
+  getVisibleWindows,
+  isShortcutAvailable,
+  keyCombo,
+  keyHold,
+  keyHoldAsync,
   keyTap,
   keyToggle,
   leftClick,
@@ -44,23 +64,48 @@ import {
   mouseToggle,
   mouseUp,
   moveMouse,
+  moveMouseAsync,
+  moveMouseBezier,
+  moveMouseRelative,
   moveMouseSmooth,
+  moveMouseSmoothAsync,
+  moveMouseSmoothRelative,
+  ocrClipboardImage,
+  ocrRegions,
+  playMacro,
   quickScreenshot,
   quickScreenshotRegion,
+  registerGlobalShortcut,
+  requestAccessibilityPermission,
+  requestScreenRecordingPermission,
   rightClick,
   Screen,
   ScreenshotTool,
   saveScreenshotToFile,
+  scrollLines,
   scrollMouse,
+  scrollSmooth,
   setClipboard,
+  setClipboardHtml,
   setClipboardImage,
+  setClipboardRtf,
   setKeyboardDelay,
   setMouseDelay,
+  startCaptureStream,
   startInteractiveCapture,
+  startRecordingMacro,
+  stopCaptureStream,
+  stopRecordingMacro,
   typeString,
+  typeStringAsync,
   typeStringDelayed,
+  typeStringDelayedAsync,
+  typeStringHumanized,
   unicodeTap,
+  unregisterGlobalShortcut,
   updateScreenMetrics,
+  waitForPixelColor,
+  watchClipboard,
 } from "@tego/botjs";
 import { describe, expect, it } from "vitest";
 
@@ -74,6 +119,11 @@ describe("@tego/bot", () => {
       expect(typeof Screen).toBe("function");
     });
 
+    it("should export CancellationToken class", () => {
+      expect(CancellationToken).toBeDefined();
+      expect(typeof CancellationToken).toBe("function");
+    });
+
     it("should export all mouse functions", () => {
       expect(typeof moveMouse).toBe("function");
       expect(typeof moveMouseSmooth).toBe("function");
@@ -83,6 +133,11 @@ describe("@tego/bot", () => {
       expect(typeof scrollMouse).toBe("function");
       expect(typeof getMousePos).toBe("function");
       expect(typeof setMouseDelay).toBe("function");
+      expect(typeof moveMouseRelative).toBe("function");
+      expect(typeof moveMouseSmoothRelative).toBe("function");
+      expect(typeof moveMouseBezier).toBe("function");
+      expect(typeof scrollSmooth).toBe("function");
+      expect(typeof scrollLines).toBe("function");
     });
 
     it("should export all keyboard functions", () => {
@@ -92,6 +147,11 @@ describe("@tego/bot", () => {
       expect(typeof typeStringDelayed).toBe("function");
       expect(typeof unicodeTap).toBe("function");
       expect(typeof setKeyboardDelay).toBe("function");
+      expect(typeof typeStringHumanized).toBe("function");
+      expect(typeof keyHold).toBe("function");
+      expect(typeof keyCombo).toBe("function");
+      expect(typeof getLockState).toBe("function");
+      expect(typeof getModifierState).toBe("function");
     });
 
     it("should export all screen functions", () => {
@@ -103,6 +163,17 @@ describe("@tego/bot", () => {
       expect(typeof getScreenSize).toBe("function");
       expect(typeof bitmapColorAt).toBe("function");
       expect(typeof updateScreenMetrics).toBe("function");
+      expect(typeof getScreenScaleFactor).toBe("function");
+      expect(typeof getRegionAverageColor).toBe("function");
+      expect(typeof getRegionDominantColor).toBe("function");
+      expect(typeof waitForPixelColor).toBe("function");
+      expect(typeof startCaptureStream).toBe("function");
+      expect(typeof stopCaptureStream).toBe("function");
+      expect(typeof getMonitors).toBe("function");
+      expect(typeof getPrimaryMonitor).toBe("function");
+      expect(typeof colorToHsv).toBe("function");
+      expect(typeof colorDistance).toBe("function");
+      expect(typeof captureWindow).toBe("function");
     });
 
     it("should export all screenshot functions", () => {
@@ -123,6 +194,12 @@ describe("@tego/bot", () => {
       expect(typeof clearClipboard).toBe("function");
       expect(typeof getClipboardImage).toBe("function");
       expect(typeof setClipboardImage).toBe("function");
+      expect(typeof getClipboardHtml).toBe("function");
+      expect(typeof setClipboardHtml).toBe("function");
+      expect(typeof getClipboardRtf).toBe("function");
+      expect(typeof setClipboardRtf).toBe("function");
+      expect(typeof watchClipboard).toBe("function");
+      expect(typeof getClipboardTypes).toBe("function");
     });
 
     it("should export all window management functions", () => {
@@ -130,6 +207,38 @@ describe("@tego/bot", () => {
       expect(typeof getAllWindows).toBe("function");
       expect(typeof findWindowsByTitle).toBe("function");
       expect(typeof findWindowsByProcess).toBe("function");
+      expect(typeof getVisibleWindows).toBe("function");
+    });
+
+    it("should export all OCR functions", () => {
+      expect(typeof ocrRegions).toBe("function");
+      expect(typeof ocrClipboardImage).toBe("function");
+    });
+
+    it("should export all macro functions", () => {
+      expect(typeof startRecordingMacro).toBe("function");
+      expect(typeof stopRecordingMacro).toBe("function");
+      expect(typeof playMacro).toBe("function");
+    });
+
+    it("should export all async input functions", () => {
+      expect(typeof moveMouseAsync).toBe("function");
+      expect(typeof moveMouseSmoothAsync).toBe("function");
+      expect(typeof keyHoldAsync).toBe("function");
+      expect(typeof typeStringAsync).toBe("function");
+      expect(typeof typeStringDelayedAsync).toBe("function");
+    });
+
+    it("should export all global shortcut functions", () => {
+      expect(typeof isShortcutAvailable).toBe("function");
+      expect(typeof registerGlobalShortcut).toBe("function");
+      expect(typeof unregisterGlobalShortcut).toBe("function");
+    });
+
+    it("should export all permission functions", () => {
+      expect(typeof checkPermissions).toBe("function");
+      expect(typeof requestAccessibilityPermission).toBe("function");
+      expect(typeof requestScreenRecordingPermission).toBe("function");
     });
 
     it("should export all helper functions", () => {
@@ -162,7 +271,12 @@ describe("@tego/bot", () => {
     });
 
     it("should have ScreenSize type", () => {
-      const size: ScreenSize = { width: 1920, height: 1080 };
+      const size: ScreenSize = {
+        width: 1440,
+        height: 900,
+        physicalWidth: 2880,
+        physicalHeight: 1800,
+      };
       expect(size).toBeDefined();
     });
 
@@ -248,6 +362,15 @@ describe("@tego/bot", () => {
     });
   });
 
+  describe("CancellationToken class", () => {
+    it("should start uncancelled and record cancel()", () => {
+      const token = new CancellationToken();
+      expect(token.isCancelled).toBe(false);
+      token.cancel();
+      expect(token.isCancelled).toBe(true);
+    });
+  });
+
   describe("Global functions - Mouse", () => {
     it("should set mouse delay", () => {
       expect(() => {
@@ -278,6 +401,26 @@ describe("@tego/bot", () => {
     });
   });
 
+  describe("Color helpers", () => {
+    it("should convert primaries to HSV", () => {
+      expect(colorToHsv("#FF0000")).toEqual({ h: 0, s: 1, v: 1 });
+      expect(colorToHsv("#00FF00")).toEqual({ h: 120, s: 1, v: 1 });
+      expect(colorToHsv("#0000FF")).toEqual({ h: 240, s: 1, v: 1 });
+    });
+
+    it("should measure perceptual color distance", () => {
+      expect(colorDistance("#000000", "#FFFFFF")).toBeCloseTo(100, 1);
+      expect(colorDistance("#FF0000", "#FF0000")).toBe(0);
+      expect(() => colorDistance("#FF0000", "red")).toThrow();
+    });
+
+    it("should attach an error code to native errors", () => {
+      expect(() => colorDistance("#FF0000", "red")).toThrow(
+        expect.objectContaining({ code: "SCREEN_ERROR" }),
+      );
+    });
+  });
+
   describe("Screenshot Tool", () => {
     it("should create ScreenshotTool instance", () => {
       const tool = new ScreenshotTool();
@@ -27,6 +27,8 @@ describe("Image Match Module", () => {
         scaleSteps: [1.0, 0.9, 0.8],
         confidence: 0.8,
         limit: 10,
+        angles: [0, 90],
+        nmsIou: 0.5,
       };
       expect(config).toBeDefined();
     });
@@ -39,6 +41,7 @@ describe("Image Match Module", () => {
         height: 30,
         confidence: 0.95,
         scale: 1.0,
+        angle: 0,
       };
       expect(result).toBeDefined();
     });
@@ -72,6 +75,7 @@ describe("Image Match Module", () => {
         height: 30,
         confidence: 0.9,
         scale: 1.0,
+        angle: 0,
       };
 
       const center = getMatchCenter(match);
@@ -88,6 +92,7 @@ describe("Image Match Module", () => {
         height: 100,
         confidence: 0.9,
         scale: 1.0,
+        angle: 0,
       };
 
       const center = getMatchCenter(match);
@@ -104,6 +109,7 @@ describe("Image Match Module", () => {
         height: 31,
         confidence: 0.9,
         scale: 1.0,
+        angle: 0,
       };
 
       const center = getMatchCenter(match);
@@ -120,6 +126,7 @@ describe("Image Match Module", () => {
         height: 150,
         confidence: 0.9,
         scale: 1.0,
+        angle: 0,
       };
 
       const center = getMatchCenter(match);
@@ -138,6 +145,7 @@ describe("Image Match Module", () => {
         height: 30,
         confidence: 0.9,
         scale: 1.0,
+        angle: 0,
       };
 
       const bounds = getMatchBounds(match);
@@ -156,6 +164,7 @@ describe("Image Match Module", () => {
         height: 100,
         confidence: 0.9,
         scale: 1.0,
+        angle: 0,
       };
 
       const bounds = getMatchBounds(match);
@@ -174,6 +183,7 @@ describe("Image Match Module", () => {
         height: 150,
         confidence: 0.9,
         scale: 1.0,
+        angle: 0,
       };
 
       const bounds = getMatchBounds(match);
@@ -216,6 +226,11 @@ describe("Image Match Module", () => {
       expect(typeof findAllInRegion).toBe("function");
     });
 
+    it("should export findImageInBuffer function", async () => {
+      const { findImageInBuffer } = await import("@tego/botjs");
+      expect(typeof findImageInBuffer).toBe("function");
+    });
+
     it("should export waitFor function", async () => {
       const { waitFor } = await import("@tego/botjs");
       expect(typeof waitFor).toBe("function");